@@ -56,12 +56,12 @@ where
 /// ## `FalseIntersect`
 /// Either of the following cases:
 /// * Line segments are not parallel and at least one must be extended to intersect (that is for
-/// `0 <= t <= 1` for both segments there is no intersect)
+///   `0 <= t <= 1` for both segments there is no intersect)
 ///
 /// ## `Overlapping`
 /// Either of the following cases:
 /// * The lines are collinear and overlap, the segments may fully, partially or not overlap at all
-/// (determined by parametric t values returned)
+///   (determined by parametric t values returned)
 ///
 /// # Examples
 ///
@@ -7,4 +7,5 @@ pub use static_aabb2d_index;
 mod macros;
 #[macro_use]
 pub mod core;
+pub mod medial_axis;
 pub mod polyline;
@@ -0,0 +1,244 @@
+use crate::core::{math::Vector2, traits::Real};
+use std::collections::HashMap;
+
+/// Marker for no neighbor triangle.
+pub const NO_NEIGHBOR: usize = usize::MAX;
+
+/// Triangle of a [Delaunay] triangulation.
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle {
+    /// Vertex indexes in counter clockwise order.
+    pub vertexes: [usize; 3],
+    /// Neighbor triangle indexes, `neighbors[i]` is across the edge opposite `vertexes[i]`
+    /// ([NO_NEIGHBOR] if there is no neighbor).
+    pub neighbors: [usize; 3],
+    /// False if the triangle was removed while inserting points.
+    pub alive: bool,
+}
+
+/// Delaunay triangulation of a set of points built incrementally (Bowyer-Watson) inside a super
+/// triangle, the super triangle vertexes are appended after the input points.
+#[derive(Debug, Clone)]
+pub struct Delaunay<T> {
+    pub points: Vec<Vector2<T>>,
+    pub triangles: Vec<Triangle>,
+    input_count: usize,
+}
+
+#[inline]
+fn orient<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> T
+where
+    T: Real,
+{
+    (b - a).perp_dot(c - a)
+}
+
+/// Returns true if `p` is strictly inside the circumcircle of the counter clockwise triangle `a`,
+/// `b`, `c`.
+#[inline]
+fn in_circumcircle<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>, p: Vector2<T>) -> bool
+where
+    T: Real,
+{
+    let a = a - p;
+    let b = b - p;
+    let c = c - p;
+    let det = a.length_squared() * b.perp_dot(c) - b.length_squared() * a.perp_dot(c)
+        + c.length_squared() * a.perp_dot(b);
+    det > T::zero()
+}
+
+impl<T> Delaunay<T>
+where
+    T: Real,
+{
+    /// Triangulate `points`, points must be distinct.
+    pub fn new(points: &[Vector2<T>]) -> Self {
+        let input_count = points.len();
+        let mut result = Self {
+            points: Vec::with_capacity(input_count + 3),
+            triangles: Vec::with_capacity(2 * input_count + 1),
+            input_count,
+        };
+        result.points.extend_from_slice(points);
+
+        let (mut min_x, mut min_y) = (<T as Real>::max_value(), <T as Real>::max_value());
+        let (mut max_x, mut max_y) = (<T as Real>::min_value(), <T as Real>::min_value());
+        for p in points {
+            min_x = num_traits::real::Real::min(min_x, p.x);
+            min_y = num_traits::real::Real::min(min_y, p.y);
+            max_x = num_traits::real::Real::max(max_x, p.x);
+            max_y = num_traits::real::Real::max(max_y, p.y);
+        }
+        if input_count == 0 {
+            return result;
+        }
+
+        let size = num_traits::real::Real::max(
+            num_traits::real::Real::max(max_x - min_x, max_y - min_y),
+            T::one(),
+        );
+        let cx = (min_x + max_x) / T::two();
+        let cy = (min_y + max_y) / T::two();
+        let far = T::from(50.0).unwrap() * size;
+        result.points.push(Vector2::new(cx - far, cy - far));
+        result.points.push(Vector2::new(cx + far, cy - far));
+        result.points.push(Vector2::new(cx, cy + far));
+        result.triangles.push(Triangle {
+            vertexes: [input_count, input_count + 1, input_count + 2],
+            neighbors: [NO_NEIGHBOR; 3],
+            alive: true,
+        });
+
+        let mut last = 0;
+        let mut in_cavity = Vec::new();
+        for i in 0..input_count {
+            last = result.insert(i, last, &mut in_cavity);
+        }
+
+        result
+    }
+
+    /// Returns true if `vertex` is one of the super triangle vertexes.
+    #[inline]
+    pub fn is_super_vertex(&self, vertex: usize) -> bool {
+        vertex >= self.input_count
+    }
+
+    /// Circumcenter of a triangle, returns `None` if the triangle is degenerate.
+    pub fn circumcenter(&self, triangle: usize) -> Option<Vector2<T>> {
+        let [i0, i1, i2] = self.triangles[triangle].vertexes;
+        let a = self.points[i0];
+        let b = self.points[i1] - a;
+        let c = self.points[i2] - a;
+        let d = T::two() * b.perp_dot(c);
+        let b2 = b.length_squared();
+        let c2 = c.length_squared();
+        if d.abs() <= T::epsilon() * (b2 + c2) {
+            return None;
+        }
+        Some(Vector2::new(
+            a.x + (c.y * b2 - b.y * c2) / d,
+            a.y + (b.x * c2 - c.x * b2) / d,
+        ))
+    }
+
+    /// Find a triangle containing point `p` by walking from triangle `start`.
+    fn locate(&self, p: Vector2<T>, start: usize) -> usize {
+        let mut t = start;
+        let mut steps = 0;
+        'walk: loop {
+            let tri = &self.triangles[t];
+            for k in 0..3 {
+                let a = self.points[tri.vertexes[(k + 1) % 3]];
+                let b = self.points[tri.vertexes[(k + 2) % 3]];
+                let n = tri.neighbors[k];
+                if n != NO_NEIGHBOR && orient(a, b, p) < T::zero() {
+                    t = n;
+                    steps += 1;
+                    if steps > self.triangles.len() {
+                        break 'walk;
+                    }
+                    continue 'walk;
+                }
+            }
+            return t;
+        }
+
+        // walk did not terminate (numeric issues), fall back to linear search
+        self.triangles
+            .iter()
+            .enumerate()
+            .filter(|(_, tri)| tri.alive)
+            .max_by(|(_, t1), (_, t2)| {
+                let min_orient = |tri: &Triangle| {
+                    (0..3)
+                        .map(|k| {
+                            orient(
+                                self.points[tri.vertexes[(k + 1) % 3]],
+                                self.points[tri.vertexes[(k + 2) % 3]],
+                                p,
+                            )
+                        })
+                        .fold(<T as Real>::max_value(), num_traits::real::Real::min)
+                };
+                min_orient(t1)
+                    .partial_cmp(&min_orient(t2))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(start)
+    }
+
+    /// Insert point at `index`, returns the index of one of the triangles created.
+    fn insert(&mut self, index: usize, start: usize, in_cavity: &mut Vec<bool>) -> usize {
+        let p = self.points[index];
+        let first = self.locate(p, start);
+
+        // entries are reset after each insert so only the new triangles need to be added
+        in_cavity.resize(self.triangles.len(), false);
+        in_cavity[first] = true;
+        let mut cavity = vec![first];
+        let mut stack = vec![first];
+        while let Some(t) = stack.pop() {
+            for &n in self.triangles[t].neighbors.iter() {
+                if n == NO_NEIGHBOR || in_cavity[n] {
+                    continue;
+                }
+                let [a, b, c] = self.triangles[n].vertexes;
+                if in_circumcircle(self.points[a], self.points[b], self.points[c], p) {
+                    in_cavity[n] = true;
+                    cavity.push(n);
+                    stack.push(n);
+                }
+            }
+        }
+
+        // new triangles fan from the cavity boundary edges to the new point
+        let mut by_first = HashMap::with_capacity(cavity.len() + 2);
+        let mut by_second = HashMap::with_capacity(cavity.len() + 2);
+        let mut created = Vec::with_capacity(cavity.len() + 2);
+        for &t in cavity.iter() {
+            let tri = self.triangles[t];
+            for k in 0..3 {
+                let n = tri.neighbors[k];
+                if n != NO_NEIGHBOR && in_cavity[n] {
+                    continue;
+                }
+                let a = tri.vertexes[(k + 1) % 3];
+                let b = tri.vertexes[(k + 2) % 3];
+                let new_index = self.triangles.len();
+                self.triangles.push(Triangle {
+                    vertexes: [a, b, index],
+                    neighbors: [NO_NEIGHBOR, NO_NEIGHBOR, n],
+                    alive: true,
+                });
+                if n != NO_NEIGHBOR {
+                    for nn in self.triangles[n].neighbors.iter_mut() {
+                        if *nn == t {
+                            *nn = new_index;
+                        }
+                    }
+                }
+                by_first.insert(a, new_index);
+                by_second.insert(b, new_index);
+                created.push(new_index);
+            }
+        }
+
+        for &t in created.iter() {
+            let [a, b, _] = self.triangles[t].vertexes;
+            let across_b_p = by_first.get(&b).copied().unwrap_or(NO_NEIGHBOR);
+            let across_p_a = by_second.get(&a).copied().unwrap_or(NO_NEIGHBOR);
+            self.triangles[t].neighbors[0] = across_b_p;
+            self.triangles[t].neighbors[1] = across_p_a;
+        }
+
+        for &t in cavity.iter() {
+            self.triangles[t].alive = false;
+            in_cavity[t] = false;
+        }
+
+        created[0]
+    }
+}
@@ -0,0 +1,606 @@
+use super::delaunay::{Delaunay, NO_NEIGHBOR};
+use crate::{
+    core::{
+        math::{bulge_from_angle, dist_squared, Vector2},
+        traits::Real,
+    },
+    polyline::{
+        seg_closest_point, seg_length, seg_point_at_parametric, PlineContainmentTree,
+        PlineCreation, PlineSource, PlineSourceMut, PlineVertex, PointLocator, Polyline,
+    },
+};
+use std::collections::HashMap;
+
+/// Options for computing the medial axis with [MedialAxis::new].
+#[derive(Debug, Clone)]
+pub struct MedialAxisOptions<T = f64> {
+    /// Maximum distance between boundary samples, smaller spacing gives a more accurate medial
+    /// axis at the cost of more computation. If `None` then the spacing is 1/1000 of the total
+    /// boundary path length.
+    pub sample_spacing: Option<T>,
+    /// Maximum number of boundary samples placed along the segments (in addition to the
+    /// vertexes), the sample spacing is increased if needed to stay within it.
+    pub max_sample_count: usize,
+    /// Maximum distance between the sampled medial axis and the line and arc edges fitted to it.
+    /// If `None` then 1/10 of the sample spacing is used.
+    pub fit_tolerance: Option<T>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> MedialAxisOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sample_spacing: None,
+            max_sample_count: 10_000,
+            fit_tolerance: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for MedialAxisOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Node of a [MedialAxis].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MedialAxisNode<T = f64> {
+    /// Position of the node.
+    pub pos: Vector2<T>,
+    /// Clearance radius at the node (distance to the closest point on the region boundary), this
+    /// is the radius of the largest circle centered at the node that fits inside the region.
+    pub radius: T,
+}
+
+/// Line or arc edge between two nodes of a [MedialAxis].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MedialAxisEdge<T = f64> {
+    /// Index of the start node.
+    pub start: usize,
+    /// Index of the end node.
+    pub end: usize,
+    /// Bulge of the edge going from the start node to the end node (same as [PlineVertex::bulge]),
+    /// zero for a line.
+    pub bulge: T,
+}
+
+/// Medial axis of a region defined by a set of closed polylines.
+///
+/// The medial axis is the set of points inside the region with more than one closest point on
+/// the region boundary. It is represented as a graph of nodes (with clearance radius) connected
+/// by line and arc edges. Bisectors of two lines or two points are lines and bisectors of
+/// concentric arcs are arcs, parabolic and other curved parts (e.g. between a line and a point)
+/// are approximated by arcs within [MedialAxisOptions::fit_tolerance].
+///
+/// The result is an approximation derived from points sampled along the boundary (see
+/// [MedialAxisOptions::sample_spacing]), the edges are fitted to the sampled medial axis so node
+/// positions (e.g. where a branch ends in a corner) are only as accurate as the sampling. The
+/// clearance radius at each node is exact for the node position.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::medial_axis::*;
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// // long thin rectangle, medial axis runs along the center with branches into the corners
+/// let rect: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 2.0, 0.0), (0.0, 2.0, 0.0)];
+/// let options = MedialAxisOptions {
+///     sample_spacing: Some(0.1),
+///     ..Default::default()
+/// };
+/// let axis = MedialAxis::new(std::iter::once(&rect), &options);
+/// // center line edge plus four corner branch edges, all lines
+/// assert_eq!(axis.edges.len(), 5);
+/// assert!(axis.edges.iter().all(|e| e.bulge == 0.0));
+/// // four corner branches end at nodes with degree 1
+/// let degrees = axis.node_degrees();
+/// assert_eq!(degrees.iter().filter(|&&d| d == 1).count(), 4);
+/// // center line between the branch points with clearance 1
+/// let center_line = axis
+///     .edges
+///     .iter()
+///     .find(|e| degrees[e.start] == 3 && degrees[e.end] == 3)
+///     .unwrap();
+/// let (a, b) = (axis.nodes[center_line.start], axis.nodes[center_line.end]);
+/// assert!((a.pos.y - 1.0).abs() < 1e-6 && (b.pos.y - 1.0).abs() < 1e-6);
+/// assert!(((a.pos.x - b.pos.x).abs() - 8.0).abs() < 1e-6);
+/// assert!((a.radius - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MedialAxis<T = f64> {
+    /// All the nodes of the medial axis.
+    pub nodes: Vec<MedialAxisNode<T>>,
+    /// All the edges of the medial axis.
+    pub edges: Vec<MedialAxisEdge<T>>,
+}
+
+/// Find root of `i` with path halving.
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Bulge of the arc from `start` to `end` passing through `mid`, returns `None` if the points are
+/// collinear or the arc sweeps more than a half circle.
+fn arc_bulge_through<T>(start: Vector2<T>, mid: Vector2<T>, end: Vector2<T>) -> Option<T>
+where
+    T: Real,
+{
+    let a = start - mid;
+    let c = end - mid;
+    let cross = a.perp_dot(c);
+    if cross.abs() <= T::epsilon() * a.length() * c.length() {
+        return None;
+    }
+
+    // angle at `mid` is half the sweep of the arc on the other side of the chord
+    let sweep = T::two() * (T::pi() - cross.abs().atan2(a.dot(c)));
+    if sweep > T::pi() {
+        return None;
+    }
+
+    // counter clockwise arcs bulge to the right of the chord
+    let bulge = bulge_from_angle(sweep);
+    if (end - start).perp_dot(mid - start) < T::zero() {
+        Some(bulge)
+    } else {
+        Some(-bulge)
+    }
+}
+
+/// Fit line and arc edges to the chain of `points` so every point is within `tolerance` of the
+/// edges. Returns the index of the first point of each edge with the edge bulge, the last edge
+/// ends at the last point.
+fn fit_chain<T>(points: &[Vector2<T>], tolerance: T, eps: T) -> Vec<(usize, T)>
+where
+    T: Real,
+{
+    let tolerance2 = tolerance * tolerance;
+    let fits = |i: usize, j: usize, bulge: T| {
+        let v1 = PlineVertex::from_vector2(points[i], bulge);
+        let v2 = PlineVertex::from_vector2(points[j], T::zero());
+        points[i + 1..j]
+            .iter()
+            .all(|&p| dist_squared(seg_closest_point(v1, v2, p, eps), p) <= tolerance2)
+    };
+    let fit = |i: usize, j: usize| {
+        if fits(i, j, T::zero()) {
+            return Some(T::zero());
+        }
+        let bulge = arc_bulge_through(points[i], points[(i + j) / 2], points[j])?;
+        if fits(i, j, bulge) {
+            Some(bulge)
+        } else {
+            None
+        }
+    };
+
+    // greedily extend each edge as far along the chain as it fits
+    let last = points.len() - 1;
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < last {
+        let mut j = i + 1;
+        let mut bulge = T::zero();
+        while j < last {
+            match fit(i, j + 1) {
+                Some(b) => {
+                    j += 1;
+                    bulge = b;
+                }
+                None => break,
+            }
+        }
+        result.push((i, bulge));
+        i = j;
+    }
+
+    result
+}
+
+impl<T> MedialAxis<T>
+where
+    T: Real,
+{
+    /// Compute the medial axis of the region defined by `plines`.
+    ///
+    /// The region is the area inside the outer polylines excluding the holes, nesting is
+    /// determined with [PlineContainmentTree] so the input orientations do not need to be
+    /// consistent. Open polylines and polylines with less than 2 vertexes are ignored.
+    ///
+    /// The boundary is sampled (every vertex plus points at most
+    /// [MedialAxisOptions::sample_spacing] apart, limited by
+    /// [MedialAxisOptions::max_sample_count]) and the Voronoi diagram of the samples is found
+    /// from their Delaunay triangulation. Voronoi edges inside the region that separate samples
+    /// which are not neighbors along the boundary form the sampled medial axis, its chains
+    /// through nodes of degree 2 are then replaced by line and arc edges fitted within
+    /// [MedialAxisOptions::fit_tolerance]. The clearance radius of each node is computed exactly
+    /// against the boundary segments.
+    pub fn new<'a, P, I>(plines: I, options: &MedialAxisOptions<T>) -> Self
+    where
+        P: PlineSource<Num = T> + 'a + ?Sized,
+        I: IntoIterator<Item = &'a P>,
+    {
+        let eps = options.pos_equal_eps;
+        let mut loops = plines
+            .into_iter()
+            .filter(|p| p.is_closed() && p.vertex_count() >= 2)
+            .map(|p| Polyline::create_from(p))
+            .collect::<Vec<Polyline<T>>>();
+
+        // outer loops counter clockwise and holes clockwise so the region is where the winding
+        // number is not zero
+        let tree = PlineContainmentTree::build(&loops, eps);
+        tree.fix_orientations(&mut loops);
+        loops.retain(|p| p.area().abs() > eps);
+        if loops.is_empty() {
+            return Self::default();
+        }
+
+        let total_length = loops.iter().fold(T::zero(), |acc, p| acc + p.path_length());
+        let spacing = match options.sample_spacing {
+            Some(s) if s > eps => s,
+            _ => total_length / T::from(1000).unwrap(),
+        };
+        // bound the number of samples, the triangulation cost grows with it
+        let max_sample_count = T::from(options.max_sample_count.max(1)).unwrap();
+        let spacing = num_traits::real::Real::max(spacing, total_length / max_sample_count);
+        let fit_tolerance = match options.fit_tolerance {
+            Some(t) if t > eps => t,
+            _ => spacing / T::from(10).unwrap(),
+        };
+
+        // boundary samples with the loop index and sample index within the loop
+        let mut samples = Vec::new();
+        let mut sample_ids = Vec::new();
+        let mut loop_sample_counts = Vec::with_capacity(loops.len());
+        for (loop_index, pline) in loops.iter().enumerate() {
+            let start_count = samples.len();
+            for (v1, v2) in pline.iter_segments() {
+                let length = seg_length(v1, v2);
+                let count = num_traits::cast::<T, usize>((length / spacing).ceil())
+                    .unwrap_or(1)
+                    .max(1);
+                for j in 0..count {
+                    let t = T::from(j).unwrap() / T::from(count).unwrap();
+                    let pt = seg_point_at_parametric(v1, v2, t);
+                    let is_repeat = samples.len() > start_count
+                        && pt.fuzzy_eq_eps(*samples.last().unwrap(), eps);
+                    if !is_repeat {
+                        sample_ids.push((loop_index, samples.len() - start_count));
+                        samples.push(pt);
+                    }
+                }
+            }
+            // drop last sample if it repeats the first
+            if samples.len() > start_count + 1
+                && samples[start_count].fuzzy_eq_eps(*samples.last().unwrap(), eps)
+            {
+                samples.pop();
+                sample_ids.pop();
+            }
+            loop_sample_counts.push(samples.len() - start_count);
+        }
+
+        let are_boundary_neighbors = |a: usize, b: usize| {
+            let (loop_a, ia) = sample_ids[a];
+            let (loop_b, ib) = sample_ids[b];
+            if loop_a != loop_b {
+                return false;
+            }
+            let n = loop_sample_counts[loop_a];
+            let diff = if ia > ib { ia - ib } else { ib - ia };
+            diff == 1 || diff + 1 == n
+        };
+
+        // coincident samples (e.g. where loops touch) are only inserted once, triangulation
+        // vertexes map back to the sample index
+        let cell_size = num_traits::real::Real::max(spacing, eps);
+        let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        let mut unique_points: Vec<Vector2<T>> = Vec::with_capacity(samples.len());
+        let mut unique_samples = Vec::with_capacity(samples.len());
+        for (i, &pt) in samples.iter().enumerate() {
+            let cell = match (
+                (pt.x / cell_size).floor().to_i64(),
+                (pt.y / cell_size).floor().to_i64(),
+            ) {
+                (Some(cx), Some(cy)) => (cx, cy),
+                _ => continue,
+            };
+            let is_repeat = (-1..=1).any(|dx| {
+                (-1..=1).any(|dy| {
+                    grid.get(&(cell.0 + dx, cell.1 + dy))
+                        .map_or(false, |cell_points| {
+                            cell_points
+                                .iter()
+                                .any(|&u| unique_points[u].fuzzy_eq_eps(pt, eps))
+                        })
+                })
+            });
+            if !is_repeat {
+                grid.entry(cell).or_default().push(unique_points.len());
+                unique_points.push(pt);
+                unique_samples.push(i);
+            }
+        }
+
+        let triangulation = Delaunay::new(&unique_points);
+        let locator = PointLocator::new(&loops, eps);
+
+        // voronoi vertex (triangle circumcenter) for each triangle, `None` if it does not lie
+        // inside the region
+        let tri_count = triangulation.triangles.len();
+        let mut tri_nodes = vec![None; tri_count];
+        let mut nodes = Vec::new();
+        for (t, tri) in triangulation.triangles.iter().enumerate() {
+            if !tri.alive
+                || tri
+                    .vertexes
+                    .iter()
+                    .any(|&v| triangulation.is_super_vertex(v))
+            {
+                continue;
+            }
+            let center = match triangulation.circumcenter(t) {
+                Some(c) => c,
+                None => continue,
+            };
+            match locator.signed_distance(center) {
+                Some(d) if d > eps => {
+                    tri_nodes[t] = Some(nodes.len());
+                    nodes.push(MedialAxisNode {
+                        pos: center,
+                        radius: d,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        // voronoi edges (between circumcenters of neighboring triangles)
+        let mut raw_edges = Vec::new();
+        for (t, tri) in triangulation.triangles.iter().enumerate() {
+            let start = match tri_nodes[t] {
+                Some(n) => n,
+                None => continue,
+            };
+            for k in 0..3 {
+                let n = tri.neighbors[k];
+                if n == NO_NEIGHBOR || n < t {
+                    continue;
+                }
+                let end = match tri_nodes[n] {
+                    Some(e) => e,
+                    None => continue,
+                };
+                let a = tri.vertexes[(k + 1) % 3];
+                let b = tri.vertexes[(k + 2) % 3];
+                if are_boundary_neighbors(unique_samples[a], unique_samples[b]) {
+                    continue;
+                }
+                raw_edges.push((start, end));
+            }
+        }
+
+        // merge coincident nodes (from co-circular samples)
+        let mut parents = (0..nodes.len()).collect::<Vec<_>>();
+        for &(a, b) in raw_edges.iter() {
+            if nodes[a].pos.fuzzy_eq_eps(nodes[b].pos, eps) {
+                let ra = find_root(&mut parents, a);
+                let rb = find_root(&mut parents, b);
+                if ra != rb {
+                    parents[rb] = ra;
+                }
+            }
+        }
+
+        // only keep nodes used by edges
+        let mut new_index = vec![usize::MAX; nodes.len()];
+        let mut sampled = Self::default();
+        let mut map_node = |i: usize, result: &mut Self| {
+            let root = find_root(&mut parents, i);
+            if new_index[root] == usize::MAX {
+                new_index[root] = result.nodes.len();
+                result.nodes.push(nodes[root]);
+            }
+            new_index[root]
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        for &(a, b) in raw_edges.iter() {
+            let start = map_node(a, &mut sampled);
+            let end = map_node(b, &mut sampled);
+            if start == end {
+                continue;
+            }
+            let key = if start < end {
+                (start, end)
+            } else {
+                (end, start)
+            };
+            if seen.insert(key) {
+                sampled.edges.push(MedialAxisEdge {
+                    start,
+                    end,
+                    bulge: T::zero(),
+                });
+            }
+        }
+
+        sampled.fit_edges(fit_tolerance, eps)
+    }
+
+    /// Replace the chains of edges through nodes of degree 2 with line and arc edges fitted
+    /// within `tolerance`, only the nodes at the ends of the fitted edges are kept.
+    fn fit_edges(&self, tolerance: T, eps: T) -> Self {
+        let mut new_index = vec![usize::MAX; self.nodes.len()];
+        let mut result = Self::default();
+        let mut map_node = |i: usize, result: &mut Self| {
+            if new_index[i] == usize::MAX {
+                new_index[i] = result.nodes.len();
+                result.nodes.push(self.nodes[i]);
+            }
+            new_index[i]
+        };
+
+        for (start_node, chain_edges) in self.edge_chains() {
+            let mut chain = Vec::with_capacity(chain_edges.len() + 1);
+            chain.push(start_node);
+            for &e in chain_edges.iter() {
+                let node = self.other_node(e, chain[chain.len() - 1]);
+                chain.push(node);
+            }
+
+            let points = chain.iter().map(|&n| self.nodes[n].pos).collect::<Vec<_>>();
+            let fitted = fit_chain(&points, tolerance, eps);
+            for (k, &(i, bulge)) in fitted.iter().enumerate() {
+                let j = fitted.get(k + 1).map_or(chain.len() - 1, |&(j, _)| j);
+                let start = map_node(chain[i], &mut result);
+                let end = map_node(chain[j], &mut result);
+                result.edges.push(MedialAxisEdge { start, end, bulge });
+            }
+        }
+
+        // keep isolated nodes (e.g. the center of a circle)
+        for (i, &d) in self.node_degrees().iter().enumerate() {
+            if d == 0 {
+                map_node(i, &mut result);
+            }
+        }
+
+        result
+    }
+
+    /// Node at the other end of `edge` from `node`.
+    #[inline]
+    fn other_node(&self, edge: usize, node: usize) -> usize {
+        let e = self.edges[edge];
+        if e.start == node {
+            e.end
+        } else {
+            e.start
+        }
+    }
+
+    /// Split the edges into chains between nodes that are branch points or end points (node
+    /// degree not equal to 2), returns the start node and the edges of each chain in order. Loops
+    /// of degree 2 nodes are returned last and end at their start node.
+    fn edge_chains(&self) -> Vec<(usize, Vec<usize>)> {
+        let node_edges = self.node_edges();
+        let mut visited = vec![false; self.edges.len()];
+        let mut result = Vec::new();
+
+        let walk = |start_node: usize, start_edge: usize, visited: &mut Vec<bool>| {
+            let mut chain = Vec::new();
+            let mut node = start_node;
+            let mut edge = start_edge;
+            loop {
+                visited[edge] = true;
+                chain.push(edge);
+                node = self.other_node(edge, node);
+                if node_edges[node].len() != 2 {
+                    break;
+                }
+                match node_edges[node].iter().find(|&&e| !visited[e]) {
+                    Some(&e) => edge = e,
+                    None => break,
+                }
+            }
+            chain
+        };
+
+        for (n, edges) in node_edges.iter().enumerate() {
+            if edges.len() == 2 {
+                continue;
+            }
+            for &e in edges.iter() {
+                if !visited[e] {
+                    result.push((n, walk(n, e, &mut visited)));
+                }
+            }
+        }
+
+        for e in 0..self.edges.len() {
+            if !visited[e] {
+                let start = self.edges[e].start;
+                result.push((start, walk(start, e, &mut visited)));
+            }
+        }
+
+        result
+    }
+
+    /// Number of edges connected to each node.
+    pub fn node_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.nodes.len()];
+        for e in self.edges.iter() {
+            degrees[e.start] += 1;
+            degrees[e.end] += 1;
+        }
+        degrees
+    }
+
+    /// Edge indexes connected to each node.
+    pub fn node_edges(&self) -> Vec<Vec<usize>> {
+        let mut result = vec![Vec::new(); self.nodes.len()];
+        for (i, e) in self.edges.iter().enumerate() {
+            result[e.start].push(i);
+            result[e.end].push(i);
+        }
+        result
+    }
+
+    /// Convert the medial axis into polylines, each polyline is a chain of edges between nodes
+    /// that are branch points or end points (node degree not equal to 2). Loops of the medial
+    /// axis (e.g. around a hole) without any branch points are returned as closed polylines.
+    ///
+    /// Useful for centerline extraction, the clearance radius along each polyline can be found
+    /// from the node positions.
+    pub fn to_plines(&self) -> Vec<Polyline<T>> {
+        let node_degrees = self.node_degrees();
+        let mut result = Vec::new();
+        for (start_node, chain_edges) in self.edge_chains() {
+            let mut pline = Polyline::with_capacity(chain_edges.len() + 1, false);
+            let p = self.nodes[start_node].pos;
+            pline.add(p.x, p.y, T::zero());
+            let mut node = start_node;
+            for &e in chain_edges.iter() {
+                let edge = self.edges[e];
+                let bulge = if edge.start == node {
+                    edge.bulge
+                } else {
+                    -edge.bulge
+                };
+                let last = pline.last().unwrap();
+                pline.set_last(PlineVertex::new(last.x, last.y, bulge));
+                node = self.other_node(e, node);
+                let p = self.nodes[node].pos;
+                pline.add(p.x, p.y, T::zero());
+            }
+
+            // loops of degree 2 nodes without any branch points
+            if node_degrees[start_node] == 2 && pline.vertex_count() > 2 {
+                pline.remove_last();
+                pline.set_is_closed(true);
+            }
+            result.push(pline);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,6 @@
+//! This module has the types and functions for computing the medial axis of regions defined by
+//! closed polylines.
+mod delaunay;
+mod graph;
+
+pub use graph::*;
@@ -3,5 +3,10 @@
 //! Not expected to be used directly as part of the library but may be used to help learn about the
 //! algorithms.
 pub mod pline_boolean;
+pub mod pline_canonical;
+pub mod pline_distance;
 pub mod pline_intersects;
 pub mod pline_offset;
+pub mod pline_path_length;
+pub mod pline_ray;
+pub mod pline_relate;
@@ -0,0 +1,202 @@
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{PlineCreation, PlineSource, PlineSourceMut},
+};
+use std::cmp::Ordering;
+
+/// Fuzzy compare two values, returning [Ordering::Equal] if they are fuzzy equal.
+fn fuzzy_cmp<T>(a: T, b: T, eps: T) -> Ordering
+where
+    T: Real,
+{
+    if a.fuzzy_eq_eps(b, eps) {
+        Ordering::Equal
+    } else if a < b {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+/// Fuzzy lexicographic comparison of two positions (x then y).
+fn fuzzy_cmp_pos<T>(a: Vector2<T>, b: Vector2<T>, eps: T) -> Ordering
+where
+    T: Real,
+{
+    fuzzy_cmp(a.x, b.x, eps).then_with(|| fuzzy_cmp(a.y, b.y, eps))
+}
+
+/// Fuzzy lexicographic comparison of the vertexes (x, y, then bulge) of two polylines with the same
+/// vertex count.
+fn fuzzy_cmp_vertexes<P, R, T>(pline1: &P, pline2: &R, eps: T) -> Ordering
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    for (v1, v2) in pline1.iter_vertexes().zip(pline2.iter_vertexes()) {
+        let ord =
+            fuzzy_cmp_pos(v1.pos(), v2.pos(), eps).then_with(|| fuzzy_cmp(v1.bulge, v2.bulge, eps));
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Copy of the polyline with redundant vertexes removed. For open polylines the bulge of the last
+/// vertex is set to zero since it does not define a segment.
+fn normalized<P, O, T>(pline: &P, pos_equal_eps: T) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    O: PlineCreation<Num = T>,
+    T: Real,
+{
+    let mut result = match pline.remove_redundant(pos_equal_eps) {
+        Some(p) => O::create_from(&p),
+        None => O::create_from(pline),
+    };
+
+    if !result.is_closed() && !result.is_empty() {
+        let last = result.last().unwrap();
+        result.set_last(last.with_bulge(T::zero()));
+    }
+
+    result
+}
+
+/// Returns true if `pline1` and `pline2` represent the same geometric path, ignoring redundant
+/// vertexes, the starting vertex (for closed polylines), and direction.
+///
+/// Both polylines are normalized using [PlineSource::remove_redundant], then `pline2` is compared
+/// vertex by vertex to `pline1` after rotating its start to each vertex that lies on the start of
+/// `pline1` (closed polylines only), first as given and then with its direction inverted.
+pub fn geometric_eq<P, R, T>(pline1: &P, pline2: &R, eps: T) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline1.is_closed() != pline2.is_closed() {
+        return false;
+    }
+
+    let pline1: P::OutputPolyline = normalized(pline1, eps);
+    let mut pline2: P::OutputPolyline = normalized(pline2, eps);
+
+    let vc = pline1.vertex_count();
+    if vc != pline2.vertex_count() {
+        return false;
+    }
+
+    if vc == 0 {
+        return true;
+    }
+
+    let matches_any_start = |other: &P::OutputPolyline| {
+        if !other.is_closed() || vc < 2 {
+            return pline1.fuzzy_eq_eps(other, eps);
+        }
+
+        let start = pline1.at(0).pos();
+        (0..vc)
+            .filter(|&i| other.at(i).pos().fuzzy_eq_eps(start, eps))
+            .any(|i| match other.rotate_start(i, other.at(i).pos(), eps) {
+                Some(rotated) => pline1.fuzzy_eq_eps(&rotated, eps),
+                None => false,
+            })
+    };
+
+    if matches_any_start(&pline2) {
+        return true;
+    }
+
+    pline2.invert_direction_mut();
+    if !pline2.is_closed() {
+        // bulge of last vertex carried over from the start when inverting an open polyline
+        let last = pline2.last().unwrap();
+        pline2.set_last(last.with_bulge(T::zero()));
+    }
+    matches_any_start(&pline2)
+}
+
+/// Returns the canonical form of a polyline, geometrically equal polylines (see [geometric_eq])
+/// have fuzzy equal canonical forms.
+///
+/// Redundant vertexes are removed, then:
+/// * open polylines are directed such that the vertexes are lexicographically smallest
+///   (comparing x, y, then bulge of each vertex in order),
+/// * closed polylines are directed counter clockwise (both directions are considered if the area
+///   is zero) and rotated to start at the lexicographically smallest vertex position, ties are
+///   broken by comparing the following vertexes.
+pub fn canonical_form<P, T>(pline: &P, pos_equal_eps: T) -> P::OutputPolyline
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let result: P::OutputPolyline = normalized(pline, pos_equal_eps);
+    let vc = result.vertex_count();
+    if vc < 2 {
+        return result;
+    }
+
+    let mut inverted = P::OutputPolyline::create_from(&result);
+    inverted.invert_direction_mut();
+
+    if !result.is_closed() {
+        let last = inverted.last().unwrap();
+        inverted.set_last(last.with_bulge(T::zero()));
+        if fuzzy_cmp_vertexes(&inverted, &result, pos_equal_eps) == Ordering::Less {
+            return inverted;
+        }
+        return result;
+    }
+
+    let area = result.area();
+    let mut candidates = Vec::with_capacity(2);
+    if area.fuzzy_eq_zero_eps(pos_equal_eps) {
+        candidates.push(result);
+        candidates.push(inverted);
+    } else if area < T::zero() {
+        candidates.push(inverted);
+    } else {
+        candidates.push(result);
+    }
+
+    // smallest vertex position across all candidates (same set of positions for both directions)
+    let min_pos = candidates[0]
+        .iter_vertexes()
+        .map(|v| v.pos())
+        .min_by(|a, b| fuzzy_cmp_pos(*a, *b, pos_equal_eps))
+        .unwrap();
+
+    let mut best: Option<P::OutputPolyline> = None;
+    for candidate in candidates.iter() {
+        for i in 0..vc {
+            let pos = candidate.at(i).pos();
+            if fuzzy_cmp_pos(pos, min_pos, pos_equal_eps) != Ordering::Equal {
+                continue;
+            }
+
+            let rotated = P::OutputPolyline::from_iter(
+                candidate
+                    .iter_vertexes()
+                    .skip(i)
+                    .chain(candidate.iter_vertexes().take(i)),
+                true,
+            );
+
+            let is_better = match best {
+                Some(ref b) => fuzzy_cmp_vertexes(&rotated, b, pos_equal_eps) == Ordering::Less,
+                None => true,
+            };
+
+            if is_better {
+                best = Some(rotated);
+            }
+        }
+    }
+
+    best.unwrap()
+}
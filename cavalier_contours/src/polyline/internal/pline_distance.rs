@@ -0,0 +1,581 @@
+use crate::{
+    core::{
+        math::{dist_squared, Vector2},
+        traits::Real,
+    },
+    polyline::{
+        internal::pline_path_length::vertex_path_lengths, seg_closest_points,
+        seg_fast_approx_bounding_box, seg_length, seg_point_at_parametric,
+        PlineClosestPointOptions, PlineDistanceOptions, PlineDistanceResult, PlineHausdorffOptions,
+        PlineHausdorffResult, PlineSource,
+    },
+};
+use static_aabb2d_index as aabb_index;
+use static_aabb2d_index::{StaticAABB2DIndex, AABB};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Squared distance between two axis aligned bounding boxes (zero if they overlap).
+#[inline]
+pub fn aabb_dist_squared<T>(a: &AABB<T>, b: &AABB<T>) -> T
+where
+    T: Real,
+{
+    let axis_gap = |a_min: T, a_max: T, b_min: T, b_max: T| {
+        if a_max < b_min {
+            b_min - a_max
+        } else if b_max < a_min {
+            a_min - b_max
+        } else {
+            T::zero()
+        }
+    };
+
+    let dx = axis_gap(a.min_x, a.max_x, b.min_x, b.max_x);
+    let dy = axis_gap(a.min_y, a.max_y, b.min_y, b.max_y);
+    dx * dx + dy * dy
+}
+
+/// Find the minimum distance and closest point pair between two polylines.
+///
+/// Uses a branch and bound search over the segments of both polylines: the best distance found so
+/// far is an upper bound used to query the spatial indexes, and bounding box distances are lower
+/// bounds used to skip segment pairs which cannot be closer than the best found. If the polylines
+/// intersect the search stops at the first intersect found and a distance of zero is returned.
+///
+/// Returns `None` if either polyline is empty.
+pub fn find_min_distance<P, R, T>(
+    pline1: &P,
+    pline2: &R,
+    options: &PlineDistanceOptions<T>,
+) -> Option<PlineDistanceResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline1.is_empty() || pline2.is_empty() {
+        return None;
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+
+    // handle single vertex polylines using closest point to the other polyline
+    if pline1.vertex_count() == 1 {
+        let point1 = pline1.at(0).pos();
+        let cp = pline2.closest_point_opt(
+            point1,
+            &PlineClosestPointOptions {
+                aabb_index: options.pline2_aabb_index,
+                vertex_path_lengths: None,
+                pos_equal_eps,
+            },
+        )?;
+        return Some(PlineDistanceResult {
+            distance: cp.distance,
+            seg_start_index1: 0,
+            point1,
+            seg_start_index2: cp.seg_start_index,
+            point2: cp.seg_point,
+        });
+    }
+
+    if pline2.vertex_count() == 1 {
+        let point2 = pline2.at(0).pos();
+        let cp = pline1.closest_point_opt(
+            point2,
+            &PlineClosestPointOptions {
+                aabb_index: options.pline1_aabb_index,
+                vertex_path_lengths: None,
+                pos_equal_eps,
+            },
+        )?;
+        return Some(PlineDistanceResult {
+            distance: cp.distance,
+            seg_start_index1: cp.seg_start_index,
+            point1: cp.seg_point,
+            seg_start_index2: 0,
+            point2,
+        });
+    }
+
+    let constructed_index1;
+    let index1 = if let Some(x) = options.pline1_aabb_index {
+        x
+    } else {
+        constructed_index1 = pline1.create_approx_aabb_index()?;
+        &constructed_index1
+    };
+
+    let constructed_index2;
+    let index2 = if let Some(x) = options.pline2_aabb_index {
+        x
+    } else {
+        constructed_index2 = pline2.create_approx_aabb_index()?;
+        &constructed_index2
+    };
+
+    // seed upper bound using closest point from first vertex of pline1 to pline2
+    let mut result = {
+        let point1 = pline1.at(0).pos();
+        let cp = pline2.closest_point_opt(
+            point1,
+            &PlineClosestPointOptions {
+                aabb_index: Some(index2),
+                vertex_path_lengths: None,
+                pos_equal_eps,
+            },
+        )?;
+        PlineDistanceResult {
+            distance: cp.distance,
+            seg_start_index1: 0,
+            point1,
+            seg_start_index2: cp.seg_start_index,
+            point2: cp.seg_point,
+        }
+    };
+
+    if result.distance <= pos_equal_eps {
+        return Some(result);
+    }
+
+    let mut best_dist2 = result.distance * result.distance;
+
+    let seg_box1 = |i: usize| {
+        seg_fast_approx_bounding_box(pline1.at(i), pline1.at(pline1.next_wrapping_index(i)))
+    };
+    let seg_box2 = |j: usize| {
+        seg_fast_approx_bounding_box(pline2.at(j), pline2.at(pline2.next_wrapping_index(j)))
+    };
+
+    // find all pline1 segments that may be closer than the current best to pline2 extents and
+    // process them in order of lower bound distance
+    let extents2 = AABB::new(
+        index2.min_x(),
+        index2.min_y(),
+        index2.max_x(),
+        index2.max_y(),
+    );
+    let mut candidates = {
+        let d = result.distance;
+        let mut candidates = Vec::new();
+        let mut visitor = |i: usize| {
+            let lower_bound = aabb_dist_squared(&seg_box1(i), &extents2);
+            if lower_bound < best_dist2 {
+                candidates.push((i, lower_bound));
+            }
+            aabb_index::Control::<()>::Continue
+        };
+        index1.visit_query(
+            extents2.min_x - d,
+            extents2.min_y - d,
+            extents2.max_x + d,
+            extents2.max_y + d,
+            &mut visitor,
+        );
+        candidates
+    };
+
+    candidates.sort_unstable_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    let mut query_stack = Vec::with_capacity(8);
+    for (i, lower_bound) in candidates {
+        if lower_bound >= best_dist2 {
+            // all remaining candidates are further away than the best found
+            break;
+        }
+
+        let v1 = pline1.at(i);
+        let v2 = pline1.at(pline1.next_wrapping_index(i));
+        let box1 = seg_box1(i);
+        let d = best_dist2.sqrt();
+        let mut found_intersect = false;
+        let mut visitor = |j: usize| {
+            if aabb_dist_squared(&box1, &seg_box2(j)) >= best_dist2 {
+                return aabb_index::Control::Continue;
+            }
+
+            let u1 = pline2.at(j);
+            let u2 = pline2.at(pline2.next_wrapping_index(j));
+            let (p1, p2) = seg_closest_points(v1, v2, u1, u2, pos_equal_eps);
+            let dist2 = dist_squared(p1, p2);
+            if dist2 < best_dist2 {
+                best_dist2 = dist2;
+                result.seg_start_index1 = i;
+                result.point1 = p1;
+                result.seg_start_index2 = j;
+                result.point2 = p2;
+                if dist2 <= pos_equal_eps * pos_equal_eps {
+                    found_intersect = true;
+                    return aabb_index::Control::Break(());
+                }
+            }
+
+            aabb_index::Control::Continue
+        };
+
+        index2.visit_query_with_stack(
+            box1.min_x - d,
+            box1.min_y - d,
+            box1.max_x + d,
+            box1.max_y + d,
+            &mut visitor,
+            &mut query_stack,
+        );
+
+        if found_intersect {
+            break;
+        }
+    }
+
+    result.distance = best_dist2.sqrt();
+
+    Some(result)
+}
+
+/// Sub part of a polyline segment used in the Hausdorff distance branch and bound search.
+#[derive(Debug, Clone, Copy)]
+struct SegPiece<T> {
+    seg_index: usize,
+    t0: T,
+    t1: T,
+    dist0: T,
+    dist1: T,
+    length: T,
+    upper_bound: T,
+}
+
+impl<T> SegPiece<T>
+where
+    T: Real,
+{
+    fn new(seg_index: usize, t0: T, t1: T, dist0: T, dist1: T, length: T) -> Self {
+        // distance to a polyline changes at most one to one with path length traveled so the
+        // maximum distance along the piece is bounded by where the two end point cones meet
+        let upper_bound = (dist0 + dist1 + length) / T::two();
+        Self {
+            seg_index,
+            t0,
+            t1,
+            dist0,
+            dist1,
+            length,
+            upper_bound,
+        }
+    }
+}
+
+impl<T> PartialEq for SegPiece<T>
+where
+    T: Real,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.upper_bound == other.upper_bound
+    }
+}
+
+impl<T> Eq for SegPiece<T> where T: Real {}
+
+impl<T> PartialOrd for SegPiece<T>
+where
+    T: Real,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for SegPiece<T>
+where
+    T: Real,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.upper_bound
+            .partial_cmp(&other.upper_bound)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Compute the directed Hausdorff distance from `pline1` to `pline2` using `pline2_aabb_index` for
+/// closest point queries.
+///
+/// The vertexes of `pline1` give an initial lower bound, then each segment is recursively
+/// subdivided (largest upper bound first) until the upper bound of every remaining piece is within
+/// `tolerance` of the lower bound.
+fn directed_hausdorff_with_index<P, R, T>(
+    pline1: &P,
+    pline2: &R,
+    pline2_aabb_index: Option<&StaticAABB2DIndex<T>>,
+    tolerance: T,
+    pos_equal_eps: T,
+) -> Option<PlineHausdorffResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline1.is_empty() || pline2.is_empty() {
+        return None;
+    }
+
+    // path lengths are given so each closest point query does not sum them segment by segment
+    let pline2_path_lengths = vertex_path_lengths(pline2);
+    let closest_point_options = PlineClosestPointOptions {
+        aabb_index: pline2_aabb_index,
+        vertex_path_lengths: Some(&pline2_path_lengths),
+        pos_equal_eps,
+    };
+
+    let closest_on_pline2 = |point: Vector2<T>| {
+        let cp = pline2
+            .closest_point_opt(point, &closest_point_options)
+            .unwrap();
+        (cp.distance, cp.seg_point)
+    };
+
+    // lower bound from all the vertexes
+    let vertex_dists = pline1
+        .iter_vertexes()
+        .map(|v| {
+            let (dist, point2) = closest_on_pline2(v.pos());
+            (dist, v.pos(), point2)
+        })
+        .collect::<Vec<_>>();
+
+    let mut result = {
+        let (distance, point1, point2) = vertex_dists
+            .iter()
+            .copied()
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .unwrap();
+        PlineHausdorffResult {
+            distance,
+            point1,
+            point2,
+        }
+    };
+
+    let mut queue = BinaryHeap::new();
+    for (i, j) in pline1.iter_segment_indexes() {
+        let length = seg_length(pline1.at(i), pline1.at(j));
+        let piece = SegPiece::new(
+            i,
+            T::zero(),
+            T::one(),
+            vertex_dists[i].0,
+            vertex_dists[j].0,
+            length,
+        );
+        if piece.upper_bound > result.distance + tolerance {
+            queue.push(piece);
+        }
+    }
+
+    while let Some(piece) = queue.pop() {
+        if piece.upper_bound <= result.distance + tolerance {
+            // largest upper bound is within tolerance so all remaining pieces are also
+            break;
+        }
+
+        let v1 = pline1.at(piece.seg_index);
+        let v2 = pline1.at(pline1.next_wrapping_index(piece.seg_index));
+        let t_mid = (piece.t0 + piece.t1) / T::two();
+        let point1 = seg_point_at_parametric(v1, v2, t_mid);
+        let (dist_mid, point2) = closest_on_pline2(point1);
+        if dist_mid > result.distance {
+            result = PlineHausdorffResult {
+                distance: dist_mid,
+                point1,
+                point2,
+            };
+        }
+
+        let half_length = piece.length / T::two();
+        let first = SegPiece::new(
+            piece.seg_index,
+            piece.t0,
+            t_mid,
+            piece.dist0,
+            dist_mid,
+            half_length,
+        );
+        let second = SegPiece::new(
+            piece.seg_index,
+            t_mid,
+            piece.t1,
+            dist_mid,
+            piece.dist1,
+            half_length,
+        );
+
+        for p in [first, second].iter() {
+            if p.upper_bound > result.distance + tolerance {
+                queue.push(*p);
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Compute the directed Hausdorff distance from `pline1` to `pline2`, this is the maximum distance
+/// from any point on `pline1` to the closest point on `pline2`.
+///
+/// Returns `None` if either polyline is empty or the tolerance is not a finite number greater than
+/// zero.
+pub fn directed_hausdorff_distance<P, R, T>(
+    pline1: &P,
+    pline2: &R,
+    options: &PlineHausdorffOptions<T>,
+) -> Option<PlineHausdorffResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    // also rejects NaN and infinity
+    if !(options.tolerance > T::zero() && options.tolerance <= <T as Real>::max_value()) {
+        return None;
+    }
+
+    let constructed_index;
+    let index2 = if options.pline2_aabb_index.is_some() {
+        options.pline2_aabb_index
+    } else {
+        constructed_index = pline2.create_approx_aabb_index();
+        constructed_index.as_ref()
+    };
+
+    directed_hausdorff_with_index(
+        pline1,
+        pline2,
+        index2,
+        options.tolerance,
+        options.pos_equal_eps,
+    )
+}
+
+/// Compute the symmetric Hausdorff distance between `pline1` and `pline2`, this is the maximum of
+/// the directed Hausdorff distance from `pline1` to `pline2` and from `pline2` to `pline1`.
+///
+/// Returns `None` if either polyline is empty or the tolerance is not a finite number greater than
+/// zero.
+pub fn hausdorff_distance<P, R, T>(
+    pline1: &P,
+    pline2: &R,
+    options: &PlineHausdorffOptions<T>,
+) -> Option<PlineHausdorffResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let forward = directed_hausdorff_distance(pline1, pline2, options)?;
+
+    let reverse_options = PlineHausdorffOptions {
+        pline1_aabb_index: options.pline2_aabb_index,
+        pline2_aabb_index: options.pline1_aabb_index,
+        tolerance: options.tolerance,
+        pos_equal_eps: options.pos_equal_eps,
+    };
+
+    let reverse = directed_hausdorff_distance(pline2, pline1, &reverse_options)?;
+
+    if reverse.distance > forward.distance {
+        // swap points so point1 is always on pline1
+        Some(PlineHausdorffResult {
+            distance: reverse.distance,
+            point1: reverse.point2,
+            point2: reverse.point1,
+        })
+    } else {
+        Some(forward)
+    }
+}
+
+/// Collect the points along a polyline used for the discrete Fréchet distance. All vertexes are
+/// included, if `sample_spacing` is greater than zero then additional points are added along each
+/// segment such that the path length between consecutive points does not exceed `sample_spacing`.
+/// For closed polylines the start point is repeated at the end to include the closing segment.
+fn frechet_sample_points<P, T>(pline: &P, sample_spacing: T) -> Vec<Vector2<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut points = Vec::with_capacity(pline.vertex_count() + 1);
+    for (v1, v2) in pline.iter_segments() {
+        points.push(v1.pos());
+        if sample_spacing > T::zero() {
+            let count = (seg_length(v1, v2) / sample_spacing)
+                .ceil()
+                .to_usize()
+                .unwrap_or(1);
+            for k in 1..count {
+                let t = T::from(k).unwrap() / T::from(count).unwrap();
+                points.push(seg_point_at_parametric(v1, v2, t));
+            }
+        }
+    }
+
+    if pline.is_closed() {
+        if let Some(v) = pline.get(0) {
+            points.push(v.pos());
+        }
+    } else if let Some(v) = pline.last() {
+        points.push(v.pos());
+    }
+
+    points
+}
+
+/// Compute the discrete Fréchet distance between `pline1` and `pline2`.
+///
+/// The discrete Fréchet distance is the minimum over all monotone (in order) couplings of the
+/// sample points of the maximum distance between coupled points. Sample points are the vertexes
+/// plus additional points along segments spaced no further than `sample_spacing` apart (if
+/// `sample_spacing` is greater than zero). The discrete Fréchet distance is never less than the
+/// continuous Fréchet distance and converges to it as `sample_spacing` approaches zero.
+///
+/// Returns `None` if either polyline is empty or `sample_spacing` is negative or not a finite
+/// number.
+pub fn discrete_frechet_distance<P, R, T>(pline1: &P, pline2: &R, sample_spacing: T) -> Option<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline1.is_empty() || pline2.is_empty() {
+        return None;
+    }
+
+    // also rejects NaN and infinity
+    if !(sample_spacing >= T::zero() && sample_spacing <= <T as Real>::max_value()) {
+        return None;
+    }
+
+    let points1 = frechet_sample_points(pline1, sample_spacing);
+    let points2 = frechet_sample_points(pline2, sample_spacing);
+
+    // dynamic programming over the coupling table keeping only the previous row (values are squared
+    // distances to avoid square roots until the end)
+    let mut prev_row = vec![T::zero(); points2.len()];
+    let mut curr_row = vec![T::zero(); points2.len()];
+
+    for (i, &p1) in points1.iter().enumerate() {
+        for (j, &p2) in points2.iter().enumerate() {
+            let d = dist_squared(p1, p2);
+            let reachable = match (i, j) {
+                (0, 0) => d,
+                (0, _) => curr_row[j - 1],
+                (_, 0) => prev_row[0],
+                _ => num_traits::real::Real::min(
+                    num_traits::real::Real::min(prev_row[j], prev_row[j - 1]),
+                    curr_row[j - 1],
+                ),
+            };
+            curr_row[j] = num_traits::real::Real::max(reachable, d);
+        }
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    Some(prev_row[points2.len() - 1].sqrt())
+}
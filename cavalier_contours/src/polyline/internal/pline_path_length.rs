@@ -0,0 +1,100 @@
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        seg_length, seg_point_at_parametric, seg_split_at_point, PlineSource, PlineViewData,
+    },
+};
+
+/// Path lengths at each vertex of a polyline, last entry is the total path length (including the
+/// closing segment if the polyline is closed).
+pub fn vertex_path_lengths<P, T>(pline: &P) -> Vec<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut result = Vec::with_capacity(pline.vertex_count() + 1);
+    let mut acc = T::zero();
+    result.push(acc);
+    for (v1, v2) in pline.iter_segments() {
+        acc = acc + seg_length(v1, v2);
+        result.push(acc);
+    }
+    result
+}
+
+/// Wrap `value` to be within `[0, total)`.
+pub fn wrap_path_length<T>(value: T, total: T) -> T
+where
+    T: Real,
+{
+    let r = value - (value / total).floor() * total;
+    if r >= total {
+        T::zero()
+    } else {
+        r
+    }
+}
+
+/// Segment index and point at path length `length` (must be within `[0, total)`).
+pub fn point_at_path_length<P, T>(pline: &P, lengths: &[T], length: T) -> (usize, Vector2<T>)
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let seg_count = lengths.len() - 1;
+    // last segment that starts at or before length
+    let i = lengths[..seg_count]
+        .partition_point(|&l| l <= length)
+        .saturating_sub(1);
+    let v1 = pline.at(i);
+    let v2 = pline.at(pline.next_wrapping_index(i));
+    let seg_len = lengths[i + 1] - lengths[i];
+    if seg_len <= T::zero() {
+        return (i, v1.pos());
+    }
+    let t = (length - lengths[i]) / seg_len;
+    (i, seg_point_at_parametric(v1, v2, t))
+}
+
+/// View data for the slice of a closed polyline between two path lengths (`end` may be greater
+/// than the total path length to wrap past the first vertex).
+pub fn path_range_view_data<P, T>(
+    pline: &P,
+    lengths: &[T],
+    start: T,
+    end: T,
+    eps: T,
+) -> Option<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let total = lengths[lengths.len() - 1];
+    let start = wrap_path_length(start, total);
+    let end = wrap_path_length(end, total);
+    let (mut start_index, start_point) = point_at_path_length(pline, lengths, start);
+    let (end_index, end_point) = point_at_path_length(pline, lengths, end);
+
+    let next_index = pline.next_wrapping_index(start_index);
+    if pline.at(next_index).pos().fuzzy_eq_eps(start_point, eps) {
+        start_index = next_index;
+    }
+
+    if start_index == end_index && end < start {
+        // slice wraps around the entire polyline, starting and ending on the same segment
+        let v1 = pline.at(start_index);
+        let v2 = pline.at(pline.next_wrapping_index(start_index));
+        let split = seg_split_at_point(v1, v2, start_point, eps);
+        return Some(PlineViewData::create(
+            pline,
+            start_index,
+            end_point,
+            end_index,
+            split.split_vertex,
+            pline.vertex_count(),
+            eps,
+        ));
+    }
+
+    PlineViewData::from_slice_points(pline, start_point, start_index, end_point, end_index, eps)
+}
@@ -0,0 +1,345 @@
+use crate::{
+    core::{
+        math::{
+            line_circle_intr, line_line_intr, point_within_arc_sweep, LineCircleIntr, LineLineIntr,
+            Vector2,
+        },
+        traits::Real,
+    },
+    polyline::{
+        seg_arc_radius_and_center, seg_tangent_vector, PlineRayCrossing, PlineRayHit,
+        PlineRayOptions, PlineSource,
+    },
+};
+use static_aabb2d_index::{StaticAABB2DIndex, AABB};
+
+/// Visit all the items in `aabb_index` for which `box_pred` returns true for the item bounding box
+/// and all of its ancestor node bounding boxes.
+///
+/// This allows querying the spatial index with shapes other than axis aligned boxes (e.g. rays and
+/// lines), `box_pred` must return true for a box if the shape may overlap the box.
+pub fn visit_index_with_predicate<T, P, V>(
+    aabb_index: &StaticAABB2DIndex<T>,
+    box_pred: &mut P,
+    visitor: &mut V,
+    stack: &mut Vec<usize>,
+) where
+    T: Real,
+    P: FnMut(&AABB<T>) -> bool,
+    V: FnMut(usize),
+{
+    let boxes = aabb_index.all_boxes();
+    let level_bounds = aabb_index.level_bounds();
+    let node_size = aabb_index.node_size();
+    let num_items = aabb_index.count();
+
+    let mut node_index = boxes.len() - 1;
+    let mut level = level_bounds.len() - 1;
+    stack.clear();
+
+    loop {
+        let end = std::cmp::min(node_index + node_size, level_bounds[level]);
+
+        for (pos, aabb) in boxes.iter().enumerate().take(end).skip(node_index) {
+            if !box_pred(aabb) {
+                continue;
+            }
+
+            let index = aabb_index.map_all_boxes_index(pos);
+            if node_index < num_items {
+                visitor(index);
+            } else {
+                stack.push(index);
+                stack.push(level - 1);
+            }
+        }
+
+        if stack.len() > 1 {
+            level = stack.pop().unwrap();
+            node_index = stack.pop().unwrap();
+        } else {
+            return;
+        }
+    }
+}
+
+/// Returns true if the line `origin + t * dir` for `t >= t_min` intersects `aabb` expanded by
+/// `eps` (slab test).
+fn line_overlaps_box<T>(
+    origin: Vector2<T>,
+    dir: Vector2<T>,
+    t_min: T,
+    aabb: &AABB<T>,
+    eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let mut t_lo = t_min;
+    let mut t_hi = <T as Real>::max_value();
+
+    let mut clip_axis = |p: T, d: T, min: T, max: T| -> bool {
+        let min = min - eps;
+        let max = max + eps;
+        if d.abs() < T::epsilon() {
+            return p >= min && p <= max;
+        }
+
+        let t1 = (min - p) / d;
+        let t2 = (max - p) / d;
+        let (t1, t2) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+        t_lo = num_traits::real::Real::max(t_lo, t1);
+        t_hi = num_traits::real::Real::min(t_hi, t2);
+        t_lo <= t_hi
+    };
+
+    clip_axis(origin.x, dir.x, aabb.min_x, aabb.max_x)
+        && clip_axis(origin.y, dir.y, aabb.min_y, aabb.max_y)
+}
+
+/// Where a raw hit lies on the polyline before classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HitLocation {
+    /// Hit at the vertex with the index given.
+    Vertex(usize),
+    /// Hit in the interior of the segment starting at the index given.
+    Segment(usize),
+}
+
+/// Find all the hits between the line `origin + t * direction` (for `t >= t_min`) and the
+/// polyline, sorted by `t`.
+fn find_line_hits<P, T>(
+    pline: &P,
+    origin: Vector2<T>,
+    direction: Vector2<T>,
+    t_min: T,
+    options: &PlineRayOptions<T>,
+) -> Vec<PlineRayHit<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let dir_length = direction.length();
+    if pline.vertex_count() < 2 || dir_length.fuzzy_eq_zero_eps(T::epsilon()) {
+        return Vec::new();
+    }
+
+    let dir = direction.scale(T::one() / dir_length);
+
+    let constructed_index;
+    let aabb_index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = pline.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    let vc = pline.vertex_count();
+    let is_closed = pline.is_closed();
+    let last_seg_index = if is_closed { vc - 1 } else { vc - 2 };
+
+    // location of a hit point on the segment starting at i
+    let hit_location = |i: usize, point: Vector2<T>| {
+        let next = pline.next_wrapping_index(i);
+        if point.fuzzy_eq_eps(pline.at(i).pos(), eps) {
+            HitLocation::Vertex(i)
+        } else if point.fuzzy_eq_eps(pline.at(next).pos(), eps) {
+            HitLocation::Vertex(next)
+        } else {
+            HitLocation::Segment(i)
+        }
+    };
+
+    let mut raw_hits: Vec<(HitLocation, T)> = Vec::new();
+    let mut add_hit = |i: usize, t: T| {
+        if t < t_min - eps {
+            return;
+        }
+        let point = origin + dir.scale(t);
+        let location = hit_location(i, point);
+        raw_hits.push((location, t));
+    };
+
+    let p1 = origin + dir;
+    let mut visit_seg = |i: usize| {
+        let v1 = pline.at(i);
+        let v2 = pline.at(pline.next_wrapping_index(i));
+        if v1.pos().fuzzy_eq_eps(v2.pos(), eps) {
+            // skip zero length segments
+            return;
+        }
+
+        if v1.bulge_is_zero() {
+            let seg_length = (v2.pos() - v1.pos()).length();
+            match line_line_intr(origin, p1, v1.pos(), v2.pos(), eps) {
+                LineLineIntr::TrueIntersect { seg1_t, seg2_t }
+                | LineLineIntr::FalseIntersect { seg1_t, seg2_t } => {
+                    if (seg2_t * seg_length).fuzzy_in_range_eps(T::zero(), seg_length, eps) {
+                        add_hit(i, seg1_t);
+                    }
+                }
+                LineLineIntr::Overlapping { .. } => {
+                    // collinear, hits at the segment end points
+                    add_hit(i, (v1.pos() - origin).dot(dir));
+                    add_hit(i, (v2.pos() - origin).dot(dir));
+                }
+                LineLineIntr::NoIntersect => {}
+            }
+        } else {
+            let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+            let mut process_t = |t: T| {
+                let point = origin + dir.scale(t);
+                if point_within_arc_sweep(
+                    arc_center,
+                    v1.pos(),
+                    v2.pos(),
+                    v1.bulge_is_neg(),
+                    point,
+                    eps,
+                ) {
+                    add_hit(i, t);
+                }
+            };
+            match line_circle_intr(origin, p1, arc_radius, arc_center, eps) {
+                LineCircleIntr::NoIntersect => {}
+                LineCircleIntr::TangentIntersect { t0 } => process_t(t0),
+                LineCircleIntr::TwoIntersects { t0, t1 } => {
+                    process_t(t0);
+                    process_t(t1);
+                }
+            }
+        }
+    };
+
+    let mut query_stack = Vec::with_capacity(8);
+    visit_index_with_predicate(
+        aabb_index,
+        &mut |aabb: &AABB<T>| line_overlaps_box(origin, dir, t_min, aabb, eps),
+        &mut |i: usize| visit_seg(i),
+        &mut query_stack,
+    );
+
+    // vertex hits are found by both adjacent segments, remove duplicates
+    raw_hits.sort_unstable_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    let mut unique_hits: Vec<(HitLocation, T)> = Vec::with_capacity(raw_hits.len());
+    for hit in raw_hits {
+        let is_duplicate = match hit.0 {
+            HitLocation::Vertex(vi) => unique_hits
+                .iter()
+                .rev()
+                .take_while(|h| (hit.1 - h.1).fuzzy_eq_zero_eps(eps))
+                .any(|h| h.0 == HitLocation::Vertex(vi)),
+            HitLocation::Segment(_) => false,
+        };
+        if !is_duplicate {
+            unique_hits.push(hit);
+        }
+    }
+
+    let is_cw = is_closed && pline.area() < T::zero();
+    let side_of_ray = |v: Vector2<T>| {
+        let s = dir.perp_dot(v.normalize());
+        if s.fuzzy_eq_zero_eps(eps) {
+            T::zero()
+        } else {
+            s
+        }
+    };
+
+    // classify crossing given the polyline directions just before and just after the hit
+    let classify = |dir_before: Option<Vector2<T>>, dir_after: Option<Vector2<T>>| {
+        let (dir_before, dir_after) = match (dir_before, dir_after) {
+            (Some(b), Some(a)) => (b, a),
+            _ => return PlineRayCrossing::Touching,
+        };
+
+        let side_before = side_of_ray(-dir_before);
+        let side_after = side_of_ray(dir_after);
+        if side_before == T::zero()
+            || side_after == T::zero()
+            || (side_before > T::zero()) == (side_after > T::zero())
+        {
+            return PlineRayCrossing::Touching;
+        }
+
+        // polyline going from right of ray to left of ray means ray is leaving the left side of
+        // the polyline
+        let leaving_left = side_after > T::zero();
+        if leaving_left != is_cw {
+            PlineRayCrossing::Exiting
+        } else {
+            PlineRayCrossing::Entering
+        }
+    };
+
+    unique_hits
+        .into_iter()
+        .map(|(location, t)| match location {
+            HitLocation::Vertex(vi) => {
+                let v = pline.at(vi);
+                let incoming = if vi > 0 || is_closed {
+                    let prev = pline.at(pline.prev_wrapping_index(vi));
+                    Some(seg_tangent_vector(prev, v, v.pos()))
+                } else {
+                    None
+                };
+                let outgoing = if vi < vc - 1 || is_closed {
+                    let next = pline.at(pline.next_wrapping_index(vi));
+                    Some(seg_tangent_vector(v, next, v.pos()))
+                } else {
+                    None
+                };
+
+                PlineRayHit {
+                    seg_start_index: std::cmp::min(vi, last_seg_index),
+                    point: v.pos(),
+                    distance: t,
+                    crossing: classify(incoming, outgoing),
+                }
+            }
+            HitLocation::Segment(i) => {
+                let v1 = pline.at(i);
+                let v2 = pline.at(pline.next_wrapping_index(i));
+                let point = origin + dir.scale(t);
+                let tangent = seg_tangent_vector(v1, v2, point);
+                PlineRayHit {
+                    seg_start_index: i,
+                    point,
+                    distance: t,
+                    crossing: classify(Some(tangent), Some(tangent)),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Find all the hits between the ray starting at `origin` going in `direction` and the polyline,
+/// sorted by distance from `origin`.
+pub fn ray_intersects<P, T>(
+    pline: &P,
+    origin: Vector2<T>,
+    direction: Vector2<T>,
+    options: &PlineRayOptions<T>,
+) -> Vec<PlineRayHit<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    find_line_hits(pline, origin, direction, T::zero(), options)
+}
+
+/// Find all the hits between the infinite line passing through `p0` and `p1` and the polyline,
+/// sorted by signed distance from `p0` in the direction of `p1`.
+pub fn line_intersects<P, T>(
+    pline: &P,
+    p0: Vector2<T>,
+    p1: Vector2<T>,
+    options: &PlineRayOptions<T>,
+) -> Vec<PlineRayHit<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    find_line_hits(pline, p0, p1 - p0, <T as Real>::min_value(), options)
+}
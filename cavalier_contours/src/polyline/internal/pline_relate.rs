@@ -0,0 +1,115 @@
+use super::pline_boolean::{process_for_boolean, slice_at_intersects, ProcessForBooleanResult};
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{PlineRelateOptions, PlineRelation, PlineSource},
+};
+
+/// Tracks which sides of the other polyline the slices of a polyline fall on.
+#[derive(Debug, Default, Clone, Copy)]
+struct SliceSides {
+    any_inside: bool,
+    any_outside: bool,
+}
+
+/// Classify all the non-overlapping slices of `pline` (formed by slicing at the intersects) as
+/// inside or outside of `other`.
+fn classify_slices<P, R, T>(
+    pline: &P,
+    other: &R,
+    boolean_info: &ProcessForBooleanResult<T>,
+    use_second_index: bool,
+    pos_equal_eps: T,
+) -> SliceSides
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut sides = SliceSides::default();
+    // predicate records the side and always returns false so no slices are collected
+    let mut record_side = |point: Vector2<T>| {
+        if other.winding_number(point) != 0 {
+            sides.any_inside = true;
+        } else {
+            sides.any_outside = true;
+        }
+        false
+    };
+
+    let mut unused = Vec::new();
+    slice_at_intersects(
+        pline,
+        boolean_info,
+        use_second_index,
+        &mut record_side,
+        &mut unused,
+        pos_equal_eps,
+    );
+
+    sides
+}
+
+/// Determine the spatial relationship between two closed polylines.
+///
+/// Intersects (including overlapping segments) are found using the same process as boolean
+/// operations, then each polyline is sliced at the intersects and the slices are classified as
+/// inside or outside of the other polyline (overlapping slices lie on both boundaries).
+pub fn relate<P, R, T>(pline1: &P, pline2: &R, options: &PlineRelateOptions<T>) -> PlineRelation
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline1.vertex_count() < 2
+        || !pline1.is_closed()
+        || pline2.vertex_count() < 2
+        || !pline2.is_closed()
+    {
+        return PlineRelation::InvalidInput;
+    }
+
+    let constructed_index;
+    let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
+        x
+    } else {
+        constructed_index = pline1.create_approx_aabb_index().unwrap();
+        &constructed_index
+    };
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let boolean_info = process_for_boolean(pline1, pline2, pline1_aabb_index, pos_equal_eps);
+
+    if boolean_info.completely_overlapping() {
+        return PlineRelation::Equal;
+    }
+
+    if !boolean_info.any_intersects() {
+        if pline2.winding_number(pline1.at(0).pos()) != 0 {
+            return PlineRelation::Within;
+        }
+        if pline1.winding_number(pline2.at(0).pos()) != 0 {
+            return PlineRelation::Contains;
+        }
+        return PlineRelation::Disjoint;
+    }
+
+    let sides1 = classify_slices(pline1, pline2, &boolean_info, false, pos_equal_eps);
+    let sides2 = classify_slices(pline2, pline1, &boolean_info, true, pos_equal_eps);
+
+    let pline1_covered = !sides1.any_outside && !sides2.any_inside;
+    let pline2_covered = !sides2.any_outside && !sides1.any_inside;
+
+    match (pline1_covered, pline2_covered) {
+        // all slices are overlapping (boundaries are the same)
+        (true, true) => PlineRelation::Equal,
+        (true, false) => PlineRelation::Within,
+        (false, true) => PlineRelation::Contains,
+        (false, false) => {
+            if sides1.any_inside || sides2.any_inside {
+                PlineRelation::Overlaps
+            } else {
+                PlineRelation::Touches
+            }
+        }
+    }
+}
@@ -2,16 +2,66 @@
 //! polyline segments.
 pub mod internal;
 mod pline;
+mod pline_arrangement;
+mod pline_containment;
+mod pline_convex_decomposition;
+mod pline_corner_relief;
+mod pline_dash;
+mod pline_distance_field;
+mod pline_hatch;
+mod pline_inscribed_circle;
+mod pline_lead;
+mod pline_minkowski;
+mod pline_no_fit;
+mod pline_point_locator;
+mod pline_rasterize;
 mod pline_seg;
 mod pline_seg_intersect;
+mod pline_swept_area;
+mod pline_tabs;
+mod pline_tool_compensation;
+mod pline_toolpath_order;
+mod pline_triangulate;
 mod pline_types;
 mod pline_vertex;
 mod pline_view;
 mod traits;
 
 pub use pline::*;
+pub use pline_arrangement::{
+    ArrangementEdge, ArrangementEdgeSource, ArrangementFace, PlineArrangement,
+    PlineArrangementOptions,
+};
+pub use pline_containment::{PlineContainmentNode, PlineContainmentTree};
+pub use pline_convex_decomposition::{convex_decomposition, ConvexDecompositionOptions};
+pub use pline_corner_relief::{
+    add_corner_relief, CornerReliefKind, CornerReliefOptions, CornerReliefResult,
+};
+pub use pline_dash::{dash_pattern, dash_pattern_views, DashOptions};
+pub use pline_distance_field::{DistanceField, DistanceFieldClosest};
+pub use pline_hatch::{hatch_fill, HatchOptions};
+pub use pline_inscribed_circle::{
+    maximum_inscribed_circle, InscribedCircle, InscribedCircleOptions,
+};
+pub use pline_lead::{add_leads, LeadEntry, LeadOptions, LeadResult, LeadShape, LeadSide};
+pub use pline_minkowski::{minkowski_difference, minkowski_sum, MinkowskiOptions, MinkowskiResult};
+pub use pline_no_fit::{inner_fit_polygon, no_fit_polygon};
+pub use pline_point_locator::{PointLocation, PointLocator};
+pub use pline_rasterize::{
+    rasterize_bitmap, rasterize_coverage, FillRule, RasterGrid, RasterizeOptions,
+};
 pub use pline_seg::*;
 pub use pline_seg_intersect::*;
+pub use pline_swept_area::swept_area;
+pub use pline_tabs::{insert_tabs, TabOptions, TabPlacement, TabResult, TabSlice};
+pub use pline_tool_compensation::{
+    compensate_tool_radius, CompensatedLoop, CutSide, ToolCompensationOptions,
+    ToolCompensationResult,
+};
+pub use pline_toolpath_order::{
+    order_toolpaths, ToolpathOrderOptions, ToolpathOrderResult, ToolpathTravel,
+};
+pub use pline_triangulate::{triangulate, TriangleMesh, TriangulateOptions};
 pub use pline_types::*;
 pub use pline_vertex::*;
 pub use pline_view::*;
@@ -0,0 +1,694 @@
+use super::{
+    pline_seg_intr, seg_arc_radius_and_center, seg_bounding_box, seg_midpoint, seg_split_at_point,
+    PlineCreation, PlineSegIntr, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+};
+use crate::core::{
+    math::{angle, angle_from_bulge, normalize_radians, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+use std::collections::{HashMap, VecDeque};
+
+/// Options for computing a [PlineArrangement].
+#[derive(Debug, Clone)]
+pub struct PlineArrangementOptions<T = f64> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineArrangementOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineArrangementOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Input polyline segment an [ArrangementEdge] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrangementEdgeSource {
+    /// Index of the input polyline (in the order given to [PlineArrangement::new]).
+    pub pline_index: usize,
+    /// Start vertex index of the input polyline segment.
+    pub seg_start_index: usize,
+    /// True if the input segment has the same direction as the edge (start to end).
+    pub same_direction: bool,
+}
+
+/// Edge of a [PlineArrangement], a line or arc segment between two vertexes with no other
+/// vertexes or intersects along it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrangementEdge<T = f64> {
+    /// Index of the start vertex.
+    pub start: usize,
+    /// Index of the end vertex.
+    pub end: usize,
+    /// Bulge of the segment going from start to end.
+    pub bulge: T,
+    /// All the input segments overlapping the edge (more than one where inputs overlap).
+    pub sources: Vec<ArrangementEdgeSource>,
+}
+
+/// Bounded face of a [PlineArrangement].
+#[derive(Debug, Clone)]
+pub struct ArrangementFace<T = f64> {
+    /// Counter clockwise closed polyline bounding the face.
+    pub boundary: Polyline<T>,
+    /// Index of the edge of each segment of [ArrangementFace::boundary].
+    pub boundary_edges: Vec<usize>,
+    /// Clockwise closed polylines bounding holes in the face (parts of the arrangement not
+    /// connected to the face boundary that lie inside of it).
+    pub holes: Vec<Polyline<T>>,
+    /// Winding number of the points in the face for each input polyline (in the order given to
+    /// [PlineArrangement::new]), always 0 for open polylines.
+    pub winding_numbers: Vec<i32>,
+}
+
+/// Planar arrangement (overlay) of a set of polylines.
+///
+/// The arrangement is the subdivision of the plane by all the input polylines (open or closed):
+/// vertexes at all segment end points and intersects, edges as the segments between them (keeping
+/// the input segments they came from), and the bounded faces formed as closed loops with the
+/// winding numbers of each input. This is the slice and stitch process used for boolean
+/// operations between two polylines generalized to any number of inputs, the faces can be
+/// selected by their winding numbers for any combination of regions (see
+/// [PlineArrangement::select_region]).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let a: Polyline = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+/// let b: Polyline = pline_closed![(2.0, 2.0, 0.0), (6.0, 2.0, 0.0), (6.0, 6.0, 0.0), (2.0, 6.0, 0.0)];
+/// let arrangement = PlineArrangement::new([&a, &b], &PlineArrangementOptions::default());
+/// // a only, b only, and the overlap
+/// assert_eq!(arrangement.faces.len(), 3);
+/// let overlap = arrangement
+///     .faces
+///     .iter()
+///     .find(|f| f.winding_numbers == [1, 1])
+///     .unwrap();
+/// assert_fuzzy_eq!(overlap.boundary.area(), 4.0);
+/// // symmetric difference
+/// let xor = arrangement.select_region(|w| (w[0] != 0) != (w[1] != 0));
+/// assert_fuzzy_eq!(xor.iter().map(|p| p.area()).sum::<f64>(), 24.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PlineArrangement<T = f64> {
+    /// All the vertexes of the arrangement.
+    pub vertexes: Vec<Vector2<T>>,
+    /// All the edges of the arrangement.
+    pub edges: Vec<ArrangementEdge<T>>,
+    /// All the bounded faces of the arrangement.
+    pub faces: Vec<ArrangementFace<T>>,
+    /// Face to the left of each half edge (half edge `2 * i` goes from start to end of edge `i`
+    /// and `2 * i + 1` goes from end to start), `None` for the unbounded face.
+    half_edge_faces: Vec<Option<usize>>,
+    /// Next half edge around the face to the left of each half edge.
+    half_edge_next: Vec<usize>,
+}
+
+/// Snap positions within `eps` of each other together, returns the index of the position each
+/// position is snapped to (the first position within `eps` of it).
+pub(crate) fn snap_positions<T>(points: &[Vector2<T>], eps: T) -> Vec<usize>
+where
+    T: Real,
+{
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let mut builder = StaticAABB2DIndexBuilder::new(points.len());
+    for p in points.iter() {
+        builder.add(p.x - eps, p.y - eps, p.x + eps, p.y + eps);
+    }
+    let index: StaticAABB2DIndex<T> = builder.build().unwrap();
+    let mut snapped: Vec<usize> = Vec::with_capacity(points.len());
+    let mut query_stack = Vec::new();
+    for (i, p) in points.iter().enumerate() {
+        let mut first = i;
+        index.visit_query_with_stack(
+            p.x,
+            p.y,
+            p.x,
+            p.y,
+            &mut |j: usize| {
+                if j < first {
+                    first = j;
+                }
+            },
+            &mut query_stack,
+        );
+        let target = if first < i { snapped[first] } else { i };
+        snapped.push(target);
+    }
+    snapped
+}
+
+/// Split the segments at all their intersects with each other, returns the sub segments (in
+/// order along each segment) with the index of the segment they came from.
+pub(crate) fn split_at_intersects<T>(
+    segments: &[(PlineVertex<T>, PlineVertex<T>)],
+    eps: T,
+) -> Vec<(PlineVertex<T>, PlineVertex<T>, usize)>
+where
+    T: Real,
+{
+    if segments.is_empty() {
+        return Vec::new();
+    }
+
+    let index: StaticAABB2DIndex<T> = {
+        let mut builder = StaticAABB2DIndexBuilder::new(segments.len());
+        for &(v1, v2) in segments.iter() {
+            let bb = seg_bounding_box(v1, v2);
+            builder.add(bb.min_x, bb.min_y, bb.max_x, bb.max_y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut split_points = vec![Vec::new(); segments.len()];
+    let mut query_stack = Vec::new();
+    for (i, &(v1, v2)) in segments.iter().enumerate() {
+        let bb = seg_bounding_box(v1, v2);
+        let mut add_split = |j: usize| {
+            if j <= i {
+                return;
+            }
+            let (u1, u2) = segments[j];
+            let points = match pline_seg_intr(v1, v2, u1, u2, eps) {
+                PlineSegIntr::NoIntersect => return,
+                PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+                    [Some(point), None]
+                }
+                PlineSegIntr::TwoIntersects { point1, point2 }
+                | PlineSegIntr::OverlappingLines { point1, point2 }
+                | PlineSegIntr::OverlappingArcs { point1, point2 } => [Some(point1), Some(point2)],
+            };
+            for point in points.iter().flatten() {
+                split_points[i].push(*point);
+                split_points[j].push(*point);
+            }
+        };
+        index.visit_query_with_stack(
+            bb.min_x - eps,
+            bb.min_y - eps,
+            bb.max_x + eps,
+            bb.max_y + eps,
+            &mut add_split,
+            &mut query_stack,
+        );
+    }
+
+    let mut result = Vec::with_capacity(segments.len());
+    for (i, &(v1, v2)) in segments.iter().enumerate() {
+        let points = &mut split_points[i];
+        let param = |p: &Vector2<T>| {
+            if v1.bulge_is_zero() {
+                (*p - v1.pos()).dot(v2.pos() - v1.pos())
+            } else {
+                let (_, center) = seg_arc_radius_and_center(v1, v2);
+                let start_angle = angle(center, v1.pos());
+                let d = normalize_radians(angle(center, *p) - start_angle);
+                let d = if v1.bulge_is_neg() { T::tau() - d } else { d };
+                if d >= T::tau() - T::fuzzy_epsilon() {
+                    T::zero()
+                } else {
+                    d
+                }
+            }
+        };
+        points.sort_by(|p1, p2| param(p1).partial_cmp(&param(p2)).unwrap());
+
+        let mut current = v1;
+        for &point in points.iter() {
+            if point.fuzzy_eq_eps(current.pos(), eps) || point.fuzzy_eq_eps(v2.pos(), eps) {
+                continue;
+            }
+            let split = seg_split_at_point(current, v2, point, eps);
+            result.push((
+                split.updated_start,
+                split.split_vertex.with_bulge(T::zero()),
+                i,
+            ));
+            current = split.split_vertex;
+        }
+        result.push((current, v2, i));
+    }
+
+    result
+}
+
+/// Find root of `i` with path halving.
+pub(crate) fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Tangent direction at the start of the segment and signed curvature (positive turning left),
+/// used to order half edges around a vertex.
+fn start_direction<T>(v1: PlineVertex<T>, v2: PlineVertex<T>) -> (T, T)
+where
+    T: Real,
+{
+    let chord = v2.pos() - v1.pos();
+    let chord_dir = chord.y.atan2(chord.x);
+    if v1.bulge_is_zero() {
+        return (normalize_radians(chord_dir), T::zero());
+    }
+    let sweep = angle_from_bulge(v1.bulge);
+    let (radius, _) = seg_arc_radius_and_center(v1, v2);
+    let curvature = if v1.bulge_is_neg() {
+        -T::one() / radius
+    } else {
+        T::one() / radius
+    };
+    (normalize_radians(chord_dir - sweep / T::two()), curvature)
+}
+
+impl<T> PlineArrangement<T>
+where
+    T: Real,
+{
+    /// Compute the arrangement of `plines` (open or closed).
+    ///
+    /// Segments of all the polylines are split at their intersects with each other, end points
+    /// within [PlineArrangementOptions::pos_equal_eps] are merged into a single vertex and
+    /// overlapping segments are merged into a single edge with multiple sources. Faces are found
+    /// by walking the edges around each vertex in angle order, the winding numbers of the faces
+    /// are found by crossing edges starting from the unbounded face (winding numbers all 0).
+    /// Polylines with less than 2 vertexes are ignored, and segments with non-finite coordinates
+    /// or bulge are skipped.
+    pub fn new<'a, P, I>(plines: I, options: &PlineArrangementOptions<T>) -> Self
+    where
+        P: PlineSource<Num = T> + 'a + ?Sized,
+        I: IntoIterator<Item = &'a P>,
+    {
+        let eps = options.pos_equal_eps;
+        let is_finite = |x: T| x.abs() <= <T as Real>::max_value();
+        let plines = plines.into_iter().collect::<Vec<_>>();
+        let is_closed = plines.iter().map(|p| p.is_closed()).collect::<Vec<_>>();
+        let input_count = plines.len();
+
+        let mut segments = Vec::new();
+        let mut segment_sources = Vec::new();
+        for (pline_index, pline) in plines.iter().enumerate() {
+            if pline.vertex_count() < 2 {
+                continue;
+            }
+            for ((seg_start_index, _), (v1, v2)) in
+                pline.iter_segment_indexes().zip(pline.iter_segments())
+            {
+                if ![v1.x, v1.y, v1.bulge, v2.x, v2.y]
+                    .iter()
+                    .all(|&c| is_finite(c))
+                {
+                    // segment cannot be placed in the spatial index
+                    continue;
+                }
+                if v1.pos().fuzzy_eq_eps(v2.pos(), eps) {
+                    continue;
+                }
+                segments.push((v1, v2));
+                segment_sources.push((pline_index, seg_start_index));
+            }
+        }
+
+        let mut result = Self::default();
+        if segments.is_empty() {
+            return result;
+        }
+
+        // snap end points of the input segments together before finding intersects
+        let end_points = segments
+            .iter()
+            .flat_map(|(v1, v2)| std::iter::once(v1.pos()).chain(std::iter::once(v2.pos())))
+            .collect::<Vec<_>>();
+        let snapped = snap_positions(&end_points, eps);
+        for (i, (v1, v2)) in segments.iter_mut().enumerate() {
+            *v1 = PlineVertex::from_vector2(end_points[snapped[2 * i]], v1.bulge);
+            *v2 = PlineVertex::from_vector2(end_points[snapped[2 * i + 1]], v2.bulge);
+        }
+
+        let sub_segments = split_at_intersects(&segments, eps);
+
+        // vertexes from the sub segment end points
+        let end_points = sub_segments
+            .iter()
+            .flat_map(|(v1, v2, _)| std::iter::once(v1.pos()).chain(std::iter::once(v2.pos())))
+            .collect::<Vec<_>>();
+        let snapped = snap_positions(&end_points, eps);
+        let mut vertex_of = vec![usize::MAX; end_points.len()];
+        for i in 0..end_points.len() {
+            let target = snapped[i];
+            if vertex_of[target] == usize::MAX {
+                vertex_of[target] = result.vertexes.len();
+                result.vertexes.push(end_points[target]);
+            }
+            vertex_of[i] = vertex_of[target];
+        }
+
+        // edges with overlapping sub segments merged
+        let mut edges_by_vertexes: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (i, &(v1, v2, seg_index)) in sub_segments.iter().enumerate() {
+            let start = vertex_of[2 * i];
+            let end = vertex_of[2 * i + 1];
+            if start == end {
+                continue;
+            }
+            let (pline_index, seg_start_index) = segment_sources[seg_index];
+            let midpoint = seg_midpoint(v1, v2);
+            let key = (start.min(end), start.max(end));
+            let candidates = edges_by_vertexes.entry(key).or_default();
+            let existing = candidates.iter().copied().find(|&e| {
+                let edge: &ArrangementEdge<T> = &result.edges[e];
+                let u1 = PlineVertex::from_vector2(result.vertexes[edge.start], edge.bulge);
+                let u2 = PlineVertex::from_vector2(result.vertexes[edge.end], T::zero());
+                seg_midpoint(u1, u2).fuzzy_eq_eps(midpoint, eps)
+            });
+            match existing {
+                Some(e) => {
+                    let edge = &mut result.edges[e];
+                    edge.sources.push(ArrangementEdgeSource {
+                        pline_index,
+                        seg_start_index,
+                        same_direction: edge.start == start,
+                    });
+                }
+                None => {
+                    candidates.push(result.edges.len());
+                    result.edges.push(ArrangementEdge {
+                        start,
+                        end,
+                        bulge: v1.bulge,
+                        sources: vec![ArrangementEdgeSource {
+                            pline_index,
+                            seg_start_index,
+                            same_direction: true,
+                        }],
+                    });
+                }
+            }
+        }
+
+        result.build_faces(eps);
+        result.compute_winding_numbers(input_count, &is_closed);
+        result
+    }
+
+    #[inline]
+    fn half_edge_vertexes(&self, half_edge: usize) -> (PlineVertex<T>, PlineVertex<T>) {
+        let edge = &self.edges[half_edge / 2];
+        let start = self.vertexes[edge.start];
+        let end = self.vertexes[edge.end];
+        if half_edge % 2 == 0 {
+            (
+                PlineVertex::from_vector2(start, edge.bulge),
+                PlineVertex::from_vector2(end, T::zero()),
+            )
+        } else {
+            (
+                PlineVertex::from_vector2(end, -edge.bulge),
+                PlineVertex::from_vector2(start, T::zero()),
+            )
+        }
+    }
+
+    #[inline]
+    fn half_edge_origin(&self, half_edge: usize) -> usize {
+        let edge = &self.edges[half_edge / 2];
+        if half_edge % 2 == 0 {
+            edge.start
+        } else {
+            edge.end
+        }
+    }
+
+    /// Closed polyline from a cycle of half edges.
+    fn cycle_pline(&self, cycle: &[usize]) -> Polyline<T> {
+        let mut pline = Polyline::with_capacity(cycle.len(), true);
+        for &h in cycle.iter() {
+            let (v1, _) = self.half_edge_vertexes(h);
+            pline.add_vertex(v1);
+        }
+        pline
+    }
+
+    /// Link the half edges around the faces and build the face boundaries and holes.
+    fn build_faces(&mut self, eps: T) {
+        let half_edge_count = 2 * self.edges.len();
+
+        // outgoing half edges around each vertex in counter clockwise order
+        let mut outgoing = vec![Vec::new(); self.vertexes.len()];
+        for h in 0..half_edge_count {
+            outgoing[self.half_edge_origin(h)].push(h);
+        }
+        let mut position = vec![0; half_edge_count];
+        for around in outgoing.iter_mut() {
+            let mut keyed = around
+                .iter()
+                .map(|&h| {
+                    let (v1, v2) = self.half_edge_vertexes(h);
+                    (start_direction(v1, v2), h)
+                })
+                .collect::<Vec<_>>();
+            keyed.sort_by(|((a1, c1), _), ((a2, c2), _)| {
+                if (*a1 - *a2).abs() < T::fuzzy_epsilon() {
+                    c1.partial_cmp(c2).unwrap()
+                } else {
+                    a1.partial_cmp(a2).unwrap()
+                }
+            });
+            for (i, (_, h)) in keyed.iter().enumerate() {
+                around[i] = *h;
+                position[*h] = i;
+            }
+        }
+
+        // next half edge around the face on the left is the outgoing half edge clockwise from
+        // the twin at the end vertex
+        self.half_edge_next = (0..half_edge_count)
+            .map(|h| {
+                let twin = h ^ 1;
+                let around = &outgoing[self.half_edge_origin(twin)];
+                around[(position[twin] + around.len() - 1) % around.len()]
+            })
+            .collect();
+
+        let mut visited = vec![false; half_edge_count];
+        let mut cycles = Vec::new();
+        for h in 0..half_edge_count {
+            if visited[h] {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut current = h;
+            while !visited[current] {
+                visited[current] = true;
+                cycle.push(current);
+                current = self.half_edge_next[current];
+            }
+            cycles.push(cycle);
+        }
+
+        // remove edges walked in both directions (dangling edges and bridges) from the cycles,
+        // these have the same face on both sides
+        let reduced_cycles = cycles
+            .iter()
+            .map(|cycle| {
+                let mut reduced = VecDeque::with_capacity(cycle.len());
+                for &h in cycle.iter() {
+                    if reduced.back() == Some(&(h ^ 1)) {
+                        reduced.pop_back();
+                    } else {
+                        reduced.push_back(h);
+                    }
+                }
+                while reduced.len() >= 2
+                    && reduced.front().map(|&h| h ^ 1) == reduced.back().copied()
+                {
+                    reduced.pop_front();
+                    reduced.pop_back();
+                }
+                reduced.into_iter().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // connected components of the vertexes, a cycle can only be inside faces of other
+        // components
+        let mut parents = (0..self.vertexes.len()).collect::<Vec<_>>();
+        for edge in self.edges.iter() {
+            let a = find_root(&mut parents, edge.start);
+            let b = find_root(&mut parents, edge.end);
+            parents[a] = b;
+        }
+        let cycle_component = cycles
+            .iter()
+            .map(|cycle| find_root(&mut parents, self.half_edge_origin(cycle[0])))
+            .collect::<Vec<_>>();
+
+        let mut cycle_faces = vec![None; cycles.len()];
+        let mut face_components = Vec::new();
+        let mut inner_cycles = Vec::new();
+        for (i, reduced) in reduced_cycles.iter().enumerate() {
+            if reduced.is_empty() {
+                inner_cycles.push((i, None));
+                continue;
+            }
+            let pline = self.cycle_pline(reduced);
+            if pline.area() > eps * eps {
+                cycle_faces[i] = Some(self.faces.len());
+                face_components.push(cycle_component[i]);
+                self.faces.push(ArrangementFace {
+                    boundary: pline,
+                    boundary_edges: reduced.iter().map(|&h| h / 2).collect(),
+                    holes: Vec::new(),
+                    winding_numbers: Vec::new(),
+                });
+            } else {
+                inner_cycles.push((i, Some(pline)));
+            }
+        }
+
+        // clockwise cycles are holes in the smallest face containing them (or the unbounded
+        // face)
+        for (i, pline) in inner_cycles {
+            let point = self.vertexes[self.half_edge_origin(cycles[i][0])];
+            let container = self
+                .faces
+                .iter()
+                .enumerate()
+                .filter(|(face, f)| {
+                    if face_components[*face] == cycle_component[i] {
+                        return false;
+                    }
+                    let extents = f.boundary.extents().unwrap();
+                    extents.min_x <= point.x
+                        && point.x <= extents.max_x
+                        && extents.min_y <= point.y
+                        && point.y <= extents.max_y
+                        && f.boundary.winding_number(point) != 0
+                })
+                .min_by(|(_, f1), (_, f2)| {
+                    f1.boundary.area().partial_cmp(&f2.boundary.area()).unwrap()
+                })
+                .map(|(face, _)| face);
+            cycle_faces[i] = container;
+            if let (Some(face), Some(pline)) = (container, pline) {
+                self.faces[face].holes.push(pline);
+            }
+        }
+
+        self.half_edge_faces = vec![None; half_edge_count];
+        for (i, cycle) in cycles.iter().enumerate() {
+            for &h in cycle.iter() {
+                self.half_edge_faces[h] = cycle_faces[i];
+            }
+        }
+    }
+
+    /// Find the winding numbers of every face by crossing edges from the unbounded face.
+    fn compute_winding_numbers(&mut self, input_count: usize, is_closed: &[bool]) {
+        // node for each face plus the unbounded face at the end
+        let unbounded = self.faces.len();
+        let node = |face: Option<usize>| face.unwrap_or(unbounded);
+        let mut adjacent = vec![Vec::new(); unbounded + 1];
+        for e in 0..self.edges.len() {
+            let left = node(self.half_edge_faces[2 * e]);
+            let right = node(self.half_edge_faces[2 * e + 1]);
+            if left != right {
+                adjacent[left].push((right, e, 1));
+                adjacent[right].push((left, e, -1));
+            }
+        }
+
+        let mut winding_numbers: Vec<Option<Vec<i32>>> = vec![None; unbounded + 1];
+        winding_numbers[unbounded] = Some(vec![0; input_count]);
+        let mut queue = VecDeque::new();
+        queue.push_back(unbounded);
+        while let Some(current) = queue.pop_front() {
+            for &(other, e, sign) in adjacent[current].iter() {
+                if winding_numbers[other].is_some() {
+                    continue;
+                }
+                // the face on the left of a closed input segment has a winding number one
+                // greater than the face on the right
+                let mut winding = winding_numbers[current].clone().unwrap();
+                for source in self.edges[e].sources.iter() {
+                    if !is_closed[source.pline_index] {
+                        continue;
+                    }
+                    let delta = if source.same_direction { 1 } else { -1 };
+                    // crossing from the left of the edge to the right when sign is 1
+                    winding[source.pline_index] -= sign * delta;
+                }
+                winding_numbers[other] = Some(winding);
+                queue.push_back(other);
+            }
+        }
+
+        for (face, winding) in self.faces.iter_mut().zip(winding_numbers) {
+            face.winding_numbers = winding.unwrap_or_else(|| vec![0; input_count]);
+        }
+    }
+
+    /// Find the boundary of the region formed by the union of all the faces for which `selector`
+    /// returns true when given the face winding numbers.
+    ///
+    /// Returns counter clockwise polylines for outer boundaries and clockwise polylines for holes.
+    /// Edges between two selected faces are dissolved so touching faces are merged.
+    pub fn select_region<F>(&self, selector: F) -> Vec<Polyline<T>>
+    where
+        F: Fn(&[i32]) -> bool,
+    {
+        let selected = self
+            .faces
+            .iter()
+            .map(|f| selector(&f.winding_numbers))
+            .collect::<Vec<_>>();
+        let is_selected = |face: Option<usize>| face.map_or(false, |f| selected[f]);
+        let is_boundary = |h: usize| {
+            is_selected(self.half_edge_faces[h]) && !is_selected(self.half_edge_faces[h ^ 1])
+        };
+
+        let mut result = Vec::new();
+        let mut visited = vec![false; self.half_edge_faces.len()];
+        for h in 0..self.half_edge_faces.len() {
+            if visited[h] || !is_boundary(h) {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut current = h;
+            while !visited[current] {
+                visited[current] = true;
+                cycle.push(current);
+                // rotate around the end vertex until reaching the next boundary half edge
+                let mut next = self.half_edge_next[current];
+                while !is_boundary(next) {
+                    next = self.half_edge_next[next ^ 1];
+                }
+                current = next;
+            }
+            result.push(self.cycle_pline(&cycle));
+        }
+
+        result
+    }
+}
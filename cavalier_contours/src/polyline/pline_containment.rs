@@ -0,0 +1,256 @@
+use super::{seg_midpoint, PlineOrientation, PlineSource, PlineSourceMut};
+use crate::core::{math::Vector2, traits::Real};
+use static_aabb2d_index::{StaticAABB2DIndexBuilder, AABB};
+
+/// Node in a [PlineContainmentTree], each node corresponds to one of the polylines the tree was
+/// built from (same index position).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlineContainmentNode {
+    /// Index of the immediate parent polyline (smallest polyline that contains this polyline) or
+    /// `None` if this polyline is not contained by any other polyline.
+    pub parent: Option<usize>,
+    /// Indexes of the polylines immediately contained by this polyline.
+    pub children: Vec<usize>,
+    /// Nesting depth of the polyline, 0 if it is not contained by any other polyline, 1 if it is
+    /// contained by one other polyline, etc.
+    pub depth: usize,
+}
+
+impl PlineContainmentNode {
+    /// Returns true if the polyline is an outer loop (even depth).
+    #[inline]
+    pub fn is_outer(&self) -> bool {
+        !self.is_hole()
+    }
+
+    /// Returns true if the polyline is a hole (odd depth).
+    #[inline]
+    pub fn is_hole(&self) -> bool {
+        self.depth % 2 == 1
+    }
+
+    /// Orientation the polyline should have for its depth, counter clockwise for outer loops and
+    /// clockwise for holes.
+    #[inline]
+    pub fn expected_orientation(&self) -> PlineOrientation {
+        if self.is_outer() {
+            PlineOrientation::CounterClockwise
+        } else {
+            PlineOrientation::Clockwise
+        }
+    }
+}
+
+/// Nesting tree of a set of closed polylines.
+///
+/// Polylines are assumed to not cross each other (e.g. the result of a boolean operation or a
+/// set of loops from a drawing), they may touch. Open polylines and polylines with less than 2
+/// vertexes are never considered to contain or be contained by other polylines (they are always
+/// roots with no children).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let hole = pline_closed![(2.0, 2.0, 0.0), (8.0, 2.0, 0.0), (8.0, 8.0, 0.0), (2.0, 8.0, 0.0)];
+/// let island = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+/// let mut plines = vec![island, outer, hole];
+/// let tree = PlineContainmentTree::build(&plines, 1e-5);
+/// assert_eq!(tree.roots, vec![1]);
+/// assert_eq!(tree.nodes[1].children, vec![2]);
+/// assert_eq!(tree.nodes[2].children, vec![0]);
+/// assert_eq!(tree.nodes[0].depth, 2);
+/// assert!(tree.nodes[2].is_hole());
+///
+/// tree.fix_orientations(&mut plines);
+/// assert_eq!(plines[2].orientation(), PlineOrientation::Clockwise);
+/// assert_eq!(plines[0].orientation(), PlineOrientation::CounterClockwise);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlineContainmentTree {
+    /// Node for each polyline (same index position as the polylines the tree was built from).
+    pub nodes: Vec<PlineContainmentNode>,
+    /// Indexes of the polylines not contained by any other polyline.
+    pub roots: Vec<usize>,
+}
+
+impl PlineContainmentTree {
+    /// Build the containment tree for a set of polylines.
+    ///
+    /// A spatial index of the polyline extents is used to find candidate containing polylines,
+    /// containment is then determined using [PlineSource::winding_number] at a point on the
+    /// contained polyline which does not lie on the containing polyline (within `pos_equal_eps`).
+    /// If two polylines have the same geometric path then the one with the lower index is
+    /// considered to contain the other.
+    pub fn build<'a, P, I, T>(plines: I, pos_equal_eps: T) -> Self
+    where
+        P: PlineSource<Num = T> + 'a + ?Sized,
+        I: IntoIterator<Item = &'a P>,
+        T: Real,
+    {
+        let plines = plines.into_iter().collect::<Vec<_>>();
+        let count = plines.len();
+        let mut nodes = vec![
+            PlineContainmentNode {
+                parent: None,
+                children: Vec::new(),
+                depth: 0,
+            };
+            count
+        ];
+
+        let is_valid = |p: &P| p.is_closed() && p.vertex_count() >= 2;
+
+        let extents = plines
+            .iter()
+            .map(|p| if is_valid(p) { p.extents() } else { None })
+            .collect::<Vec<_>>();
+
+        let valid_count = extents.iter().filter(|e| e.is_some()).count();
+        if valid_count < 2 {
+            return Self {
+                roots: (0..count).collect(),
+                nodes,
+            };
+        }
+
+        // index of the extents, index positions are mapped back to polyline indexes
+        let mut index_to_pline = Vec::with_capacity(valid_count);
+        let mut builder = StaticAABB2DIndexBuilder::new(valid_count);
+        for (i, e) in extents.iter().enumerate() {
+            if let Some(e) = e {
+                builder.add(e.min_x, e.min_y, e.max_x, e.max_y);
+                index_to_pline.push(i);
+            }
+        }
+        let extents_index = builder.build().unwrap();
+
+        let abs_areas = plines
+            .iter()
+            .map(|p| num_traits::real::Real::abs(p.area()))
+            .collect::<Vec<_>>();
+
+        let box_contains = |outer: &AABB<T>, inner: &AABB<T>| {
+            outer.min_x <= inner.min_x + pos_equal_eps
+                && outer.min_y <= inner.min_y + pos_equal_eps
+                && outer.max_x + pos_equal_eps >= inner.max_x
+                && outer.max_y + pos_equal_eps >= inner.max_y
+        };
+
+        // test if pline j contains pline i
+        let contains = |j: usize, i: usize| -> bool {
+            let outer = plines[j];
+            let inner = plines[i];
+            match sample_point_off_boundary(inner, outer, pos_equal_eps) {
+                Some(pt) => outer.winding_number(pt) != 0,
+                // same geometric path, lower index is considered the container
+                None => j < i,
+            }
+        };
+
+        // containers of each polyline (all polylines that contain it)
+        let mut containers = vec![Vec::new(); count];
+        let mut query_stack = Vec::new();
+        for (i, e) in extents.iter().enumerate() {
+            let e = match e {
+                Some(e) => e,
+                None => continue,
+            };
+
+            let mut candidates = Vec::new();
+            extents_index.visit_query_with_stack(
+                e.min_x,
+                e.min_y,
+                e.max_x,
+                e.max_y,
+                &mut |index_pos: usize| {
+                    let j = index_to_pline[index_pos];
+                    if j != i
+                        && box_contains(extents[j].as_ref().unwrap(), e)
+                        && abs_areas[j] + pos_equal_eps >= abs_areas[i]
+                    {
+                        candidates.push(j);
+                    }
+                    static_aabb2d_index::Control::<()>::Continue
+                },
+                &mut query_stack,
+            );
+
+            containers[i] = candidates.into_iter().filter(|&j| contains(j, i)).collect();
+        }
+
+        for (i, node) in nodes.iter_mut().enumerate() {
+            node.depth = containers[i].len();
+            // immediate parent is the container with the greatest depth (smallest container)
+            node.parent = containers[i]
+                .iter()
+                .copied()
+                .max_by_key(|&j| containers[j].len());
+        }
+
+        let mut roots = Vec::new();
+        for i in 0..count {
+            match nodes[i].parent {
+                Some(p) => nodes[p].children.push(i),
+                None => roots.push(i),
+            }
+        }
+
+        Self { nodes, roots }
+    }
+
+    /// Returns each outer polyline index paired with the indexes of its holes (immediate
+    /// children), useful for processing regions one at a time.
+    pub fn outer_with_holes(&self) -> Vec<(usize, Vec<usize>)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.is_outer())
+            .map(|(i, n)| (i, n.children.clone()))
+            .collect()
+    }
+
+    /// Invert the direction of any polylines which do not have the orientation expected for their
+    /// depth (see [PlineContainmentNode::expected_orientation]).
+    ///
+    /// `plines` must be the same polylines (same order) the tree was built from.
+    pub fn fix_orientations<P>(&self, plines: &mut [P])
+    where
+        P: PlineSourceMut,
+    {
+        debug_assert_eq!(plines.len(), self.nodes.len());
+        for (pline, node) in plines.iter_mut().zip(self.nodes.iter()) {
+            if !pline.is_closed() || pline.vertex_count() < 2 {
+                continue;
+            }
+
+            if pline.orientation() != node.expected_orientation() {
+                pline.invert_direction_mut();
+            }
+        }
+    }
+}
+
+/// Find a point on `pline` that does not lie on `other` (vertexes and segment midpoints are
+/// tried). Returns `None` if all points tried lie on `other`.
+fn sample_point_off_boundary<P, R, T>(pline: &P, other: &R, pos_equal_eps: T) -> Option<Vector2<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let is_off_boundary = |pt: Vector2<T>| match other.closest_point(pt, pos_equal_eps) {
+        Some(cp) => cp.distance > pos_equal_eps,
+        None => true,
+    };
+
+    pline
+        .iter_segments()
+        .flat_map(|(v1, v2)| {
+            let mid = seg_midpoint(v1, v2);
+            std::iter::once(mid).chain(std::iter::once(v1.pos()))
+        })
+        .find(|&pt| is_off_boundary(pt))
+}
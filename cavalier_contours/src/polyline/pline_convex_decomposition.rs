@@ -0,0 +1,417 @@
+use super::{
+    pline_arrangement::{find_root, snap_positions},
+    pline_no_fit::region_loops,
+    seg_arc_radius_and_center, seg_tangent_vector, triangulate, PlineCreation, PlineSource,
+    PlineSourceMut, PlineVertex, Polyline, TriangulateOptions,
+};
+use crate::core::{
+    math::{angle, angle_from_bulge, bulge_from_angle, point_on_circle, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+use std::collections::HashMap;
+
+/// Options for decomposing closed polylines into convex pieces with [convex_decomposition].
+#[derive(Debug, Clone)]
+pub struct ConvexDecompositionOptions<T = f64> {
+    /// Maximum distance between a concave arc and the line segments approximating it.
+    pub arc_error_distance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> ConvexDecompositionOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            arc_error_distance: T::from(1e-2).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ConvexDecompositionOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convex arc kept in the decomposition, the chord from `start` to `end` is an edge of the
+/// polygon that is triangulated.
+struct ChordArc<T> {
+    start: Vector2<T>,
+    end: Vector2<T>,
+    bulge: T,
+}
+
+/// Split the arc segment from `v1` to `v2` into `count` sub arcs, returns the vertexes of the sub
+/// arcs (excluding the end).
+fn split_arc<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, count: usize) -> Vec<PlineVertex<T>>
+where
+    T: Real,
+{
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let sweep = angle_from_bulge(v1.bulge) / T::from(count).unwrap();
+    let bulge = bulge_from_angle(sweep);
+    let mut result = Vec::with_capacity(count);
+    result.push(v1.with_bulge(bulge));
+    for i in 1..count {
+        let p = point_on_circle(radius, center, start_angle + sweep * T::from(i).unwrap());
+        result.push(PlineVertex::from_vector2(p, bulge));
+    }
+    result
+}
+
+/// Number of line segments required to approximate an arc within `error_distance`.
+fn approx_segment_count<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, error_distance: T) -> usize
+where
+    T: Real,
+{
+    let (radius, _) = seg_arc_radius_and_center(v1, v2);
+    let sweep = angle_from_bulge(v1.bulge).abs();
+    let max_sweep = if error_distance >= radius {
+        T::pi()
+    } else {
+        T::two() * (T::one() - error_distance / radius).acos()
+    };
+    num_traits::cast::<T, usize>((sweep / max_sweep).ceil())
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// Returns true if the turn at `pos` going from the segment `prev` to `pos` into the segment
+/// `pos` to `next` is convex (turning left or straight ahead).
+fn is_convex_turn<T>(
+    prev: PlineVertex<T>,
+    pos: Vector2<T>,
+    out_bulge: T,
+    next: Vector2<T>,
+    eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let incoming =
+        seg_tangent_vector(prev, PlineVertex::from_vector2(pos, T::zero()), pos).normalize();
+    let outgoing = seg_tangent_vector(
+        PlineVertex::from_vector2(pos, out_bulge),
+        PlineVertex::from_vector2(next, T::zero()),
+        pos,
+    )
+    .normalize();
+    let cross = incoming.perp_dot(outgoing);
+    cross > eps || (cross > -eps && incoming.dot(outgoing) > T::zero())
+}
+
+/// Merge `piece_a` and `piece_b` across the edge going from `a` to `b` in `piece_a` (and `b` to
+/// `a` in `piece_b`), returns `None` if the edge is not found or the merged piece is not convex.
+///
+/// Pieces are counter clockwise cycles of (vertex index, bulge) pairs.
+fn merge_pieces<T>(
+    points: &[Vector2<T>],
+    piece_a: &[(usize, T)],
+    piece_b: &[(usize, T)],
+    a: usize,
+    b: usize,
+    eps: T,
+) -> Option<Vec<(usize, T)>>
+where
+    T: Real,
+{
+    let len_a = piece_a.len();
+    let len_b = piece_b.len();
+    let i = (0..len_a).find(|&i| piece_a[i].0 == a && piece_a[(i + 1) % len_a].0 == b)?;
+    let j = (0..len_b).find(|&j| piece_b[j].0 == b && piece_b[(j + 1) % len_b].0 == a)?;
+
+    // piece_a from b around to a, then piece_b after a around to before b, the edge leaving a
+    // is now from piece_b
+    let mut merged = Vec::with_capacity(len_a + len_b - 2);
+    merged.extend((0..len_a).map(|k| piece_a[(i + 1 + k) % len_a]));
+    merged[len_a - 1].1 = piece_b[(j + 1) % len_b].1;
+    merged.extend((1..len_b - 1).map(|k| piece_b[(j + 1 + k) % len_b]));
+
+    let len = merged.len();
+    let convex_at = |k: usize| {
+        let (prev, prev_bulge) = merged[(k + len - 1) % len];
+        let (current, bulge) = merged[k];
+        let (next, _) = merged[(k + 1) % len];
+        is_convex_turn(
+            PlineVertex::from_vector2(points[prev], prev_bulge),
+            points[current],
+            bulge,
+            points[next],
+            eps,
+        )
+    };
+
+    if convex_at(0) && convex_at(len_a - 1) {
+        Some(merged)
+    } else {
+        None
+    }
+}
+
+/// Decompose the regions defined by a set of closed polylines into convex pieces.
+///
+/// Nesting is determined with [PlineContainmentTree](super::PlineContainmentTree) so the input
+/// orientations do not need to be consistent: polylines at even depth are outer boundaries and
+/// polylines at odd depth are holes. The regions are triangulated (see [triangulate]) and then
+/// triangles are merged across their shared edges while the merged pieces stay convex
+/// (Hertel-Mehlhorn), giving at most four times the minimum number of convex pieces.
+///
+/// Arcs which are convex with respect to the region are kept as arcs in the pieces (split into
+/// sub arcs of at most a quarter circle), arcs which are concave with respect to the region are
+/// approximated by line segments within [ConvexDecompositionOptions::arc_error_distance].
+///
+/// All pieces returned are closed counter clockwise polylines. Open polylines and polylines
+/// without area are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// // L shape
+/// let pline: Polyline = pline_closed![
+///     (0.0, 0.0, 0.0),
+///     (4.0, 0.0, 0.0),
+///     (4.0, 1.0, 0.0),
+///     (1.0, 1.0, 0.0),
+///     (1.0, 4.0, 0.0),
+///     (0.0, 4.0, 0.0)
+/// ];
+/// let pieces = convex_decomposition(std::iter::once(&pline), &ConvexDecompositionOptions::default());
+/// assert_eq!(pieces.len(), 2);
+/// let area: f64 = pieces.iter().map(|p| p.area()).sum();
+/// assert_fuzzy_eq!(area, 7.0);
+/// ```
+pub fn convex_decomposition<'a, P, I, T>(
+    plines: I,
+    options: &ConvexDecompositionOptions<T>,
+) -> Vec<Polyline<T>>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let loops = region_loops(plines, eps);
+    if loops.is_empty() {
+        return Vec::new();
+    }
+
+    // approximate concave arcs and split convex arcs, arcs in the loops are counter clockwise
+    // (positive bulge) for convex since outer boundaries are counter clockwise and holes are
+    // clockwise
+    let quarter_turn = T::pi() / T::two();
+    let loops = loops
+        .iter()
+        .map(|pline| {
+            let mut result = Polyline::with_capacity(pline.vertex_count(), true);
+            for (v1, v2) in pline.iter_segments() {
+                if v1.bulge_is_zero() || v1.pos().fuzzy_eq_eps(v2.pos(), eps) {
+                    result.add_vertex(v1.with_bulge(T::zero()));
+                } else if v1.bulge_is_pos() {
+                    let sweep = angle_from_bulge(v1.bulge);
+                    let count = num_traits::cast::<T, usize>((sweep / quarter_turn).ceil())
+                        .unwrap_or(1)
+                        .max(1);
+                    for v in split_arc(v1, v2, count) {
+                        result.add_vertex(v);
+                    }
+                } else {
+                    let count = approx_segment_count(v1, v2, options.arc_error_distance);
+                    for v in split_arc(v1, v2, count) {
+                        result.add_vertex(v.with_bulge(T::zero()));
+                    }
+                }
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+
+    // convex arcs with other vertexes inside of their circular segment (between chord and arc)
+    // are approximated so the chord polygon does not intersect itself
+    let all_points = loops
+        .iter()
+        .flat_map(|pline| pline.iter_vertexes().map(|v| v.pos()))
+        .collect::<Vec<_>>();
+    let points_index: StaticAABB2DIndex<T> = {
+        let mut builder = StaticAABB2DIndexBuilder::new(all_points.len());
+        for p in all_points.iter() {
+            builder.add(p.x, p.y, p.x, p.y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut arcs = Vec::new();
+    let loops = loops
+        .iter()
+        .map(|pline| {
+            let mut result = Polyline::with_capacity(pline.vertex_count(), true);
+            for (v1, v2) in pline.iter_segments() {
+                if v1.bulge_is_zero() {
+                    result.add_vertex(v1);
+                    continue;
+                }
+
+                let (radius, center) = seg_arc_radius_and_center(v1, v2);
+                let (start, end) = (v1.pos(), v2.pos());
+                let mut blocked = false;
+                let min_x = num_traits::real::Real::min(start.x, end.x);
+                let min_y = num_traits::real::Real::min(start.y, end.y);
+                let max_x = num_traits::real::Real::max(start.x, end.x);
+                let max_y = num_traits::real::Real::max(start.y, end.y);
+                // sub arcs are at most a quarter circle so the arc lies within the chord's
+                // bounding box expanded by the sagitta
+                let half_chord_sq = (end - start).length_squared() / T::four();
+                let sagitta = radius
+                    - num_traits::real::Real::max(radius * radius - half_chord_sq, T::zero())
+                        .sqrt();
+                points_index.visit_query(
+                    min_x - sagitta,
+                    min_y - sagitta,
+                    max_x + sagitta,
+                    max_y + sagitta,
+                    &mut |i: usize| {
+                        let p = all_points[i];
+                        if (p - center).length() < radius - eps
+                            && (end - start).perp_dot(p - start) < -eps * (end - start).length()
+                        {
+                            blocked = true;
+                        }
+                    },
+                );
+
+                if blocked {
+                    let count = approx_segment_count(v1, v2, options.arc_error_distance);
+                    for v in split_arc(v1, v2, count) {
+                        result.add_vertex(v.with_bulge(T::zero()));
+                    }
+                } else {
+                    result.add_vertex(v1.with_bulge(T::zero()));
+                    arcs.push(ChordArc {
+                        start,
+                        end,
+                        bulge: v1.bulge,
+                    });
+                }
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+
+    let mut triangulate_options = TriangulateOptions::new();
+    triangulate_options.arc_error_distance = options.arc_error_distance;
+    triangulate_options.pos_equal_eps = eps;
+    let mesh = triangulate(&loops, &triangulate_options);
+
+    // find the triangle edges which are chords of arcs
+    let mut points = mesh.vertexes;
+    let vertex_count = points.len();
+    let snapped = {
+        let mut all = points.clone();
+        all.extend(
+            arcs.iter()
+                .flat_map(|arc| std::iter::once(arc.start).chain(std::iter::once(arc.end))),
+        );
+        snap_positions(&all, eps)
+    };
+    let mut arc_lookup = HashMap::with_capacity(arcs.len());
+    for i in 0..arcs.len() {
+        let start = snapped[vertex_count + 2 * i];
+        let end = snapped[vertex_count + 2 * i + 1];
+        arc_lookup.insert((start, end), i);
+    }
+
+    // pieces start as the triangles and the circular segments of the arcs, all pieces are convex
+    let mut pieces = mesh
+        .triangles
+        .iter()
+        .map(|t| t.iter().map(|&v| (v, T::zero())).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut edge_pieces = HashMap::with_capacity(3 * pieces.len());
+    for (i, t) in mesh.triangles.iter().enumerate() {
+        for k in 0..3 {
+            edge_pieces.insert((t[k], t[(k + 1) % 3]), i);
+        }
+    }
+
+    let mut arc_edges = Vec::new();
+    for t in mesh.triangles.iter() {
+        for k in 0..3 {
+            let (u, v) = (t[k], t[(k + 1) % 3]);
+            if let Some(arc_index) = arc_lookup.remove(&(snapped[u], snapped[v])) {
+                edge_pieces.insert((v, u), pieces.len());
+                pieces.push(vec![(u, arcs[arc_index].bulge), (v, T::zero())]);
+                arc_edges.push((u, v));
+            }
+        }
+    }
+    // arcs not matching a triangle edge (e.g. chords of degenerate triangles) are kept as pieces
+    for (_, arc_index) in arc_lookup {
+        let arc = &arcs[arc_index];
+        points.push(arc.start);
+        points.push(arc.end);
+        pieces.push(vec![
+            (points.len() - 2, arc.bulge),
+            (points.len() - 1, T::zero()),
+        ]);
+    }
+
+    // interior edges shared by two triangles, longest first
+    let mut diagonals = mesh
+        .triangles
+        .iter()
+        .flat_map(|t| (0..3).map(move |k| (t[k], t[(k + 1) % 3])))
+        .filter(|&(u, v)| u < v && edge_pieces.contains_key(&(v, u)))
+        .collect::<Vec<_>>();
+    diagonals.sort_by(|&(a1, b1), &(a2, b2)| {
+        let l1 = (points[b1] - points[a1]).length_squared();
+        let l2 = (points[b2] - points[a2]).length_squared();
+        l2.partial_cmp(&l1).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // merge arc segments into their triangles first so arcs are not left as separate pieces
+    let mut parents = (0..pieces.len()).collect::<Vec<_>>();
+    for (u, v) in arc_edges.into_iter().chain(diagonals) {
+        let (pa, pb) = match (edge_pieces.get(&(u, v)), edge_pieces.get(&(v, u))) {
+            (Some(&pa), Some(&pb)) => (pa, pb),
+            _ => continue,
+        };
+        let pa = find_root(&mut parents, pa);
+        let pb = find_root(&mut parents, pb);
+        if pa == pb {
+            continue;
+        }
+        if let Some(merged) = merge_pieces(&points, &pieces[pa], &pieces[pb], u, v, eps) {
+            pieces[pa] = merged;
+            pieces[pb].clear();
+            parents[pb] = pa;
+        }
+    }
+
+    pieces
+        .into_iter()
+        .filter(|piece| !piece.is_empty())
+        .map(|piece| {
+            let mut pline = Polyline::with_capacity(piece.len(), true);
+            for (v, bulge) in piece {
+                pline.add_vertex(PlineVertex::from_vector2(points[v], bulge));
+            }
+            match pline.remove_redundant(eps) {
+                Some(r) => r,
+                None => pline,
+            }
+        })
+        .filter(|pline| pline.vertex_count() >= 2)
+        .collect()
+}
@@ -0,0 +1,529 @@
+use super::{
+    pline_seg_intr, seg_bounding_box, seg_length, seg_split_at_point, seg_tangent_vector,
+    PlineCreation, PlineSegIntr, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+};
+use crate::core::{
+    math::{angle, bulge_from_angle, normalize_radians, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index as aabb_index;
+
+/// Shape of the relief cut added at concave corners, see [CornerReliefOptions::kind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CornerReliefKind {
+    /// Relief circle centered on the corner bisector, cuts equally into both segments.
+    Dogbone,
+    /// Relief circle tangent to the incoming segment at the corner, the relief extends in the
+    /// direction of the incoming segment and cuts only into the outgoing segment.
+    TBoneIncoming,
+    /// Relief circle tangent to the outgoing segment at the corner, the relief extends in the
+    /// reverse direction of the outgoing segment and cuts only into the incoming segment.
+    TBoneOutgoing,
+}
+
+/// Options for adding corner relief cuts with [add_corner_relief].
+#[derive(Debug, Clone)]
+pub struct CornerReliefOptions<T = f64> {
+    /// Shape of the relief cut.
+    pub kind: CornerReliefKind,
+    /// Radius of the cutting tool (radius of the relief arcs).
+    pub tool_radius: T,
+    /// If false then the material is inside the polyline (e.g. part profile cut from outside),
+    /// otherwise the material is outside the polyline (e.g. pocket cut from inside). Determines
+    /// which corners are concave.
+    pub material_outside: bool,
+    /// Corners where the path direction changes by less than this angle (in radians) are not
+    /// relieved.
+    pub min_corner_angle: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> CornerReliefOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            kind: CornerReliefKind::Dogbone,
+            tool_radius: T::one(),
+            material_outside: false,
+            min_corner_angle: T::from(1e-3).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for CornerReliefOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of [add_corner_relief].
+#[derive(Debug, Clone)]
+pub struct CornerReliefResult<T = f64> {
+    /// Polyline with the relief cuts added.
+    pub pline: Polyline<T>,
+    /// Vertex indexes (of the input polyline with repeat positions removed) of the corners that
+    /// were relieved.
+    pub relieved_corners: Vec<usize>,
+    /// Vertex indexes of concave corners that were not relieved because the relief did not fit
+    /// (adjacent segments too short or the relief would intersect other parts of the polyline).
+    pub skipped_corners: Vec<usize>,
+}
+
+/// Relief cut at a corner.
+#[derive(Debug, Clone, Copy)]
+struct Relief<T> {
+    /// Point on the incoming segment where the relief starts.
+    start: Vector2<T>,
+    /// Point on the outgoing segment where the relief ends.
+    end: Vector2<T>,
+    /// Relief arc vertexes (start, corner, end) with the arc bulges, repeat positions removed.
+    arcs: [Option<PlineVertex<T>>; 2],
+}
+
+/// Length along the segment `v1` to `v2` from `v1` to `point`.
+fn dist_along_seg<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>, eps: T) -> T
+where
+    T: Real,
+{
+    if v1.pos().fuzzy_eq_eps(point, eps) {
+        return T::zero();
+    }
+    let split = seg_split_at_point(v1, v2, point, eps);
+    seg_length(split.updated_start, split.split_vertex)
+}
+
+/// Full circle as two arc segments.
+fn circle_segments<T>(center: Vector2<T>, radius: T) -> [(PlineVertex<T>, PlineVertex<T>); 2]
+where
+    T: Real,
+{
+    let left = PlineVertex::new(center.x - radius, center.y, T::one());
+    let right = PlineVertex::new(center.x + radius, center.y, T::one());
+    [(left, right), (right, left)]
+}
+
+/// Visit all the intersect points between the circle and the segment.
+fn visit_circle_seg_intersects<T, F>(
+    center: Vector2<T>,
+    radius: T,
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    eps: T,
+    visitor: &mut F,
+) where
+    T: Real,
+    F: FnMut(Vector2<T>),
+{
+    for &(u1, u2) in circle_segments(center, radius).iter() {
+        match pline_seg_intr(v1, v2, u1, u2, eps) {
+            PlineSegIntr::NoIntersect => {}
+            PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+                visitor(point)
+            }
+            PlineSegIntr::TwoIntersects { point1, point2 }
+            | PlineSegIntr::OverlappingLines { point1, point2 }
+            | PlineSegIntr::OverlappingArcs { point1, point2 } => {
+                visitor(point1);
+                visitor(point2);
+            }
+        }
+    }
+}
+
+/// Bulge of the arc on the circle at `center` going from `p1` to `p2` in the direction given.
+fn arc_bulge<T>(center: Vector2<T>, p1: Vector2<T>, p2: Vector2<T>, is_ccw: bool) -> T
+where
+    T: Real,
+{
+    let a1 = angle(center, p1);
+    let a2 = angle(center, p2);
+    if is_ccw {
+        bulge_from_angle(normalize_radians(a2 - a1))
+    } else {
+        bulge_from_angle(-normalize_radians(a1 - a2))
+    }
+}
+
+/// Build the relief at corner `v` given the incoming and outgoing segments, returns `None` if the
+/// relief does not fit on the segments.
+fn create_relief<T>(
+    prev: PlineVertex<T>,
+    v: PlineVertex<T>,
+    next: PlineVertex<T>,
+    dir_in: Vector2<T>,
+    dir_out: Vector2<T>,
+    material_left: bool,
+    options: &CornerReliefOptions<T>,
+) -> Option<Relief<T>>
+where
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let radius = options.tool_radius;
+    let corner = v.pos();
+
+    // normals pointing into the material
+    let material_normal = |dir: Vector2<T>| {
+        if material_left {
+            dir.perp()
+        } else {
+            -dir.perp()
+        }
+    };
+    let normal_in = material_normal(dir_in);
+    let normal_out = material_normal(dir_out);
+
+    // relief circle passes through the corner with its center on the side away from the material
+    let center_dir = match options.kind {
+        CornerReliefKind::Dogbone => (normal_in + normal_out).normalize(),
+        CornerReliefKind::TBoneIncoming => normal_in,
+        CornerReliefKind::TBoneOutgoing => normal_out,
+    };
+    let center = corner - center_dir.scale(radius);
+
+    // intersect closest to the corner (excluding the corner) along a segment
+    let closest_intersect = |v1: PlineVertex<T>, v2: PlineVertex<T>, from_end: bool| {
+        let mut best: Option<(T, Vector2<T>)> = None;
+        visit_circle_seg_intersects(center, radius, v1, v2, eps, &mut |pt| {
+            if pt.fuzzy_eq_eps(corner, eps) {
+                return;
+            }
+            let d = dist_along_seg(v1, v2, pt, eps);
+            let d = if from_end { seg_length(v1, v2) - d } else { d };
+            if best.map_or(true, |(bd, _)| d < bd) {
+                best = Some((d, pt));
+            }
+        });
+        best.map(|(_, pt)| pt)
+    };
+
+    // circle tangent to the segment at the corner (no trimming required)
+    let is_tangent = |dir: Vector2<T>| dir.dot(center - corner).fuzzy_eq_zero_eps(eps);
+
+    let start = match closest_intersect(prev, v, true) {
+        Some(pt) => pt,
+        None if is_tangent(dir_in) => corner,
+        None => return None,
+    };
+
+    let end = match closest_intersect(v, next, false) {
+        Some(pt) => pt,
+        None if is_tangent(dir_out) => corner,
+        None => return None,
+    };
+
+    // arcs go around the side of the circle containing the corner, direction is found by the
+    // circle tangent at the corner (continuing the incoming direction for dogbone and t-bone
+    // incoming, arriving along the outgoing direction for t-bone outgoing)
+    let corner_tangent = match options.kind {
+        CornerReliefKind::TBoneOutgoing => dir_out,
+        _ => {
+            if start.fuzzy_eq_eps(corner, eps) {
+                dir_in
+            } else {
+                // tangent at corner when traveling from start to corner along the short way
+                let radial = corner - center;
+                let ccw_tangent = radial.perp();
+                if ccw_tangent.dot(corner - start) > T::zero() {
+                    ccw_tangent
+                } else {
+                    -ccw_tangent
+                }
+            }
+        }
+    };
+    let is_ccw = (corner - center).perp().dot(corner_tangent) > T::zero();
+
+    let mut arcs = [None, None];
+    if !start.fuzzy_eq_eps(corner, eps) {
+        arcs[0] = Some(PlineVertex::from_vector2(
+            start,
+            arc_bulge(center, start, corner, is_ccw),
+        ));
+    }
+    if !end.fuzzy_eq_eps(corner, eps) {
+        arcs[1] = Some(PlineVertex::from_vector2(
+            corner,
+            arc_bulge(center, corner, end, is_ccw),
+        ));
+    }
+
+    if arcs[0].is_none() && arcs[1].is_none() {
+        return None;
+    }
+
+    Some(Relief { start, end, arcs })
+}
+
+/// Returns true if any of the relief arcs intersect `v1` to `v2`, ignoring intersects at the
+/// positions given.
+fn relief_intersects_seg<T>(
+    relief: &Relief<T>,
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    ignore: &[Vector2<T>],
+    eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let arc_segs = relief_arc_segments(relief);
+    let is_ignored = |pt: Vector2<T>| ignore.iter().any(|p| p.fuzzy_eq_eps(pt, eps));
+    for &(u1, u2) in arc_segs.iter().flatten() {
+        let found = match pline_seg_intr(u1, u2, v1, v2, eps) {
+            PlineSegIntr::NoIntersect => false,
+            PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+                !is_ignored(point)
+            }
+            PlineSegIntr::TwoIntersects { point1, point2 } => {
+                !is_ignored(point1) || !is_ignored(point2)
+            }
+            PlineSegIntr::OverlappingLines { .. } | PlineSegIntr::OverlappingArcs { .. } => true,
+        };
+        if found {
+            return true;
+        }
+    }
+    false
+}
+
+/// Arc segments of a relief.
+fn relief_arc_segments<T>(relief: &Relief<T>) -> [Option<(PlineVertex<T>, PlineVertex<T>)>; 2]
+where
+    T: Real,
+{
+    let seg = |i: usize| {
+        relief.arcs[i].map(|v| {
+            let end = if i == 0 {
+                relief.arcs[1]
+                    .map(|c| c.pos())
+                    .unwrap_or_else(|| relief.end)
+            } else {
+                relief.end
+            };
+            (v, PlineVertex::from_vector2(end, T::zero()))
+        })
+    };
+    [seg(0), seg(1)]
+}
+
+/// Add dogbone or t-bone relief cuts at the concave corners of a closed polyline so a tool of
+/// the radius given can fully clear the corners.
+///
+/// Concave corners are found using the polyline orientation (and
+/// [CornerReliefOptions::material_outside]) along with the tangent directions of the segments at
+/// each vertex (found with [seg_tangent_vector]). At each concave corner a relief circle with
+/// the tool radius passing through the corner is placed, the adjacent segments are trimmed where
+/// they meet the circle and joined by arcs around the circle through the corner.
+///
+/// A corner is skipped if the relief does not fit on its adjacent segments, overlaps the relief
+/// of a neighboring corner, or would intersect any other part of the polyline, so the result
+/// remains non-self-intersecting (assuming the input is).
+///
+/// Returns `None` if the polyline is open or has less than 2 vertexes (after removing repeat
+/// positions).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::pline_closed;
+/// // L shape with one concave corner at (5, 5)
+/// let l_shape = pline_closed![
+///     (0.0, 0.0, 0.0),
+///     (10.0, 0.0, 0.0),
+///     (10.0, 5.0, 0.0),
+///     (5.0, 5.0, 0.0),
+///     (5.0, 10.0, 0.0),
+///     (0.0, 10.0, 0.0),
+/// ];
+/// let options = CornerReliefOptions {
+///     tool_radius: 1.0,
+///     ..Default::default()
+/// };
+/// let result = add_corner_relief(&l_shape, &options).unwrap();
+/// assert_eq!(result.relieved_corners, vec![3]);
+/// // corner replaced by two arcs going through the corner
+/// assert_eq!(result.pline.vertex_count(), 8);
+/// assert!(result.pline.iter_vertexes().any(|v| v.pos().fuzzy_eq(Vector2::new(5.0, 5.0))));
+/// assert!(result.pline.area() < l_shape.area());
+/// ```
+pub fn add_corner_relief<P, T>(
+    pline: &P,
+    options: &CornerReliefOptions<T>,
+) -> Option<CornerReliefResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    if !pline.is_closed() {
+        return None;
+    }
+
+    let source: Polyline<T> = match pline.remove_repeat_pos(eps) {
+        Some(p) => Polyline::create_from(&p),
+        None => Polyline::create_from(pline),
+    };
+    let vc = source.vertex_count();
+    if vc < 2 {
+        return None;
+    }
+
+    let mut relieved_corners = Vec::new();
+    let mut skipped_corners = Vec::new();
+    if options.tool_radius <= eps {
+        return Some(CornerReliefResult {
+            pline: source,
+            relieved_corners,
+            skipped_corners,
+        });
+    }
+
+    let material_left = (source.area() > T::zero()) != options.material_outside;
+    let aabb_index = source.create_approx_aabb_index().unwrap();
+    let mut query_stack = Vec::new();
+
+    let mut reliefs: Vec<Option<Relief<T>>> = vec![None; vc];
+    for i in 0..vc {
+        let prev = source.at(source.prev_wrapping_index(i));
+        let v = source.at(i);
+        let next = source.at(source.next_wrapping_index(i));
+        let dir_in = seg_tangent_vector(prev, v, v.pos()).normalize();
+        let dir_out = seg_tangent_vector(v, next, v.pos()).normalize();
+
+        let turn = dir_in.perp_dot(dir_out);
+        let turn_angle = turn.atan2(dir_in.dot(dir_out)).abs();
+        let is_concave = if material_left {
+            turn < T::zero()
+        } else {
+            turn > T::zero()
+        };
+        if !is_concave || turn_angle < options.min_corner_angle {
+            continue;
+        }
+
+        let relief = match create_relief(prev, v, next, dir_in, dir_out, material_left, options) {
+            Some(r) => r,
+            None => {
+                skipped_corners.push(i);
+                continue;
+            }
+        };
+
+        // trimmed segments must not overlap with the previous relief (or first relief for the last
+        // corner)
+        let prev_i = source.prev_wrapping_index(i);
+        let overlaps_prev = match reliefs[prev_i] {
+            Some(ref r) => {
+                dist_along_seg(prev, v, r.end, eps)
+                    > dist_along_seg(prev, v, relief.start, eps) - eps
+            }
+            None => false,
+        };
+        let next_i = source.next_wrapping_index(i);
+        let overlaps_next = match reliefs[next_i] {
+            Some(ref r) if next_i < i => {
+                dist_along_seg(v, next, relief.end, eps)
+                    > dist_along_seg(v, next, r.start, eps) - eps
+            }
+            _ => false,
+        };
+        if overlaps_prev || overlaps_next {
+            skipped_corners.push(i);
+            continue;
+        }
+
+        // relief must not intersect other parts of the polyline or other reliefs
+        let mut intersects = false;
+        for &(u1, u2) in relief_arc_segments(&relief).iter().flatten() {
+            let bb = seg_bounding_box(u1, u2);
+            aabb_index.visit_query_with_stack(
+                bb.min_x - eps,
+                bb.min_y - eps,
+                bb.max_x + eps,
+                bb.max_y + eps,
+                &mut |j: usize| {
+                    let w1 = source.at(j);
+                    let w2 = source.at(source.next_wrapping_index(j));
+                    let ignore: &[Vector2<T>] = if j == prev_i {
+                        &[relief.start, v.pos()]
+                    } else if j == i {
+                        &[relief.end, v.pos()]
+                    } else {
+                        &[]
+                    };
+                    if relief_intersects_seg(&relief, w1, w2, ignore, eps) {
+                        intersects = true;
+                        return aabb_index::Control::Break(());
+                    }
+                    aabb_index::Control::Continue
+                },
+                &mut query_stack,
+            );
+        }
+
+        if !intersects {
+            intersects = reliefs.iter().flatten().any(|other| {
+                relief_arc_segments(other)
+                    .iter()
+                    .flatten()
+                    .any(|&(w1, w2)| relief_intersects_seg(&relief, w1, w2, &[], eps))
+            });
+        }
+
+        if intersects {
+            skipped_corners.push(i);
+            continue;
+        }
+
+        reliefs[i] = Some(relief);
+        relieved_corners.push(i);
+    }
+
+    // bulge of segment i trimmed to start and end at the relief points
+    let trimmed_bulge = |i: usize| {
+        let v1 = source.at(i);
+        let next_i = source.next_wrapping_index(i);
+        let v2 = source.at(next_i);
+        if v1.bulge_is_zero() {
+            return T::zero();
+        }
+        let start = reliefs[i].map_or(v1.pos(), |r| r.end);
+        let end = reliefs[next_i].map_or(v2.pos(), |r| r.start);
+        let start_split = seg_split_at_point(v1, v2, start, eps).split_vertex;
+        seg_split_at_point(start_split, v2, end, eps)
+            .updated_start
+            .bulge
+    };
+
+    let mut result = Polyline::with_capacity(vc + 2 * relieved_corners.len(), true);
+    for (i, relief) in reliefs.iter().enumerate() {
+        let seg_start = match relief {
+            Some(r) => {
+                for arc_v in r.arcs.iter().flatten() {
+                    result.add_or_replace_vertex(*arc_v, eps);
+                }
+                r.end
+            }
+            None => source.at(i).pos(),
+        };
+        result.add_or_replace_vertex(PlineVertex::from_vector2(seg_start, trimmed_bulge(i)), eps);
+    }
+
+    Some(CornerReliefResult {
+        pline: result,
+        relieved_corners,
+        skipped_corners,
+    })
+}
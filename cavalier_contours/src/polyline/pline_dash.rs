@@ -0,0 +1,268 @@
+use super::{
+    internal::pline_path_length::{path_range_view_data, vertex_path_lengths},
+    seg_point_at_parametric, PlineCreation, PlineSource, PlineViewData, Polyline,
+};
+use crate::core::{math::Vector2, traits::Real};
+
+/// Options for splitting a polyline into dashes with [dash_pattern_views] and [dash_pattern].
+#[derive(Debug, Clone)]
+pub struct DashOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Alternating on (dash) and off (gap) path lengths, starting with on. If the pattern has an
+    /// odd number of lengths it is repeated to form an even number (e.g. `[2.0]` is the same as
+    /// `[2.0, 2.0]`), negative lengths are treated as zero.
+    pub pattern: &'a [T],
+    /// Path length into the pattern at the start of the polyline.
+    pub phase: T,
+    /// If true then on closed polylines the pattern (and phase) is scaled so a whole number of
+    /// repeats fit evenly around the polyline.
+    pub fit_closed: bool,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> DashOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pattern: &[],
+            phase: T::zero(),
+            fit_closed: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for DashOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Path length ranges of the dashes along the polyline (end may be greater than the total path
+/// length for a dash wrapping past the start of a closed polyline).
+fn dash_ranges<T>(
+    pattern: &[T],
+    phase: T,
+    total: T,
+    is_closed: bool,
+    fit: bool,
+    eps: T,
+) -> Vec<(T, T)>
+where
+    T: Real,
+{
+    let mut pattern = pattern
+        .iter()
+        .map(|&l| num_traits::real::Real::max(l, T::zero()))
+        .collect::<Vec<_>>();
+    if pattern.len() % 2 != 0 {
+        pattern.extend_from_slice(&pattern.clone());
+    }
+
+    let period = pattern.iter().fold(T::zero(), |acc, &l| acc + l);
+    if period <= eps {
+        // no pattern, solid line
+        return vec![(T::zero(), total)];
+    }
+
+    let mut phase = phase;
+    if is_closed && fit {
+        let count = num_traits::real::Real::max((total / period).round(), T::one());
+        let scale = total / (count * period);
+        for l in pattern.iter_mut() {
+            *l = *l * scale;
+        }
+        phase = phase * scale;
+    }
+    let period = pattern.iter().fold(T::zero(), |acc, &l| acc + l);
+
+    // find the pattern entry and the length remaining in it at the start of the polyline
+    let mut pattern_index = 0;
+    let mut offset = phase - (phase / period).floor() * period;
+    while offset >= pattern[pattern_index] && pattern_index + 1 < pattern.len() {
+        offset = offset - pattern[pattern_index];
+        pattern_index += 1;
+    }
+    let mut remaining = num_traits::real::Real::max(pattern[pattern_index] - offset, T::zero());
+
+    let mut ranges: Vec<(T, T)> = Vec::new();
+    let mut start = T::zero();
+    loop {
+        let end = num_traits::real::Real::min(start + remaining, total);
+        if pattern_index % 2 == 0 && end - start > eps {
+            match ranges.last_mut() {
+                // joined to previous dash by a zero length gap
+                Some(last) if last.1 >= start - eps => last.1 = end,
+                _ => ranges.push((start, end)),
+            }
+        }
+        if start + remaining >= total {
+            break;
+        }
+        start = start + remaining;
+        pattern_index = (pattern_index + 1) % pattern.len();
+        remaining = pattern[pattern_index];
+    }
+
+    // join the dashes at the start and end of a closed polyline
+    if is_closed && ranges.len() > 1 {
+        let first = ranges[0];
+        let last = ranges[ranges.len() - 1];
+        if first.0 <= eps && last.1 >= total - eps {
+            ranges[0] = (last.0, first.1 + total);
+            ranges.pop();
+        }
+    }
+
+    ranges
+}
+
+/// Split a polyline into dashes, returning view data for each dash.
+///
+/// Dashes follow the [DashOptions::pattern] of alternating on and off path lengths, starting
+/// [DashOptions::phase] into the pattern at the first vertex of the polyline. On closed
+/// polylines a dash running through the last vertex continues past the first vertex (so the
+/// first dash returned may start part way around the polyline), and if [DashOptions::fit_closed]
+/// is true then the pattern is scaled to repeat evenly around the polyline. If the pattern has
+/// no length the whole polyline is returned as a single dash.
+///
+/// Path lengths are computed once and the polyline is traversed a single time so the cost is
+/// linear in the number of vertexes plus the number of dashes.
+///
+/// Returns empty result if the polyline has less than 2 vertexes or has zero path length.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_open};
+/// let pline: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+/// let options = DashOptions {
+///     pattern: &[2.0, 1.0],
+///     ..Default::default()
+/// };
+/// let dashes = dash_pattern_views(&pline, &options);
+/// assert_eq!(dashes.len(), 4);
+/// let last = dashes[3].view(&pline);
+/// assert_fuzzy_eq!(last.at(0).x, 9.0);
+/// assert_fuzzy_eq!(last.path_length(), 1.0);
+/// ```
+pub fn dash_pattern_views<P, T>(pline: &P, options: &DashOptions<T>) -> Vec<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    if pline.vertex_count() < 2 {
+        return Vec::new();
+    }
+
+    let lengths = vertex_path_lengths(pline);
+    let total = lengths[lengths.len() - 1];
+    if total <= eps {
+        return Vec::new();
+    }
+
+    let ranges = dash_ranges(
+        options.pattern,
+        options.phase,
+        total,
+        pline.is_closed(),
+        options.fit_closed,
+        eps,
+    );
+
+    let seg_count = lengths.len() - 1;
+    // segment index only moves forward since dash ranges are increasing
+    let mut seg_index = 0;
+    let mut point_at_length = |length: T| -> (usize, Vector2<T>) {
+        while seg_index + 1 < seg_count && lengths[seg_index + 1] <= length {
+            seg_index += 1;
+        }
+        let v1 = pline.at(seg_index);
+        let v2 = pline.at(pline.next_wrapping_index(seg_index));
+        let seg_len = lengths[seg_index + 1] - lengths[seg_index];
+        if seg_len <= T::zero() {
+            return (seg_index, v1.pos());
+        }
+        let t = num_traits::real::Real::min((length - lengths[seg_index]) / seg_len, T::one());
+        (seg_index, seg_point_at_parametric(v1, v2, t))
+    };
+
+    let mut result = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        if start <= eps && end >= total - eps {
+            result.push(PlineViewData::from_entire_pline(pline));
+            continue;
+        }
+
+        let view_data = if end > total {
+            path_range_view_data(pline, &lengths, start, end, eps)
+        } else {
+            let (start_index, start_point) = point_at_length(start);
+            let (end_index, end_point) = point_at_length(end);
+            PlineViewData::from_slice_points(
+                pline,
+                start_point,
+                start_index,
+                end_point,
+                end_index,
+                eps,
+            )
+        };
+
+        if let Some(view_data) = view_data {
+            result.push(view_data);
+        }
+    }
+
+    result
+}
+
+/// Same as [dash_pattern_views] but returns each dash as an owned open polyline.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let square: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// // pattern repeats 3.6 times around the square, scaled so 4 repeats fit evenly
+/// let options = DashOptions {
+///     pattern: &[5.5, 5.5],
+///     phase: 2.75,
+///     fit_closed: true,
+///     ..Default::default()
+/// };
+/// let dashes = dash_pattern(&square, &options);
+/// assert_eq!(dashes.len(), 4);
+/// for dash in dashes.iter() {
+///     assert!(!dash.is_closed());
+///     assert_fuzzy_eq!(dash.path_length(), 5.0);
+/// }
+/// // dashes centered on the corners, first dash wraps past the start
+/// assert_fuzzy_eq!(dashes[0].at(1).x, 0.0);
+/// assert_fuzzy_eq!(dashes[0].at(1).y, 0.0);
+/// assert_fuzzy_eq!(dashes[1].at(1).x, 10.0);
+/// assert_fuzzy_eq!(dashes[1].at(1).y, 0.0);
+/// ```
+pub fn dash_pattern<P, T>(pline: &P, options: &DashOptions<T>) -> Vec<Polyline<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    dash_pattern_views(pline, options)
+        .into_iter()
+        .map(|view_data| Polyline::create_from(&view_data.view(pline)))
+        .collect()
+}
@@ -0,0 +1,197 @@
+use super::{
+    seg_bounding_box, seg_closest_point, PlineSource, PlineVertex, PointLocator, RasterGrid,
+};
+use crate::core::{
+    math::{dist_squared, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index as aabb_index;
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+
+/// Closest boundary point found by [DistanceField::closest_point].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceFieldClosest<T = f64> {
+    /// Index of the polyline (in the order given to [DistanceField::new]) the point lies on.
+    pub pline_index: usize,
+    /// Start vertex index of the segment the point lies on.
+    pub seg_start_index: usize,
+    /// Closest point.
+    pub point: Vector2<T>,
+    /// Distance from the query point to the closest point.
+    pub distance: T,
+}
+
+/// Structure for fast repeated distance queries against a set of polylines, used to sample
+/// signed distance fields.
+///
+/// The segments of all the polylines are copied and a spatial index of their bounding boxes is
+/// built once, each query then finds the nearest segment by visiting the index in order of
+/// bounding box distance. Signed distances are negative inside the region defined by the closed
+/// polylines (winding number is not zero, see [PointLocator]) and positive outside. Open
+/// polylines contribute to the distance but not to the sign, so a field of only open polylines
+/// is an unsigned distance field.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let square: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let field = DistanceField::new(std::iter::once(&square), 1e-5);
+/// assert_fuzzy_eq!(field.signed_distance(Vector2::new(2.0, 5.0)).unwrap(), -2.0);
+/// assert_fuzzy_eq!(field.signed_distance(Vector2::new(13.0, 14.0)).unwrap(), 5.0);
+/// assert_fuzzy_eq!(field.distance(Vector2::new(2.0, 5.0)).unwrap(), 2.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DistanceField<T = f64>
+where
+    T: Real,
+{
+    /// Segments with their polyline index and segment start index.
+    segments: Vec<(PlineVertex<T>, PlineVertex<T>, usize, usize)>,
+    aabb_index: Option<StaticAABB2DIndex<T>>,
+    locator: PointLocator<T>,
+    pos_equal_eps: T,
+}
+
+impl<T> DistanceField<T>
+where
+    T: Real,
+{
+    /// Build a distance field from a set of polylines (open or closed).
+    ///
+    /// Polylines with less than 2 vertexes are ignored. `pos_equal_eps` is used for segment
+    /// closest point calculations.
+    pub fn new<'a, P, I>(plines: I, pos_equal_eps: T) -> Self
+    where
+        P: PlineSource<Num = T> + 'a + ?Sized,
+        I: IntoIterator<Item = &'a P>,
+    {
+        let plines = plines.into_iter().collect::<Vec<_>>();
+        let segments = plines
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.vertex_count() >= 2)
+            .flat_map(|(i, p)| {
+                p.iter_segment_indexes()
+                    .zip(p.iter_segments())
+                    .map(move |((start, _), (v1, v2))| (v1, v2, i, start))
+            })
+            .collect::<Vec<_>>();
+
+        let aabb_index = if segments.is_empty() {
+            None
+        } else {
+            let mut builder = StaticAABB2DIndexBuilder::new(segments.len());
+            for &(v1, v2, _, _) in segments.iter() {
+                let bb = seg_bounding_box(v1, v2);
+                builder.add(bb.min_x, bb.min_y, bb.max_x, bb.max_y);
+            }
+            builder.build().ok()
+        };
+
+        Self {
+            segments,
+            aabb_index,
+            locator: PointLocator::new(plines.iter().copied(), pos_equal_eps),
+            pos_equal_eps,
+        }
+    }
+
+    /// Spatial index of the segment bounding boxes (`None` if there are no segments).
+    #[inline]
+    pub fn aabb_index(&self) -> Option<&StaticAABB2DIndex<T>> {
+        self.aabb_index.as_ref()
+    }
+
+    /// Point locator used for the sign of the distance queries.
+    #[inline]
+    pub fn locator(&self) -> &PointLocator<T> {
+        &self.locator
+    }
+
+    /// Find the closest point on any polyline to `point`, returns `None` if there are no
+    /// segments.
+    pub fn closest_point(&self, point: Vector2<T>) -> Option<DistanceFieldClosest<T>> {
+        let index = self.aabb_index.as_ref()?;
+        let eps = self.pos_equal_eps;
+        let mut closest_dist2 = <T as Real>::max_value();
+        let mut closest = None;
+        let mut visitor = |i: usize, box_dist2: T| {
+            if box_dist2 > closest_dist2 {
+                // all remaining segments are further away than the closest found
+                return aabb_index::Control::Break(());
+            }
+            let (v1, v2, pline_index, seg_start_index) = self.segments[i];
+            let seg_point = seg_closest_point(v1, v2, point, eps);
+            let dist2 = dist_squared(seg_point, point);
+            if dist2 < closest_dist2 {
+                closest_dist2 = dist2;
+                closest = Some((pline_index, seg_start_index, seg_point));
+            }
+            aabb_index::Control::Continue
+        };
+        index.visit_neighbors(point.x, point.y, &mut visitor);
+
+        closest.map(
+            |(pline_index, seg_start_index, seg_point)| DistanceFieldClosest {
+                pline_index,
+                seg_start_index,
+                point: seg_point,
+                distance: closest_dist2.sqrt(),
+            },
+        )
+    }
+
+    /// Unsigned distance from `point` to the closest point on any polyline, returns `None` if
+    /// there are no segments.
+    #[inline]
+    pub fn distance(&self, point: Vector2<T>) -> Option<T> {
+        self.closest_point(point).map(|c| c.distance)
+    }
+
+    /// Signed distance from `point` to the closest point on any polyline, negative if `point` is
+    /// inside the region defined by the closed polylines and positive otherwise. Returns `None`
+    /// if there are no segments.
+    pub fn signed_distance(&self, point: Vector2<T>) -> Option<T> {
+        let dist = self.distance(point)?;
+        if self.locator.winding_number(point) != 0 {
+            Some(-dist)
+        } else {
+            Some(dist)
+        }
+    }
+
+    /// Sample the signed distance at the center of every pixel of `grid` (stored row by row, see
+    /// [RasterGrid]). Returns an empty vector if there are no segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle: Polyline = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    /// let field = DistanceField::new(std::iter::once(&circle), 1e-5);
+    /// let grid = RasterGrid::new(Vector2::new(-2.0, -2.0), 1.0, 4, 4);
+    /// let samples = field.sample_grid(&grid);
+    /// // pixel center at (-0.5, -0.5)
+    /// assert!((samples[1 * 4 + 1] - (0.5f64.sqrt() - 1.0)).abs() < 1e-9);
+    /// ```
+    pub fn sample_grid(&self, grid: &RasterGrid<T>) -> Vec<T> {
+        if self.segments.is_empty() {
+            return Vec::new();
+        }
+
+        let mut result = Vec::with_capacity(grid.width * grid.height);
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                // segments are not empty so the distance always exists
+                result.push(self.signed_distance(grid.pixel_center(col, row)).unwrap());
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,545 @@
+use super::{
+    seg_arc_radius_and_center, seg_bounding_box, seg_length, seg_split_at_point,
+    PlineContainmentTree, PlineCreation, PlineSource, PlineSourceMut, PlineVertex, PlineViewData,
+    Polyline,
+};
+use crate::core::{
+    math::{angle, angle_from_bulge, normalize_radians, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index as aabb_index;
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+
+/// Options for generating hatch lines with [hatch_fill].
+#[derive(Debug, Clone)]
+pub struct HatchOptions<T = f64> {
+    /// Direction of the hatch lines as an angle in radians measured counter clockwise from the
+    /// positive x axis.
+    pub angle: T,
+    /// Distance between adjacent hatch lines (measured perpendicular to the lines), no lines are
+    /// generated if it is not positive or not finite.
+    pub spacing: T,
+    /// Perpendicular offset of the hatch lines from the origin, hatch lines are placed at `phase +
+    /// k * spacing` for all integers `k` so the same lines are produced regardless of the region
+    /// extents.
+    pub phase: T,
+    /// Distance the region boundary is offset inward (using [PlineSource::parallel_offset])
+    /// before generating the hatch lines, outer loops shrink and holes grow.
+    pub margin: T,
+    /// If true then adjacent hatch lines are connected into zig-zag paths by following the region
+    /// boundary between their end points (with exact arcs), otherwise every hatch line interval is
+    /// returned as its own two vertex polyline.
+    pub zigzag: bool,
+    /// If true then a second set of hatch lines is generated at `angle + PI / 2`.
+    pub crosshatch: bool,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> HatchOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            angle: T::zero(),
+            spacing: T::one(),
+            phase: T::zero(),
+            margin: T::zero(),
+            zigzag: false,
+            crosshatch: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for HatchOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Generate hatch line toolpaths filling the region defined by a set of closed polylines.
+///
+/// The region is the area inside the outer loops and outside of their holes, the polylines may
+/// be given in any order and orientation (nesting is found using [PlineContainmentTree]) but they
+/// must not cross each other. Open polylines and polylines with less than 2 vertexes are ignored.
+///
+/// Hatch lines are clipped exactly against the region boundary (arc segments are not
+/// approximated) and returned as open polylines. Each hatch line interval is a two vertex line
+/// polyline unless [HatchOptions::zigzag] is set in which case intervals on adjacent hatch lines
+/// are joined by the portion of the boundary between them (which may contain arcs).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// let square = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let options = HatchOptions {
+///     spacing: 2.0,
+///     phase: 1.0,
+///     ..Default::default()
+/// };
+///
+/// let lines = hatch_fill(std::iter::once(&square), &options);
+/// // horizontal lines at y = 1, 3, 5, 7, 9
+/// assert_eq!(lines.len(), 5);
+/// assert!(lines.iter().all(|l| l.vertex_count() == 2 && !l.is_closed()));
+///
+/// // connect the lines into one zig-zag path
+/// let options = HatchOptions { zigzag: true, ..options };
+/// let paths = hatch_fill(&[square], &options);
+/// assert_eq!(paths.len(), 1);
+/// assert_eq!(paths[0].vertex_count(), 10);
+/// ```
+pub fn hatch_fill<'a, P, I, T>(plines: I, options: &HatchOptions<T>) -> Vec<Polyline<T>>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    // false for NaN and infinity
+    let is_finite = |x: T| x.abs() <= <T as Real>::max_value();
+    if options.spacing <= eps
+        || !is_finite(options.spacing)
+        || !is_finite(options.phase)
+        || !is_finite(options.angle)
+        || !is_finite(options.margin)
+    {
+        return Vec::new();
+    }
+
+    let mut loops = plines
+        .into_iter()
+        .filter(|p| p.is_closed() && p.vertex_count() >= 2)
+        .map(|p| Polyline::create_from(p))
+        .collect::<Vec<Polyline<T>>>();
+
+    // outer loops counter clockwise and holes clockwise so the region is where the winding
+    // number is positive, this also holds after offsetting inward where offset loops may overlap
+    let tree = PlineContainmentTree::build(&loops, eps);
+    tree.fix_orientations(&mut loops);
+
+    if !options.margin.fuzzy_eq_zero_eps(eps) {
+        loops = loops
+            .iter()
+            .flat_map(|p| p.parallel_offset(options.margin))
+            .filter(|p| p.is_closed() && p.vertex_count() >= 2)
+            .collect();
+    }
+
+    if loops.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result = hatch_at_angle(&loops, options.angle, options);
+    if options.crosshatch {
+        result.extend(hatch_at_angle(
+            &loops,
+            options.angle + T::pi() / T::two(),
+            options,
+        ));
+    }
+
+    result
+}
+
+/// Hatch line crossing of a region boundary segment.
+#[derive(Debug, Clone, Copy)]
+struct Crossing<T> {
+    /// Position along the hatch line.
+    x: T,
+    /// Change in winding number when passing the crossing going along the hatch line.
+    winding_delta: i32,
+    loop_index: usize,
+    seg_index: usize,
+}
+
+/// Hatch line interval inside the region.
+#[derive(Debug, Clone, Copy)]
+struct Interval<T> {
+    line_index: usize,
+    y: T,
+    start: Crossing<T>,
+    end: Crossing<T>,
+}
+
+/// Crossing position along its boundary loop, used for zig-zag connections.
+#[derive(Debug, Clone, Copy)]
+struct LoopPoint<T> {
+    seg_index: usize,
+    dist_along_seg: T,
+    point: Vector2<T>,
+    /// Interval index and whether this point is its start, `None` if the crossing is not an
+    /// interval end point.
+    interval_end: Option<(usize, bool)>,
+}
+
+/// Transform `pline` by rotating it by `-angle` (hatch lines become horizontal).
+fn rotated<T>(pline: &Polyline<T>, cos_a: T, sin_a: T) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut result = Polyline::with_capacity(pline.vertex_count(), pline.is_closed());
+    for v in pline.iter_vertexes() {
+        result.add(
+            v.x * cos_a + v.y * sin_a,
+            -v.x * sin_a + v.y * cos_a,
+            v.bulge,
+        );
+    }
+    result
+}
+
+/// Visit the crossings of the horizontal line at `y` with the segment `v1` to `v2`.
+///
+/// A segment end point is considered above the line if its y value is greater than `y`, crossings
+/// are found where a segment (or y monotonic arc piece) goes from above to not above the line or
+/// vice versa. This makes shared vertexes and segments lying along the line consistent between
+/// adjacent segments.
+fn visit_seg_crossings<T, F>(v1: PlineVertex<T>, v2: PlineVertex<T>, y: T, visitor: &mut F)
+where
+    T: Real,
+    F: FnMut(T, i32),
+{
+    let winding_delta = |start_above: bool| if start_above { 1 } else { -1 };
+
+    if v1.bulge_is_zero() {
+        let above1 = v1.y > y;
+        let above2 = v2.y > y;
+        if above1 != above2 {
+            let x = v1.x + (v2.x - v1.x) * (y - v1.y) / (v2.y - v1.y);
+            visitor(x, winding_delta(above1));
+        }
+        return;
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let sweep = angle_from_bulge(v1.bulge);
+    let start_angle = angle(center, v1.pos());
+    let angle_dist = |a: T| {
+        if sweep > T::zero() {
+            normalize_radians(a - start_angle)
+        } else {
+            normalize_radians(start_angle - a)
+        }
+    };
+
+    // split arc into y monotonic pieces at the top and bottom of the circle
+    let half_pi = T::pi() / T::two();
+    let total = sweep.abs();
+    let angle_eps = T::from(1e-9).unwrap();
+    let mut splits = Vec::with_capacity(2);
+    for (a, pt) in [
+        (half_pi, Vector2::new(center.x, center.y + radius)),
+        (-half_pi, Vector2::new(center.x, center.y - radius)),
+    ]
+    .iter()
+    {
+        let d = angle_dist(*a);
+        if d > angle_eps && d < total - angle_eps {
+            splits.push((d, *pt));
+        }
+    }
+    splits.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut piece_start = (T::zero(), v1.pos());
+    let piece_ends = splits.into_iter().chain(std::iter::once((total, v2.pos())));
+    for piece_end in piece_ends {
+        let above1 = piece_start.1.y > y;
+        let above2 = piece_end.1.y > y;
+        if above1 != above2 {
+            // piece lies entirely on one side (left or right) of the circle center
+            let mid_dist = (piece_start.0 + piece_end.0) / T::two();
+            let mid_angle = if sweep > T::zero() {
+                start_angle + mid_dist
+            } else {
+                start_angle - mid_dist
+            };
+            let dy = y - center.y;
+            let dx = num_traits::real::Real::max(radius * radius - dy * dy, T::zero()).sqrt();
+            let x = if mid_angle.cos() > T::zero() {
+                center.x + dx
+            } else {
+                center.x - dx
+            };
+            visitor(x, winding_delta(above1));
+        }
+        piece_start = piece_end;
+    }
+}
+
+/// Length along the segment `v1` to `v2` from `v1` to `point`.
+fn dist_along_seg<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>, eps: T) -> T
+where
+    T: Real,
+{
+    if v1.pos().fuzzy_eq_eps(point, eps) {
+        return T::zero();
+    }
+    let split = seg_split_at_point(v1, v2, point, eps);
+    seg_length(split.updated_start, split.split_vertex)
+}
+
+/// Generate hatch lines at a single angle.
+fn hatch_at_angle<T>(loops: &[Polyline<T>], angle: T, options: &HatchOptions<T>) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let (sin_a, cos_a) = angle.sin_cos();
+    let loops = loops
+        .iter()
+        .map(|p| rotated(p, cos_a, sin_a))
+        .collect::<Vec<_>>();
+
+    let segments = loops
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p)| p.iter_segment_indexes().map(move |(s, _)| (i, s)))
+        .collect::<Vec<_>>();
+
+    let seg_vertexes = |(i, s): (usize, usize)| {
+        let p = &loops[i];
+        (p.at(s), p.at(p.next_wrapping_index(s)))
+    };
+
+    let aabb_index: StaticAABB2DIndex<T> = {
+        let mut builder = StaticAABB2DIndexBuilder::new(segments.len());
+        for &seg in segments.iter() {
+            let (v1, v2) = seg_vertexes(seg);
+            let bb = seg_bounding_box(v1, v2);
+            builder.add(bb.min_x, bb.min_y, bb.max_x, bb.max_y);
+        }
+        builder.build().unwrap()
+    };
+
+    let first_line = ((aabb_index.min_y() - options.phase) / options.spacing)
+        .ceil()
+        .to_i64();
+    let last_line = ((aabb_index.max_y() - options.phase) / options.spacing)
+        .floor()
+        .to_i64();
+    let (first_line, last_line) = match (first_line, last_line) {
+        (Some(first), Some(last)) => (first, last),
+        // line indexes out of range (e.g. non-finite vertex positions)
+        _ => return Vec::new(),
+    };
+
+    let mut intervals = Vec::new();
+    // all crossings for each loop (used to connect intervals along loops)
+    let mut loop_points: Vec<Vec<LoopPoint<T>>> = vec![Vec::new(); loops.len()];
+    let mut crossings = Vec::new();
+    let mut query_stack = Vec::new();
+    for (line_index, k) in (first_line..=last_line).enumerate() {
+        let y = options.phase + T::from(k).unwrap() * options.spacing;
+        crossings.clear();
+        aabb_index.visit_query_with_stack(
+            aabb_index.min_x(),
+            y,
+            aabb_index.max_x(),
+            y,
+            &mut |i: usize| {
+                let (loop_index, seg_index) = segments[i];
+                let (v1, v2) = seg_vertexes(segments[i]);
+                visit_seg_crossings(v1, v2, y, &mut |x, winding_delta| {
+                    crossings.push(Crossing {
+                        x,
+                        winding_delta,
+                        loop_index,
+                        seg_index,
+                    })
+                });
+                aabb_index::Control::<()>::Continue
+            },
+            &mut query_stack,
+        );
+
+        // sort along line, positive winding changes first so touching regions are merged
+        crossings.sort_unstable_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.winding_delta.cmp(&a.winding_delta))
+        });
+
+        let mut interval_ends = vec![None; crossings.len()];
+        let mut winding = 0;
+        let mut interval_start = None;
+        for (ci, c) in crossings.iter().enumerate() {
+            let prev_winding = winding;
+            winding += c.winding_delta;
+            if prev_winding <= 0 && winding > 0 {
+                interval_start = Some(ci);
+            } else if prev_winding > 0 && winding <= 0 {
+                let si = interval_start.take().unwrap();
+                if c.x - crossings[si].x > eps {
+                    let interval_index = intervals.len();
+                    interval_ends[si] = Some((interval_index, true));
+                    interval_ends[ci] = Some((interval_index, false));
+                    intervals.push(Interval {
+                        line_index,
+                        y,
+                        start: crossings[si],
+                        end: *c,
+                    });
+                }
+            }
+        }
+
+        if options.zigzag {
+            for (c, interval_end) in crossings.iter().zip(interval_ends) {
+                let (v1, v2) = seg_vertexes((c.loop_index, c.seg_index));
+                let point = Vector2::new(c.x, y);
+                loop_points[c.loop_index].push(LoopPoint {
+                    seg_index: c.seg_index,
+                    dist_along_seg: dist_along_seg(v1, v2, point, eps),
+                    point,
+                    interval_end,
+                });
+            }
+        }
+    }
+
+    let output_vertex = |v: PlineVertex<T>| {
+        PlineVertex::new(
+            v.x * cos_a - v.y * sin_a,
+            v.x * sin_a + v.y * cos_a,
+            v.bulge,
+        )
+    };
+
+    if !options.zigzag {
+        return intervals
+            .iter()
+            .map(|interval| {
+                let mut pline = Polyline::with_capacity(2, false);
+                let start = PlineVertex::new(interval.start.x, interval.y, T::zero());
+                let end = PlineVertex::new(interval.end.x, interval.y, T::zero());
+                pline.add_vertex(output_vertex(start));
+                pline.add_vertex(output_vertex(end));
+                pline
+            })
+            .collect();
+    }
+
+    // order points along each loop and record where each interval end point is
+    let mut end_positions = vec![[(0, 0); 2]; intervals.len()];
+    for (loop_index, points) in loop_points.iter_mut().enumerate() {
+        points.sort_unstable_by(|a, b| {
+            a.seg_index.cmp(&b.seg_index).then(
+                a.dist_along_seg
+                    .partial_cmp(&b.dist_along_seg)
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+        });
+        for (pos, p) in points.iter().enumerate() {
+            if let Some((interval_index, is_start)) = p.interval_end {
+                let side = if is_start { 0 } else { 1 };
+                end_positions[interval_index][side] = (loop_index, pos);
+            }
+        }
+    }
+
+    let mut visited = vec![false; intervals.len()];
+
+    // find boundary path from an interval end point to an unvisited interval on the next hatch
+    // line, the path must not pass any other crossing
+    let find_connection = |visited: &[bool], interval_index: usize, exit_is_start: bool| {
+        let side = if exit_is_start { 0 } else { 1 };
+        let (loop_index, pos) = end_positions[interval_index][side];
+        let points = &loop_points[loop_index];
+        let count = points.len();
+        let exit = points[pos];
+        let next_line = intervals[interval_index].line_index + 1;
+        for &forward in [true, false].iter() {
+            let nb_pos = if forward {
+                (pos + 1) % count
+            } else {
+                (pos + count - 1) % count
+            };
+            let nb = points[nb_pos];
+            let (next_index, next_is_start) = match nb.interval_end {
+                Some(x) => x,
+                None => continue,
+            };
+
+            if visited[next_index] || intervals[next_index].line_index != next_line {
+                continue;
+            }
+
+            let (from, to) = if forward { (exit, nb) } else { (nb, exit) };
+            if from.seg_index == to.seg_index && from.dist_along_seg > to.dist_along_seg {
+                // path wraps around the entire loop
+                continue;
+            }
+
+            let path = PlineViewData::from_slice_points(
+                &loops[loop_index],
+                from.point,
+                from.seg_index,
+                to.point,
+                to.seg_index,
+                eps,
+            )
+            .map(|mut data| {
+                data.inverted_direction = !forward;
+                data
+            });
+
+            return Some((next_index, next_is_start, path, loop_index));
+        }
+
+        None
+    };
+
+    let mut result = Vec::new();
+    for first in 0..intervals.len() {
+        if visited[first] {
+            continue;
+        }
+        visited[first] = true;
+
+        // traverse the first interval in the direction that allows connecting to the next line
+        let mut current = first;
+        let mut enter_at_start = find_connection(&visited, first, false).is_some()
+            || find_connection(&visited, first, true).is_none();
+
+        let mut pline = Polyline::new();
+        loop {
+            let interval = &intervals[current];
+            let (enter, exit) = if enter_at_start {
+                (interval.start, interval.end)
+            } else {
+                (interval.end, interval.start)
+            };
+            pline.add_or_replace_vertex(PlineVertex::new(enter.x, interval.y, T::zero()), eps);
+            pline.add_or_replace_vertex(PlineVertex::new(exit.x, interval.y, T::zero()), eps);
+
+            match find_connection(&visited, current, !enter_at_start) {
+                Some((next, next_is_start, path, loop_index)) => {
+                    if let Some(path) = path {
+                        pline.extend_remove_repeat(&path.view(&loops[loop_index]), eps);
+                    }
+                    visited[next] = true;
+                    current = next;
+                    enter_at_start = next_is_start;
+                }
+                None => break,
+            }
+        }
+
+        for v in pline.vertex_data.iter_mut() {
+            *v = output_vertex(*v);
+        }
+        result.push(pline);
+    }
+
+    result
+}
@@ -0,0 +1,218 @@
+use super::{PlineContainmentTree, PlineCreation, PlineSource, PointLocator, Polyline};
+use crate::core::{math::Vector2, traits::Real};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Options for finding the maximum inscribed circle with [maximum_inscribed_circle].
+#[derive(Debug, Clone)]
+pub struct InscribedCircleOptions<T = f64> {
+    /// Absolute tolerance of the result, the radius found is within this distance of the true
+    /// maximum inscribed circle radius.
+    pub tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> InscribedCircleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            tolerance: T::from(1e-4).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for InscribedCircleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Circle found by [maximum_inscribed_circle].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InscribedCircle<T = f64> {
+    /// Center of the circle.
+    pub center: Vector2<T>,
+    /// Radius of the circle (distance from the center to the closest point on the boundary).
+    pub radius: T,
+}
+
+/// Square cell of the search grid.
+#[derive(Debug, Clone, Copy)]
+struct Cell<T> {
+    center: Vector2<T>,
+    half_size: T,
+    /// Signed distance from the cell center to the region boundary.
+    dist: T,
+    /// Upper bound of the signed distance for any point in the cell.
+    max_dist: T,
+}
+
+impl<T> Cell<T>
+where
+    T: Real,
+{
+    fn new(center: Vector2<T>, half_size: T, locator: &PointLocator<T>) -> Self {
+        let dist = locator
+            .signed_distance(center)
+            .unwrap_or_else(<T as Real>::min_value);
+        let max_dist = dist + half_size * T::two().sqrt();
+        Self {
+            center,
+            half_size,
+            dist,
+            max_dist,
+        }
+    }
+}
+
+impl<T> PartialEq for Cell<T>
+where
+    T: Real,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.max_dist == other.max_dist
+    }
+}
+
+impl<T> Eq for Cell<T> where T: Real {}
+
+impl<T> PartialOrd for Cell<T>
+where
+    T: Real,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Cell<T>
+where
+    T: Real,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_dist
+            .partial_cmp(&other.max_dist)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Find the maximum inscribed circle of a region defined by a set of closed polylines.
+///
+/// The region is the area inside the outer polylines excluding the holes, nesting is determined
+/// with [PlineContainmentTree] so the input orientations do not need to be consistent. The radius
+/// of the circle is the collapse distance of the region: the largest distance the region can be
+/// offset inward before it disappears.
+///
+/// The circle is found directly by subdividing square cells covering the region (largest
+/// potential distance first) and pruning cells which cannot contain a center further from the
+/// boundary than the best found (within [InscribedCircleOptions::tolerance]), distances to the
+/// boundary are found using [PointLocator::signed_distance].
+///
+/// Returns `None` if there are no closed polylines with area.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::pline_closed;
+/// let outer: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+/// let circle = maximum_inscribed_circle(std::iter::once(&outer), &Default::default()).unwrap();
+/// assert!((circle.radius - 2.0).abs() < 1e-4);
+/// assert!((circle.center.y - 2.0).abs() < 1e-3);
+/// ```
+pub fn maximum_inscribed_circle<'a, P, I, T>(
+    plines: I,
+    options: &InscribedCircleOptions<T>,
+) -> Option<InscribedCircle<T>>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let mut loops = plines
+        .into_iter()
+        .filter(|p| p.is_closed() && p.vertex_count() >= 2)
+        .map(|p| Polyline::create_from(p))
+        .collect::<Vec<Polyline<T>>>();
+
+    // outer loops counter clockwise and holes clockwise so the region is where the winding number
+    // is not zero
+    let tree = PlineContainmentTree::build(&loops, eps);
+    tree.fix_orientations(&mut loops);
+
+    let extents = loops.iter().filter_map(|p| p.extents()).reduce(|a, b| {
+        static_aabb2d_index::AABB::new(
+            num_traits::real::Real::min(a.min_x, b.min_x),
+            num_traits::real::Real::min(a.min_y, b.min_y),
+            num_traits::real::Real::max(a.max_x, b.max_x),
+            num_traits::real::Real::max(a.max_y, b.max_y),
+        )
+    })?;
+
+    let width = extents.max_x - extents.min_x;
+    let height = extents.max_y - extents.min_y;
+    let cell_size = num_traits::real::Real::min(width, height);
+    if cell_size <= eps {
+        return None;
+    }
+
+    let locator = PointLocator::new(&loops, eps);
+    let tolerance = num_traits::real::Real::max(options.tolerance, eps);
+    let half_size = cell_size / T::two();
+
+    // cover the extents with cells
+    let mut queue = BinaryHeap::new();
+    let mut x = extents.min_x;
+    while x < extents.max_x {
+        let mut y = extents.min_y;
+        while y < extents.max_y {
+            let center = Vector2::new(x + half_size, y + half_size);
+            queue.push(Cell::new(center, half_size, &locator));
+            y = y + cell_size;
+        }
+        x = x + cell_size;
+    }
+
+    // initial best guess at the extents center
+    let extents_center = Vector2::new(
+        (extents.min_x + extents.max_x) / T::two(),
+        (extents.min_y + extents.max_y) / T::two(),
+    );
+    let mut best = Cell::new(extents_center, T::zero(), &locator);
+
+    while let Some(cell) = queue.pop() {
+        if cell.dist > best.dist {
+            best = cell;
+        }
+
+        if cell.max_dist - best.dist <= tolerance {
+            // no remaining cell can improve on the best found
+            break;
+        }
+
+        let h = cell.half_size / T::two();
+        for &(dx, dy) in [(-h, -h), (h, -h), (-h, h), (h, h)].iter() {
+            let center = Vector2::new(cell.center.x + dx, cell.center.y + dy);
+            queue.push(Cell::new(center, h, &locator));
+        }
+    }
+
+    if best.dist <= eps {
+        return None;
+    }
+
+    Some(InscribedCircle {
+        center: best.center,
+        radius: best.dist,
+    })
+}
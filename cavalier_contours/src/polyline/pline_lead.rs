@@ -0,0 +1,375 @@
+use super::{
+    seg_tangent_vector, PlineCreation, PlineOrientation, PlineSource, PlineSourceMut, PlineVertex,
+    PlineViewData, PointLocator, Polyline,
+};
+use crate::core::{
+    math::{bulge_from_angle, Vector2},
+    traits::Real,
+};
+
+/// How the entry point of a closed polyline is chosen, see [LeadOptions::entry].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LeadEntry<T = f64> {
+    /// Enter at the point on the polyline closest to the point given.
+    ClosestTo(Vector2<T>),
+    /// Enter at the point at the path length given (measured from the first vertex, wraps around
+    /// the polyline if greater than its total path length).
+    AtPathLength(T),
+}
+
+/// Shape of a lead-in or lead-out move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LeadShape<T = f64> {
+    /// No lead move.
+    None,
+    /// Straight line tangent to the path with the length given, the lead-in approaches along the
+    /// path direction at the entry point and the lead-out continues along the path direction at
+    /// the exit point. The line extends the path so it can not be moved to [LeadOptions::side],
+    /// where the path curves towards the other side the line crosses the path and the lead is
+    /// marked invalid (see [LeadResult::lead_in_valid]).
+    Line {
+        /// Length of the line.
+        length: T,
+    },
+    /// Straight line perpendicular to the path with the length given, placed on
+    /// [LeadOptions::side]. Unlike [LeadShape::Line] the direction changes sharply where the line
+    /// meets the path.
+    PerpendicularLine {
+        /// Length of the line.
+        length: T,
+    },
+    /// Quarter circle arc tangent to the path with the radius given.
+    Arc {
+        /// Radius of the arc.
+        radius: T,
+    },
+}
+
+/// Side of the closed polyline the lead moves are placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeadSide {
+    /// Leads are placed inside the polyline (e.g. for pockets and holes).
+    Inside,
+    /// Leads are placed outside the polyline (e.g. for outer part profiles).
+    Outside,
+}
+
+/// Options for adding lead moves with [add_leads].
+#[derive(Debug, Clone)]
+pub struct LeadOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// How the entry point is chosen.
+    pub entry: LeadEntry<T>,
+    /// Lead-in move ending at the entry point.
+    pub lead_in: LeadShape<T>,
+    /// Lead-out move starting at the exit point.
+    pub lead_out: LeadShape<T>,
+    /// Side of the polyline the lead moves are placed on.
+    pub side: LeadSide,
+    /// Distance to continue along the polyline past the entry point before the lead-out (clamped
+    /// to one full loop).
+    pub overlap: T,
+    /// Region the lead moves must stay within, if `None` then lead moves are always considered
+    /// valid.
+    pub allowed_region: Option<&'a PointLocator<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> LeadOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entry: LeadEntry::AtPathLength(T::zero()),
+            lead_in: LeadShape::None,
+            lead_out: LeadShape::None,
+            side: LeadSide::Outside,
+            overlap: T::zero(),
+            allowed_region: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for LeadOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of [add_leads].
+#[derive(Debug, Clone)]
+pub struct LeadResult<T = f64> {
+    /// Closed polyline rotated to start at the entry point.
+    pub rotated: Polyline<T>,
+    /// Lead-in move (empty if no lead-in).
+    pub lead_in: Polyline<T>,
+    /// Lead-out move (empty if no lead-out).
+    pub lead_out: Polyline<T>,
+    /// Complete open toolpath: lead-in, the full loop starting at the entry point, the overlap, and
+    /// lead-out.
+    pub toolpath: Polyline<T>,
+    /// False if the lead-in crosses to the other side of the polyline than [LeadOptions::side] or
+    /// leaves [LeadOptions::allowed_region].
+    pub lead_in_valid: bool,
+    /// False if the lead-out crosses to the other side of the polyline than [LeadOptions::side] or
+    /// leaves [LeadOptions::allowed_region].
+    pub lead_out_valid: bool,
+}
+
+/// Normal of `tangent` pointing to the side given.
+fn side_normal<T>(tangent: Vector2<T>, side: LeadSide, inside_is_left: bool) -> Vector2<T>
+where
+    T: Real,
+{
+    let left = tangent.perp();
+    if (side == LeadSide::Inside) == inside_is_left {
+        left
+    } else {
+        -left
+    }
+}
+
+/// Quarter arc bulge for an arc turning towards `normal` while traveling along `tangent`.
+fn quarter_arc_bulge<T>(tangent: Vector2<T>, normal: Vector2<T>) -> T
+where
+    T: Real,
+{
+    let bulge = bulge_from_angle(T::pi() / T::two());
+    if tangent.perp_dot(normal) > T::zero() {
+        bulge
+    } else {
+        -bulge
+    }
+}
+
+/// Lead move arriving at `point` traveling along `tangent` (unit vector), approaching from the
+/// `normal` side.
+fn create_lead_in<T>(
+    shape: LeadShape<T>,
+    point: Vector2<T>,
+    tangent: Vector2<T>,
+    normal: Vector2<T>,
+    eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut result = Polyline::new();
+    match shape {
+        LeadShape::Line { length } if length > eps => {
+            let start = point - tangent.scale(length);
+            result.add(start.x, start.y, T::zero());
+            result.add(point.x, point.y, T::zero());
+        }
+        LeadShape::PerpendicularLine { length } if length > eps => {
+            let start = point + normal.scale(length);
+            result.add(start.x, start.y, T::zero());
+            result.add(point.x, point.y, T::zero());
+        }
+        LeadShape::Arc { radius } if radius > eps => {
+            let center = point + normal.scale(radius);
+            let start = center - tangent.scale(radius);
+            result.add(start.x, start.y, quarter_arc_bulge(tangent, normal));
+            result.add(point.x, point.y, T::zero());
+        }
+        _ => {}
+    }
+    result
+}
+
+/// Lead move departing from `point` traveling along `tangent` (unit vector), leaving towards the
+/// `normal` side.
+fn create_lead_out<T>(
+    shape: LeadShape<T>,
+    point: Vector2<T>,
+    tangent: Vector2<T>,
+    normal: Vector2<T>,
+    eps: T,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut result = Polyline::new();
+    match shape {
+        LeadShape::Line { length } if length > eps => {
+            let end = point + tangent.scale(length);
+            result.add(point.x, point.y, T::zero());
+            result.add(end.x, end.y, T::zero());
+        }
+        LeadShape::PerpendicularLine { length } if length > eps => {
+            let end = point + normal.scale(length);
+            result.add(point.x, point.y, T::zero());
+            result.add(end.x, end.y, T::zero());
+        }
+        LeadShape::Arc { radius } if radius > eps => {
+            let center = point + normal.scale(radius);
+            let end = center + tangent.scale(radius);
+            result.add(point.x, point.y, quarter_arc_bulge(tangent, normal));
+            result.add(end.x, end.y, T::zero());
+        }
+        _ => {}
+    }
+    result
+}
+
+/// Add lead-in and lead-out moves to a closed polyline toolpath.
+///
+/// The entry point is chosen according to [LeadOptions::entry] and the polyline is rotated to
+/// start at it using [PlineSource::rotate_start]. Lead moves are placed on [LeadOptions::side]
+/// relative to the polyline (using its orientation to determine which side is inside), tangent
+/// line and arc leads join the path smoothly. After one full loop the path continues for
+/// [LeadOptions::overlap] distance before the lead-out. Each lead move is checked to lie
+/// entirely on [LeadOptions::side] of the polyline (boundary included), and if
+/// [LeadOptions::allowed_region] is given then also to lie entirely within it using
+/// [PointLocator::contains_pline].
+///
+/// Returns `None` if the polyline is open or has less than 2 vertexes.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let square: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let options = LeadOptions {
+///     entry: LeadEntry::ClosestTo(Vector2::new(5.0, -3.0)),
+///     lead_in: LeadShape::Arc { radius: 2.0 },
+///     lead_out: LeadShape::PerpendicularLine { length: 2.0 },
+///     side: LeadSide::Outside,
+///     overlap: 1.0,
+///     ..Default::default()
+/// };
+/// let result = add_leads(&square, &options).unwrap();
+/// assert!(result.rotated[0].pos().fuzzy_eq(Vector2::new(5.0, 0.0)));
+/// // arc lead-in starts below the square
+/// assert!(result.lead_in[0].pos().fuzzy_eq(Vector2::new(3.0, -2.0)));
+/// // perpendicular line lead-out after overlapping by 1
+/// assert!(result.lead_out[1].pos().fuzzy_eq(Vector2::new(6.0, -2.0)));
+/// let expected_length = std::f64::consts::PI + 40.0 + 1.0 + 2.0;
+/// assert_fuzzy_eq!(result.toolpath.path_length(), expected_length);
+/// ```
+pub fn add_leads<P, T>(pline: &P, options: &LeadOptions<T>) -> Option<LeadResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    if !pline.is_closed() || pline.vertex_count() < 2 {
+        return None;
+    }
+
+    let (start_index, entry_point) = match options.entry {
+        LeadEntry::ClosestTo(point) => {
+            let cp = pline.closest_point(point, eps)?;
+            (cp.seg_start_index, cp.seg_point)
+        }
+        LeadEntry::AtPathLength(length) => {
+            let total = pline.path_length();
+            let length = if total > T::zero() {
+                length - (length / total).floor() * total
+            } else {
+                T::zero()
+            };
+            match pline.find_point_at_path_length(length) {
+                Ok(x) => x,
+                Err(_) => (0, pline.at(0).pos()),
+            }
+        }
+    };
+
+    let rotated = Polyline::create_from(&pline.rotate_start(start_index, entry_point, eps)?);
+    let vc = rotated.vertex_count();
+    if vc < 2 {
+        return None;
+    }
+
+    let inside_is_left = rotated.orientation() == PlineOrientation::CounterClockwise;
+    let entry_point = rotated.at(0).pos();
+    let entry_tangent = seg_tangent_vector(rotated.at(0), rotated.at(1), entry_point).normalize();
+
+    // overlap path and exit point
+    let total_length = rotated.path_length();
+    let (overlap_path, exit_point, exit_tangent) = if options.overlap > eps {
+        let (seg_index, point) = match rotated.find_point_at_path_length(options.overlap) {
+            Ok(x) if options.overlap < total_length => x,
+            _ => (vc - 1, entry_point),
+        };
+        let v1 = rotated.at(seg_index);
+        let v2 = rotated.at(rotated.next_wrapping_index(seg_index));
+        let path = if seg_index == vc - 1 && point.fuzzy_eq_eps(entry_point, eps) {
+            // full loop overlap
+            Some(PlineViewData::from_entire_pline(&rotated))
+        } else {
+            PlineViewData::from_slice_points(&rotated, entry_point, 0, point, seg_index, eps)
+        };
+        (path, point, seg_tangent_vector(v1, v2, point).normalize())
+    } else {
+        let last = rotated.at(vc - 1);
+        let tangent = seg_tangent_vector(last, rotated.at(0), entry_point).normalize();
+        (None, entry_point, tangent)
+    };
+
+    let lead_in = create_lead_in(
+        options.lead_in,
+        entry_point,
+        entry_tangent,
+        side_normal(entry_tangent, options.side, inside_is_left),
+        eps,
+    );
+
+    let lead_out = create_lead_out(
+        options.lead_out,
+        exit_point,
+        exit_tangent,
+        side_normal(exit_tangent, options.side, inside_is_left),
+        eps,
+    );
+
+    let mut toolpath = Polyline::with_capacity(vc + 6, false);
+    toolpath.extend_vertexes(lead_in.iter_vertexes());
+    toolpath.extend_remove_repeat(
+        &PlineViewData::from_entire_pline(&rotated).view(&rotated),
+        eps,
+    );
+    if let Some(path) = overlap_path {
+        toolpath.extend_remove_repeat(&path.view(&rotated), eps);
+    }
+    toolpath.extend_remove_repeat(&lead_out, eps);
+    if let Some(last) = toolpath.last() {
+        toolpath.set_last(PlineVertex::from_vector2(last.pos(), T::zero()));
+    }
+
+    let side_locator = PointLocator::new(std::iter::once(&rotated), eps);
+    let is_valid = |lead: &Polyline<T>| {
+        let on_side = match options.side {
+            LeadSide::Inside => side_locator.contains_pline(lead),
+            LeadSide::Outside => side_locator.excludes_pline(lead),
+        };
+        on_side
+            && options
+                .allowed_region
+                .map_or(true, |region| region.contains_pline(lead))
+    };
+    let lead_in_valid = is_valid(&lead_in);
+    let lead_out_valid = is_valid(&lead_out);
+
+    Some(LeadResult {
+        rotated,
+        lead_in,
+        lead_out,
+        toolpath,
+        lead_in_valid,
+        lead_out_valid,
+    })
+}
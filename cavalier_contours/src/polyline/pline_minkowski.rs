@@ -0,0 +1,776 @@
+use super::{
+    pline_arrangement::{snap_positions, split_at_intersects},
+    seg_arc_radius_and_center, seg_bounding_box, seg_length, seg_midpoint, seg_tangent_vector,
+    seg_winding_number, PlineCreation, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+};
+use crate::core::{
+    math::{angle_from_bulge, bulge_from_angle, delta_angle, normalize_radians, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+
+/// Options for [minkowski_sum] and [minkowski_difference].
+#[derive(Debug, Clone)]
+pub struct MinkowskiOptions<T = f64> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// the result boundary together.
+    pub slice_join_eps: T,
+}
+
+impl<T> MinkowskiOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for MinkowskiOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of [minkowski_sum] and [minkowski_difference].
+#[derive(Debug, Clone)]
+pub struct MinkowskiResult<T = f64> {
+    /// Counter clockwise polylines bounding the result.
+    pub pos_plines: Vec<Polyline<T>>,
+    /// Clockwise polylines bounding holes in the result.
+    pub neg_plines: Vec<Polyline<T>>,
+}
+
+impl<T> MinkowskiResult<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_plines: Vec::new(),
+            neg_plines: Vec::new(),
+        }
+    }
+}
+
+impl<T> Default for MinkowskiResult<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Right normal of direction `theta` (direction rotated -90 degrees).
+#[inline]
+fn right_of<T>(theta: T) -> Vector2<T>
+where
+    T: Real,
+{
+    Vector2::new(theta.sin(), -theta.cos())
+}
+
+/// Segment of a polyline with its tangent directions for convolution.
+#[derive(Debug, Clone, Copy)]
+struct ConvSeg<T> {
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    /// Tangent direction at the start of the segment.
+    start_dir: T,
+    /// Change in tangent direction along the segment (arc sweep angle, 0 for lines).
+    sweep: T,
+    /// Direction the tangent turns at the start vertex (from the end of the previous segment).
+    turn: T,
+    /// Arc center and radius (unused for lines).
+    center: Vector2<T>,
+    radius: T,
+}
+
+impl<T> ConvSeg<T>
+where
+    T: Real,
+{
+    fn end_dir(&self) -> T {
+        self.start_dir + self.sweep
+    }
+
+    fn is_arc(&self) -> bool {
+        !self.v1.bulge_is_zero()
+    }
+
+    /// Counter clockwise angle interval (start, length) of the tangent directions along the
+    /// segment.
+    fn dir_interval(&self) -> (T, T) {
+        if self.sweep > T::zero() {
+            (self.start_dir, self.sweep)
+        } else {
+            (self.start_dir + self.sweep, -self.sweep)
+        }
+    }
+
+    /// Counter clockwise angle interval (start, length) of the tangent directions turned through
+    /// at the start vertex.
+    fn turn_interval(&self) -> (T, T) {
+        let in_dir = self.start_dir - self.turn;
+        if self.turn > T::zero() {
+            (in_dir, self.turn)
+        } else {
+            (self.start_dir, -self.turn)
+        }
+    }
+
+    /// Signed radius such that the point on the arc with tangent direction `theta` is
+    /// `center + signed_radius * right_of(theta)`.
+    fn signed_radius(&self) -> T {
+        if self.sweep > T::zero() {
+            self.radius
+        } else {
+            -self.radius
+        }
+    }
+}
+
+fn conv_seg<T>(v1: PlineVertex<T>, v2: PlineVertex<T>) -> ConvSeg<T>
+where
+    T: Real,
+{
+    let chord = v2.pos() - v1.pos();
+    let chord_dir = chord.y.atan2(chord.x);
+    let sweep = angle_from_bulge(v1.bulge);
+    let (radius, center) = if v1.bulge_is_zero() {
+        (T::zero(), Vector2::zero())
+    } else {
+        seg_arc_radius_and_center(v1, v2)
+    };
+    ConvSeg {
+        v1,
+        v2,
+        start_dir: chord_dir - sweep / T::two(),
+        sweep,
+        turn: T::zero(),
+        center,
+        radius,
+    }
+}
+
+/// Set the turn at the start of every segment in a closed cycle of segments.
+fn set_turns<T>(segs: &mut [ConvSeg<T>])
+where
+    T: Real,
+{
+    let n = segs.len();
+    for i in 0..n {
+        let prev_end = segs[(i + n - 1) % n].end_dir();
+        segs[i].turn = delta_angle(prev_end, segs[i].start_dir);
+    }
+}
+
+fn conv_segs<P, T>(pline: &P, pos_equal_eps: T) -> Vec<ConvSeg<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut segs = pline
+        .iter_segments()
+        .filter(|(v1, v2)| !v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps))
+        .map(|(v1, v2)| conv_seg(v1, v2))
+        .collect::<Vec<_>>();
+    set_turns(&mut segs);
+    segs
+}
+
+/// Segments of a path traversed forward and then backward, forming a closed cycle bounding a
+/// region with no area (the limit of a thin region around the path).
+fn path_conv_segs<P, T>(path: &P, pos_equal_eps: T) -> Vec<ConvSeg<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let forward = path
+        .iter_segments()
+        .filter(|(v1, v2)| !v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps))
+        .collect::<Vec<_>>();
+    let m = forward.len();
+    let mut segs = forward
+        .iter()
+        .map(|&(v1, v2)| conv_seg(v1, v2))
+        .chain(
+            forward
+                .iter()
+                .rev()
+                .map(|&(v1, v2)| conv_seg(v2.with_bulge(-v1.bulge), v1.with_bulge(T::zero()))),
+        )
+        .collect::<Vec<_>>();
+    if segs.is_empty() {
+        return segs;
+    }
+
+    set_turns(&mut segs);
+    // turning back at the ends of the path is a half turn counter clockwise (the thin region
+    // around the path is counter clockwise)
+    segs[0].turn = T::pi();
+    segs[m].turn = T::pi();
+    segs
+}
+
+/// Intersect two counter clockwise angle intervals given as (start, length), returns up to two
+/// intervals.
+fn intersect_intervals<T>(a: (T, T), b: (T, T), eps: T) -> Vec<(T, T)>
+where
+    T: Real,
+{
+    let d = normalize_radians(b.0 - a.0);
+    let mut result = Vec::new();
+    for &offset in [d, d - T::tau()].iter() {
+        let lo = num_traits::real::Real::max(offset, T::zero());
+        let hi = num_traits::real::Real::min(offset + b.1, a.1);
+        if hi - lo > eps {
+            result.push((a.0 + lo, hi - lo));
+        }
+    }
+    result
+}
+
+/// Returns true if direction `theta` is within the angle interval (start, length), boundary
+/// inclusion is controlled by `include_start` and `include_end`.
+fn in_interval<T>(
+    theta: T,
+    interval: (T, T),
+    include_start: bool,
+    include_end: bool,
+    eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let mut d = normalize_radians(theta - interval.0);
+    if d >= T::tau() - eps {
+        d = d - T::tau();
+    }
+    let after_start = if include_start { d > -eps } else { d > eps };
+    let before_end = if include_end {
+        d < interval.1 + eps
+    } else {
+        d < interval.1 - eps
+    };
+    after_start && before_end
+}
+
+/// Arc piece `center + signed_radius * right_of(theta)` for `theta` going from `from` to `to`.
+fn arc_piece<T>(
+    center: Vector2<T>,
+    signed_radius: T,
+    from: T,
+    to: T,
+) -> (PlineVertex<T>, PlineVertex<T>)
+where
+    T: Real,
+{
+    let start = center + right_of(from).scale(signed_radius);
+    let end = center + right_of(to).scale(signed_radius);
+    (
+        PlineVertex::from_vector2(start, bulge_from_angle(to - from)),
+        PlineVertex::from_vector2(end, T::zero()),
+    )
+}
+
+#[inline]
+fn reversed<T>(piece: (PlineVertex<T>, PlineVertex<T>)) -> (PlineVertex<T>, PlineVertex<T>)
+where
+    T: Real,
+{
+    (
+        piece.1.with_bulge(-piece.0.bulge),
+        piece.0.with_bulge(T::zero()),
+    )
+}
+
+/// Add the convolution pieces of the segments of one polyline with the vertexes of the other.
+fn add_seg_vertex_pieces<T>(
+    segs: &[ConvSeg<T>],
+    vertexes: &[ConvSeg<T>],
+    include_start: bool,
+    include_end: bool,
+    eps: T,
+    pieces: &mut Vec<(PlineVertex<T>, PlineVertex<T>)>,
+) where
+    T: Real,
+{
+    for vertex in vertexes.iter() {
+        if vertex.turn.abs() <= eps {
+            continue;
+        }
+        let pos = vertex.v1.pos();
+        let turn_interval = vertex.turn_interval();
+        // turning at a reflex vertex traverses the pieces backwards
+        let reflex = vertex.turn < T::zero();
+
+        for seg in segs.iter() {
+            if seg.is_arc() {
+                for (lo, len) in intersect_intervals(seg.dir_interval(), turn_interval, eps) {
+                    let (from, to) = if seg.sweep > T::zero() {
+                        (lo, lo + len)
+                    } else {
+                        (lo + len, lo)
+                    };
+                    let piece = arc_piece(seg.center + pos, seg.signed_radius(), from, to);
+                    pieces.push(if reflex { reversed(piece) } else { piece });
+                }
+            } else if in_interval(
+                seg.start_dir,
+                turn_interval,
+                include_start,
+                include_end,
+                eps,
+            ) {
+                let piece = (
+                    PlineVertex::from_vector2(seg.v1.pos() + pos, T::zero()),
+                    PlineVertex::from_vector2(seg.v2.pos() + pos, T::zero()),
+                );
+                pieces.push(if reflex { reversed(piece) } else { piece });
+            }
+        }
+    }
+}
+
+/// Add the convolution pieces of the line segments of one polyline with the points of matching
+/// tangent direction on the arc segments of the other.
+fn add_line_arc_pieces<T>(
+    lines: &[ConvSeg<T>],
+    arcs: &[ConvSeg<T>],
+    include_start: bool,
+    include_end: bool,
+    eps: T,
+    pieces: &mut Vec<(PlineVertex<T>, PlineVertex<T>)>,
+) where
+    T: Real,
+{
+    for arc in arcs.iter().filter(|s| s.is_arc()) {
+        // arc going clockwise traverses the pieces backwards (same as a reflex vertex)
+        let concave = arc.sweep < T::zero();
+        for line in lines.iter().filter(|s| !s.is_arc()) {
+            if !in_interval(
+                line.start_dir,
+                arc.dir_interval(),
+                include_start,
+                include_end,
+                eps,
+            ) {
+                continue;
+            }
+            let pos = arc.center + right_of(line.start_dir).scale(arc.signed_radius());
+            let piece = (
+                PlineVertex::from_vector2(line.v1.pos() + pos, T::zero()),
+                PlineVertex::from_vector2(line.v2.pos() + pos, T::zero()),
+            );
+            pieces.push(if concave { reversed(piece) } else { piece });
+        }
+    }
+}
+
+/// Convolution of two counter clockwise closed polylines as a set of directed segments forming
+/// closed cycles.
+fn convolution<T>(
+    a: &[ConvSeg<T>],
+    b: &[ConvSeg<T>],
+    eps: T,
+) -> Vec<(PlineVertex<T>, PlineVertex<T>)>
+where
+    T: Real,
+{
+    let mut pieces = Vec::new();
+    // half open direction intervals at vertexes and arcs (start included for `a` and end
+    // included for `b`) so parallel segments are not repeated or skipped
+    add_seg_vertex_pieces(a, b, false, true, eps, &mut pieces);
+    add_seg_vertex_pieces(b, a, true, false, eps, &mut pieces);
+    add_line_arc_pieces(a, b, false, true, eps, &mut pieces);
+    add_line_arc_pieces(b, a, true, false, eps, &mut pieces);
+
+    // arc with arc where tangent directions match
+    for sa in a.iter().filter(|s| s.is_arc()) {
+        for sb in b.iter().filter(|s| s.is_arc()) {
+            let center = sa.center + sb.center;
+            let signed_radius = sa.signed_radius() + sb.signed_radius();
+            if signed_radius.abs() <= eps {
+                continue;
+            }
+            let same_sense = (sa.sweep > T::zero()) == (sb.sweep > T::zero());
+            for (lo, len) in intersect_intervals(sa.dir_interval(), sb.dir_interval(), eps) {
+                // traversed with direction increasing only if both arcs are counter clockwise
+                let (from, to) = if same_sense && sa.sweep > T::zero() {
+                    (lo, lo + len)
+                } else {
+                    (lo + len, lo)
+                };
+                pieces.push(arc_piece(center, signed_radius, from, to));
+            }
+        }
+    }
+
+    pieces
+}
+
+/// Find the boundary of the region where the winding number of the directed `pieces` is
+/// positive, returned as closed polylines.
+fn positive_winding_boundary<T>(
+    pieces: &[(PlineVertex<T>, PlineVertex<T>)],
+    options: &MinkowskiOptions<T>,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let join_eps = options.slice_join_eps;
+    let mut pieces = pieces
+        .iter()
+        .copied()
+        .filter(|(v1, v2)| !v1.pos().fuzzy_eq_eps(v2.pos(), eps))
+        .collect::<Vec<_>>();
+    if pieces.is_empty() {
+        return Vec::new();
+    }
+
+    // snap coincident end points (computed separately for each piece) to the same position so
+    // winding numbers are not thrown off by tiny gaps or overlaps between pieces
+    let end_points = pieces
+        .iter()
+        .flat_map(|(v1, v2)| std::iter::once(v1.pos()).chain(std::iter::once(v2.pos())))
+        .collect::<Vec<_>>();
+    let snapped = snap_positions(&end_points, eps);
+    for (i, (v1, v2)) in pieces.iter_mut().enumerate() {
+        *v1 = PlineVertex::from_vector2(end_points[snapped[2 * i]], v1.bulge);
+        *v2 = PlineVertex::from_vector2(end_points[snapped[2 * i + 1]], v2.bulge);
+    }
+    pieces.retain(|(v1, v2)| !v1.pos().fuzzy_eq_eps(v2.pos(), eps));
+
+    let index: StaticAABB2DIndex<T> = {
+        let mut builder = StaticAABB2DIndexBuilder::new(pieces.len());
+        for &(v1, v2) in pieces.iter() {
+            let bb = seg_bounding_box(v1, v2);
+            builder.add(bb.min_x, bb.min_y, bb.max_x, bb.max_y);
+        }
+        builder.build().unwrap()
+    };
+
+    let winding_number = |point: Vector2<T>, query_stack: &mut Vec<usize>| {
+        let mut winding = 0;
+        index.visit_query_with_stack(
+            point.x,
+            point.y,
+            index.max_x(),
+            point.y,
+            &mut |i: usize| {
+                let (v1, v2) = pieces[i];
+                winding += seg_winding_number(v1, v2, point);
+            },
+            query_stack,
+        );
+        winding
+    };
+
+    // split pieces and keep the sub pieces with positive winding on the left and zero on the
+    // right
+    let mut kept: Vec<(PlineVertex<T>, PlineVertex<T>)> = Vec::new();
+    let mut query_stack = Vec::new();
+    for (s1, s2, _) in split_at_intersects(&pieces, eps) {
+        let length = seg_length(s1, s2);
+        if length <= eps {
+            continue;
+        }
+        let mid = seg_midpoint(s1, s2);
+        let tangent = seg_tangent_vector(s1, s2, mid).normalize();
+        let sample_dist = num_traits::real::Real::min(join_eps / T::two(), length / T::four());
+        let right = Vector2::new(tangent.y, -tangent.x).scale(sample_dist);
+        let right_winding = winding_number(mid + right, &mut query_stack);
+        if right_winding > 0 {
+            continue;
+        }
+        let left_winding = winding_number(mid - right, &mut query_stack);
+        if left_winding <= 0 {
+            continue;
+        }
+        let is_duplicate = kept
+            .iter()
+            .any(|(k1, k2)| k1.fuzzy_eq_eps(s1, eps) && k2.pos().fuzzy_eq_eps(s2.pos(), eps));
+        if !is_duplicate {
+            kept.push((s1, s2));
+        }
+    }
+
+    stitch_pieces(&kept, join_eps, eps)
+}
+
+/// Stitch directed pieces end to start into closed polylines.
+fn stitch_pieces<T>(
+    pieces: &[(PlineVertex<T>, PlineVertex<T>)],
+    join_eps: T,
+    pos_equal_eps: T,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let mut result = Vec::new();
+    if pieces.is_empty() {
+        return result;
+    }
+
+    let index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(pieces.len());
+        for (v1, _) in pieces.iter() {
+            builder.add(
+                v1.x - join_eps,
+                v1.y - join_eps,
+                v1.x + join_eps,
+                v1.y + join_eps,
+            );
+        }
+        builder.build().unwrap()
+    };
+
+    let mut visited = vec![false; pieces.len()];
+    let mut query_stack = Vec::new();
+    for i in 0..pieces.len() {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+
+        let start = pieces[i].0.pos();
+        let mut pline = Polyline::new_closed();
+        let mut current = i;
+        let closed = loop {
+            let (v1, v2) = pieces[current];
+            pline.add_vertex(v1);
+            let end = v2.pos();
+            if end.fuzzy_eq_eps(start, join_eps) {
+                break true;
+            }
+
+            // choose the piece turning furthest clockwise to keep loops separate where they touch
+            let end_dir = seg_tangent_vector(v1, v2, end);
+            let end_angle = end_dir.y.atan2(end_dir.x);
+            let mut next = None;
+            let mut next_turn = T::zero();
+            index.visit_query_with_stack(
+                end.x - join_eps,
+                end.y - join_eps,
+                end.x + join_eps,
+                end.y + join_eps,
+                &mut |j: usize| {
+                    if visited[j] {
+                        return;
+                    }
+                    let (u1, u2) = pieces[j];
+                    let start_dir = seg_tangent_vector(u1, u2, u1.pos());
+                    let turn = delta_angle(end_angle, start_dir.y.atan2(start_dir.x));
+                    if next.is_none() || turn < next_turn {
+                        next = Some(j);
+                        next_turn = turn;
+                    }
+                },
+                &mut query_stack,
+            );
+
+            match next {
+                Some(j) => {
+                    visited[j] = true;
+                    current = j;
+                }
+                None => break false,
+            }
+        };
+
+        if !closed {
+            continue;
+        }
+
+        if let Some(cleaned) = pline.remove_repeat_pos(pos_equal_eps) {
+            pline = cleaned;
+        }
+        if pline.vertex_count() >= 2 && pline.area().abs() > pos_equal_eps {
+            result.push(pline);
+        }
+    }
+
+    result
+}
+
+fn counter_clockwise_copy<P, T>(pline: &P) -> Polyline<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut result = Polyline::create_from(pline);
+    if result.area() < T::zero() {
+        result.invert_direction_mut();
+    }
+    result
+}
+
+/// Compute the Minkowski sum of two closed polylines.
+///
+/// The Minkowski sum is the set of all points `a + b` where `a` is a point in the region of
+/// polyline `a` and `b` is a point in the region of polyline `b`. Both polylines may be non-convex
+/// and contain arcs (input orientation does not matter but the polylines must not self
+/// intersect), e.g. [parallel_offset](PlineSource::parallel_offset) outward is the Minkowski sum
+/// with a circle.
+///
+/// The sum is computed from the convolution of the two polylines (segments of one polyline
+/// translated by vertexes of the other where tangent directions match, plus arcs from pairs of
+/// arcs with matching tangent directions), the convolution is split at its self intersects and
+/// the result is the boundary of the region where its winding number is positive.
+///
+/// Returns empty result if either polyline is open or has no area.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let square: Polyline = pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0), (0.0, 2.0, 0.0)];
+/// let circle: Polyline = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+/// let result = minkowski_sum(&square, &circle, &MinkowskiOptions::default());
+/// assert_eq!(result.pos_plines.len(), 1);
+/// assert!(result.neg_plines.is_empty());
+/// // rounded square: square + 4 edge strips + circle
+/// assert_fuzzy_eq!(result.pos_plines[0].area(), 4.0 + 8.0 + std::f64::consts::PI);
+/// ```
+pub fn minkowski_sum<P, Q, T>(a: &P, b: &Q, options: &MinkowskiOptions<T>) -> MinkowskiResult<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    Q: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let is_region =
+        |p: &Polyline<T>| p.is_closed() && p.vertex_count() >= 2 && p.area().abs() > eps;
+    if !a.is_closed() || !b.is_closed() {
+        return MinkowskiResult::new();
+    }
+    let a = counter_clockwise_copy(a);
+    let b = counter_clockwise_copy(b);
+    if !is_region(&a) || !is_region(&b) {
+        return MinkowskiResult::new();
+    }
+
+    minkowski_sum_loops(&[a], &[b], options)
+}
+
+/// Minkowski sum of two regions each given by closed boundary loops oriented with outer
+/// boundaries counter clockwise and holes clockwise (e.g. from
+/// [PlineContainmentTree::fix_orientations](super::PlineContainmentTree::fix_orientations)).
+///
+/// Every loop of `a` is convolved with every loop of `b`, the convolution winding numbers add up
+/// so holes in either region are accounted for (e.g. a hole of `a` remains where all of `b` fits
+/// inside it).
+pub(crate) fn minkowski_sum_loops<T>(
+    a: &[Polyline<T>],
+    b: &[Polyline<T>],
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let a_cycles = a.iter().map(|p| conv_segs(p, eps)).collect::<Vec<_>>();
+    let b_cycles = b.iter().map(|p| conv_segs(p, eps)).collect::<Vec<_>>();
+    sum_of_cycles(&a_cycles, &b_cycles, options)
+}
+
+/// Minkowski sum of a path (open or closed polyline treated as a curve) and a region given by
+/// oriented closed boundary loops (see [minkowski_sum_loops]), this is the area swept by the
+/// region translating along the path.
+pub(crate) fn minkowski_sum_path<P, T>(
+    path: &P,
+    loops: &[Polyline<T>],
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let path_cycle = path_conv_segs(path, eps);
+    if path_cycle.is_empty() {
+        return MinkowskiResult::new();
+    }
+    let cycles = loops.iter().map(|p| conv_segs(p, eps)).collect::<Vec<_>>();
+    sum_of_cycles(std::slice::from_ref(&path_cycle), &cycles, options)
+}
+
+fn sum_of_cycles<T>(
+    a_cycles: &[Vec<ConvSeg<T>>],
+    b_cycles: &[Vec<ConvSeg<T>>],
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    T: Real,
+{
+    let angle_eps = T::fuzzy_epsilon();
+    let mut pieces = Vec::new();
+    for a_segs in a_cycles.iter() {
+        for b_segs in b_cycles.iter() {
+            pieces.extend(convolution(a_segs, b_segs, angle_eps));
+        }
+    }
+
+    let mut result = MinkowskiResult::new();
+    for pline in positive_winding_boundary(&pieces, options) {
+        if pline.area() > T::zero() {
+            result.pos_plines.push(pline);
+        } else {
+            result.neg_plines.push(pline);
+        }
+    }
+
+    result
+}
+
+/// Compute the Minkowski difference of two closed polylines, defined as the Minkowski sum of `a`
+/// with `b` reflected through the origin (`a ⊕ -b`).
+///
+/// The difference is the set of translations `t` for which `b` translated by `t` overlaps `a`,
+/// this is the configuration space obstacle of `a` for a part `b` positioned by its origin. See
+/// [minkowski_sum] for more information.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let obstacle: Polyline = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+/// // part with its origin at its lower left corner
+/// let part: Polyline = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)];
+/// let result = minkowski_difference(&obstacle, &part, &MinkowskiOptions::default());
+/// assert_eq!(result.pos_plines.len(), 1);
+/// assert_fuzzy_eq!(result.pos_plines[0].area(), 25.0);
+/// assert_eq!(result.pos_plines[0].winding_number(Vector2::new(-0.5, -0.5)), 1);
+/// assert_eq!(result.pos_plines[0].winding_number(Vector2::new(4.5, 4.5)), 0);
+/// ```
+pub fn minkowski_difference<P, Q, T>(
+    a: &P,
+    b: &Q,
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    Q: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    // reflecting through the origin is a rotation by 180 degrees so orientation is kept
+    let mut reflected = Polyline::create_from(b);
+    for i in 0..reflected.vertex_count() {
+        let v = reflected.at(i);
+        reflected.set_vertex(i, PlineVertex::new(-v.x, -v.y, v.bulge));
+    }
+
+    minkowski_sum(a, &reflected, options)
+}
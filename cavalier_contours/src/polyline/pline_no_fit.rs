@@ -0,0 +1,232 @@
+use super::{
+    pline_minkowski::minkowski_sum_loops, MinkowskiOptions, MinkowskiResult, PlineContainmentTree,
+    PlineCreation, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+};
+use crate::core::traits::Real;
+
+/// Copy the closed polylines with area into region loops with outer boundaries counter clockwise
+/// and holes clockwise (determined by nesting).
+pub(crate) fn region_loops<'a, P, I, T>(plines: I, pos_equal_eps: T) -> Vec<Polyline<T>>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let mut loops = plines
+        .into_iter()
+        .filter(|p| p.is_closed() && p.vertex_count() >= 2)
+        .map(|p| Polyline::create_from(p))
+        .filter(|p: &Polyline<T>| p.area().abs() > pos_equal_eps)
+        .collect::<Vec<_>>();
+
+    let tree = PlineContainmentTree::build(&loops, pos_equal_eps);
+    tree.fix_orientations(&mut loops);
+    loops
+}
+
+/// Reflect the loops through the origin (rotation by 180 degrees so orientations are kept).
+fn reflected_loops<T>(loops: &mut [Polyline<T>])
+where
+    T: Real,
+{
+    for pline in loops.iter_mut() {
+        for i in 0..pline.vertex_count() {
+            let v = pline.at(i);
+            pline.set_vertex(i, PlineVertex::new(-v.x, -v.y, v.bulge));
+        }
+    }
+}
+
+/// Compute the no-fit polygon of an `orbiting` part around a `stationary` part.
+///
+/// The no-fit polygon is the set of translations of the `orbiting` part (relative to its own
+/// coordinate origin) for which it overlaps the `stationary` part, so a translation `t` is a valid
+/// placement touching or clear of the stationary part if the winding number of `t` with respect
+/// to the result is 0 (points on the boundary are touching placements).
+///
+/// Both parts are given as sets of closed polylines which may contain arcs, nesting is determined
+/// with [PlineContainmentTree] so input orientations do not matter: polylines at even depth are
+/// outer boundaries and polylines at odd depth are holes. Holes in either part are accounted for,
+/// e.g. placements of the orbiting part inside a hole of the stationary part appear as holes in
+/// the result. This is the Minkowski difference of the two regions (see
+/// [minkowski_difference](super::minkowski_difference)), with every boundary loop of one part
+/// convolved with every boundary loop of the other.
+///
+/// Returns empty result if either part has no closed polylines with area.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let stationary: Polyline = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+/// let orbiting: Polyline = pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (0.0, 1.0, 0.0)];
+/// let nfp = no_fit_polygon(
+///     std::iter::once(&stationary),
+///     std::iter::once(&orbiting),
+///     &MinkowskiOptions::default(),
+/// );
+/// assert_eq!(nfp.pos_plines.len(), 1);
+/// assert_fuzzy_eq!(nfp.pos_plines[0].area(), 6.0 * 5.0);
+/// // overlapping placement
+/// assert_eq!(nfp.pos_plines[0].winding_number(Vector2::new(1.0, 1.0)), 1);
+/// // placement to the right of the stationary part
+/// assert_eq!(nfp.pos_plines[0].winding_number(Vector2::new(4.5, 1.0)), 0);
+/// ```
+pub fn no_fit_polygon<'a, 'b, P, I, Q, J, T>(
+    stationary: I,
+    orbiting: J,
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    Q: PlineSource<Num = T> + 'b + ?Sized,
+    J: IntoIterator<Item = &'b Q>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let stationary = region_loops(stationary, eps);
+    let mut orbiting = region_loops(orbiting, eps);
+    if stationary.is_empty() || orbiting.is_empty() {
+        return MinkowskiResult::new();
+    }
+
+    reflected_loops(&mut orbiting);
+    minkowski_sum_loops(&stationary, &orbiting, options)
+}
+
+/// Compute the inner-fit polygon of a `part` inside a `container` (e.g. a sheet boundary).
+///
+/// The inner-fit polygon is the set of translations of the `part` (relative to its own coordinate
+/// origin) for which it lies entirely inside the `container`, so a translation `t` is a valid
+/// placement if the winding number of `t` with respect to the result is not 0 or `t` lies on the
+/// boundary (touching the container boundary).
+///
+/// Both are given as sets of closed polylines which may contain arcs, nesting is determined with
+/// [PlineContainmentTree] so input orientations do not matter. Holes in the container (e.g.
+/// defects or already cut areas) are holes in the result, holes in the part may contain islands
+/// of the container.
+///
+/// The result is computed as the complement of the no-fit polygon of the part around the
+/// complement of the container (container inverted inside of a bounding frame).
+///
+/// Returns empty result if either has no closed polylines with area or the part does not fit.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let sheet: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 5.0, 0.0), (0.0, 5.0, 0.0)];
+/// let part: Polyline = pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (0.0, 1.0, 0.0)];
+/// let ifp = inner_fit_polygon(
+///     std::iter::once(&sheet),
+///     std::iter::once(&part),
+///     &MinkowskiOptions::default(),
+/// );
+/// assert_eq!(ifp.pos_plines.len(), 1);
+/// assert_fuzzy_eq!(ifp.pos_plines[0].area(), 8.0 * 4.0);
+/// assert_eq!(ifp.pos_plines[0].winding_number(Vector2::new(7.5, 3.5)), 1);
+/// assert_eq!(ifp.pos_plines[0].winding_number(Vector2::new(8.5, 3.5)), 0);
+/// ```
+pub fn inner_fit_polygon<'a, 'b, P, I, Q, J, T>(
+    container: I,
+    part: J,
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    Q: PlineSource<Num = T> + 'b + ?Sized,
+    J: IntoIterator<Item = &'b Q>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let container = region_loops(container, eps);
+    let mut part = region_loops(part, eps);
+    if container.is_empty() || part.is_empty() {
+        return MinkowskiResult::new();
+    }
+
+    let extents = |loops: &[Polyline<T>]| {
+        loops
+            .iter()
+            .filter_map(|p| p.extents())
+            .reduce(|a, b| {
+                let mut a = a;
+                a.min_x = num_traits::real::Real::min(a.min_x, b.min_x);
+                a.min_y = num_traits::real::Real::min(a.min_y, b.min_y);
+                a.max_x = num_traits::real::Real::max(a.max_x, b.max_x);
+                a.max_y = num_traits::real::Real::max(a.max_y, b.max_y);
+                a
+            })
+            .unwrap()
+    };
+
+    // complement of the container bounded by a frame around the container, the frame only needs
+    // to be clear of the container but a margin relative to the part size keeps the frame's sum
+    // with the part well separated from the inner-fit regions
+    let container_extents = extents(&container);
+    let part_extents = extents(&part);
+    let margin = (part_extents.max_x - part_extents.min_x)
+        + (part_extents.max_y - part_extents.min_y)
+        + T::one();
+    let mut frame = Polyline::with_capacity(4, true);
+    frame.add(
+        container_extents.min_x - margin,
+        container_extents.min_y - margin,
+        T::zero(),
+    );
+    frame.add(
+        container_extents.max_x + margin,
+        container_extents.min_y - margin,
+        T::zero(),
+    );
+    frame.add(
+        container_extents.max_x + margin,
+        container_extents.max_y + margin,
+        T::zero(),
+    );
+    frame.add(
+        container_extents.min_x - margin,
+        container_extents.max_y + margin,
+        T::zero(),
+    );
+
+    let mut complement = Vec::with_capacity(container.len() + 1);
+    complement.push(frame);
+    for mut pline in container {
+        pline.invert_direction_mut();
+        complement.push(pline);
+    }
+
+    reflected_loops(&mut part);
+    let obstacle = minkowski_sum_loops(&complement, &part, options);
+
+    // the largest positive polyline is the outer boundary of the frame sum, the holes of the sum
+    // are the inner-fit regions and any other positive polylines are holes inside of them
+    let frame_index = obstacle
+        .pos_plines
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.area().partial_cmp(&b.area()).unwrap())
+        .map(|(i, _)| i);
+
+    let mut result = MinkowskiResult::new();
+    for mut pline in obstacle.neg_plines {
+        pline.invert_direction_mut();
+        result.pos_plines.push(pline);
+    }
+    for (i, mut pline) in obstacle.pos_plines.into_iter().enumerate() {
+        if Some(i) == frame_index {
+            continue;
+        }
+        pline.invert_direction_mut();
+        result.neg_plines.push(pline);
+    }
+
+    result
+}
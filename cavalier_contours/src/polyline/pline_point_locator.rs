@@ -0,0 +1,373 @@
+use super::{
+    pline_seg_intr, seg_bounding_box, seg_closest_point, seg_length, seg_point_at_parametric,
+    seg_split_at_point, seg_winding_number, PlineSegIntr, PlineSource, PlineVertex,
+};
+use crate::core::{
+    math::{dist_squared, Vector2},
+    traits::Real,
+};
+use static_aabb2d_index as aabb_index;
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+
+/// Location of a point relative to a region, see [PointLocator::locate].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointLocation {
+    /// Point is inside the region (winding number is not zero).
+    Inside,
+    /// Point is outside the region (winding number is zero).
+    Outside,
+    /// Point is on the boundary of the region (within the boundary tolerance).
+    Boundary,
+}
+
+/// Structure for fast repeated point in region queries against a set of closed polylines.
+///
+/// The segments of all the polylines are copied and a spatial index of their bounding boxes is
+/// built once, each query then only processes the segments whose bounding box intersects the
+/// horizontal ray cast from the query point (for winding number) or lies within the boundary
+/// tolerance of the query point (for boundary classification).
+///
+/// The winding number of a point is the sum of the winding numbers of all the polylines, so holes
+/// should have the opposite orientation of the polyline that contains them (see
+/// [PlineContainmentTree::fix_orientations](crate::polyline::PlineContainmentTree::fix_orientations)).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::pline_closed;
+/// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// // clockwise hole
+/// let hole = pline_closed![(4.0, 5.0, -1.0), (6.0, 5.0, -1.0)];
+/// let locator = PointLocator::new(&[outer, hole], 1e-5);
+/// assert_eq!(locator.locate(Vector2::new(1.0, 1.0)), PointLocation::Inside);
+/// assert_eq!(locator.locate(Vector2::new(5.0, 5.0)), PointLocation::Outside);
+/// assert_eq!(locator.locate(Vector2::new(6.0, 5.0)), PointLocation::Boundary);
+/// assert_eq!(locator.locate(Vector2::new(11.0, 5.0)), PointLocation::Outside);
+///
+/// let points = [Vector2::new(1.0, 1.0), Vector2::new(5.0, 5.0), Vector2::new(10.0, 1.0)];
+/// assert_eq!(locator.winding_numbers(points.iter().copied()), vec![1, 0, 0]);
+/// assert_eq!(locator.contains_all(points.iter().copied()), vec![true, false, true]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PointLocator<T = f64>
+where
+    T: Real,
+{
+    segments: Vec<(PlineVertex<T>, PlineVertex<T>)>,
+    aabb_index: Option<StaticAABB2DIndex<T>>,
+    boundary_eps: T,
+}
+
+impl<T> PointLocator<T>
+where
+    T: Real,
+{
+    /// Build a point locator from a set of closed polylines.
+    ///
+    /// Points within `boundary_eps` distance of any polyline segment are classified as
+    /// [PointLocation::Boundary]. Open polylines and polylines with less than 2 vertexes are
+    /// ignored.
+    pub fn new<'a, P, I>(plines: I, boundary_eps: T) -> Self
+    where
+        P: PlineSource<Num = T> + 'a + ?Sized,
+        I: IntoIterator<Item = &'a P>,
+    {
+        let segments = plines
+            .into_iter()
+            .filter(|p| p.is_closed() && p.vertex_count() >= 2)
+            .flat_map(|p| p.iter_segments())
+            .collect::<Vec<_>>();
+
+        let aabb_index = if segments.is_empty() {
+            None
+        } else {
+            let mut builder = StaticAABB2DIndexBuilder::new(segments.len());
+            for &(v1, v2) in segments.iter() {
+                let bb = seg_bounding_box(v1, v2);
+                builder.add(bb.min_x, bb.min_y, bb.max_x, bb.max_y);
+            }
+            builder.build().ok()
+        };
+
+        Self {
+            segments,
+            aabb_index,
+            boundary_eps,
+        }
+    }
+
+    /// Boundary tolerance used when classifying points.
+    #[inline]
+    pub fn boundary_eps(&self) -> T {
+        self.boundary_eps
+    }
+
+    /// Spatial index of the segment bounding boxes (`None` if there are no segments).
+    #[inline]
+    pub fn aabb_index(&self) -> Option<&StaticAABB2DIndex<T>> {
+        self.aabb_index.as_ref()
+    }
+
+    fn winding_number_with_stack(&self, point: Vector2<T>, query_stack: &mut Vec<usize>) -> i32 {
+        let index = match self.aabb_index {
+            Some(ref index) => index,
+            None => return 0,
+        };
+
+        if point.x > index.max_x() {
+            return 0;
+        }
+
+        let mut winding = 0;
+        index.visit_query_with_stack(
+            point.x,
+            point.y,
+            index.max_x(),
+            point.y,
+            &mut |i: usize| {
+                let (v1, v2) = self.segments[i];
+                winding += seg_winding_number(v1, v2, point);
+                aabb_index::Control::<()>::Continue
+            },
+            query_stack,
+        );
+
+        winding
+    }
+
+    fn on_boundary_with_stack(&self, point: Vector2<T>, query_stack: &mut Vec<usize>) -> bool {
+        let index = match self.aabb_index {
+            Some(ref index) => index,
+            None => return false,
+        };
+
+        let eps = self.boundary_eps;
+        let eps2 = eps * eps;
+        let mut on_boundary = false;
+        index.visit_query_with_stack(
+            point.x - eps,
+            point.y - eps,
+            point.x + eps,
+            point.y + eps,
+            &mut |i: usize| {
+                let (v1, v2) = self.segments[i];
+                let cp = seg_closest_point(v1, v2, point, eps);
+                if dist_squared(cp, point) <= eps2 {
+                    on_boundary = true;
+                    return aabb_index::Control::Break(());
+                }
+                aabb_index::Control::Continue
+            },
+            query_stack,
+        );
+
+        on_boundary
+    }
+
+    fn locate_with_stack(&self, point: Vector2<T>, query_stack: &mut Vec<usize>) -> PointLocation {
+        if self.on_boundary_with_stack(point, query_stack) {
+            PointLocation::Boundary
+        } else if self.winding_number_with_stack(point, query_stack) != 0 {
+            PointLocation::Inside
+        } else {
+            PointLocation::Outside
+        }
+    }
+
+    /// Winding number of `point` (sum of the winding numbers of all the polylines).
+    ///
+    /// Boundary tolerance is not used, the result for points on the boundary follows the same
+    /// rules as [PlineSource::winding_number].
+    #[inline]
+    pub fn winding_number(&self, point: Vector2<T>) -> i32 {
+        self.winding_number_with_stack(point, &mut Vec::new())
+    }
+
+    /// Classify `point` as inside, outside, or on the boundary of the region.
+    #[inline]
+    pub fn locate(&self, point: Vector2<T>) -> PointLocation {
+        self.locate_with_stack(point, &mut Vec::new())
+    }
+
+    /// Returns true if `point` is inside or on the boundary of the region.
+    #[inline]
+    pub fn contains(&self, point: Vector2<T>) -> bool {
+        self.locate(point) != PointLocation::Outside
+    }
+
+    /// Returns true if the entire path of `pline` lies inside or on the boundary of the region.
+    ///
+    /// Each segment of `pline` is split at its intersects with the region boundary and the midpoint
+    /// of every piece is classified (along with the vertexes), so the result does not depend on
+    /// sampling density.
+    #[inline]
+    pub fn contains_pline<P>(&self, pline: &P) -> bool
+    where
+        P: PlineSource<Num = T> + ?Sized,
+    {
+        self.pline_avoids(pline, PointLocation::Outside)
+    }
+
+    /// Returns true if the entire path of `pline` lies outside or on the boundary of the region
+    /// (no part of the path is strictly inside), see [PointLocator::contains_pline].
+    #[inline]
+    pub fn excludes_pline<P>(&self, pline: &P) -> bool
+    where
+        P: PlineSource<Num = T> + ?Sized,
+    {
+        self.pline_avoids(pline, PointLocation::Inside)
+    }
+
+    /// Returns true if no part of the path of `pline` is classified as `location`.
+    fn pline_avoids<P>(&self, pline: &P, location: PointLocation) -> bool
+    where
+        P: PlineSource<Num = T> + ?Sized,
+    {
+        let index = match self.aabb_index {
+            Some(ref index) => index,
+            // empty region so every point is outside
+            None => return location != PointLocation::Outside || pline.is_empty(),
+        };
+
+        let mut query_stack = Vec::new();
+        if pline
+            .iter_vertexes()
+            .any(|v| self.locate_with_stack(v.pos(), &mut query_stack) == location)
+        {
+            return false;
+        }
+
+        let eps = self.boundary_eps;
+        let mut split_params = Vec::new();
+        for (v1, v2) in pline.iter_segments() {
+            let seg_len = seg_length(v1, v2);
+            if seg_len <= eps {
+                continue;
+            }
+
+            // parametric value (from 0 to 1) along the segment of a point on the segment
+            let param_of = |point: Vector2<T>| {
+                if v1.pos().fuzzy_eq_eps(point, eps) {
+                    return T::zero();
+                }
+                let split = seg_split_at_point(v1, v2, point, eps);
+                seg_length(split.updated_start, split.split_vertex) / seg_len
+            };
+
+            split_params.clear();
+            split_params.push(T::zero());
+            split_params.push(T::one());
+            let bb = seg_bounding_box(v1, v2);
+            index.visit_query_with_stack(
+                bb.min_x - eps,
+                bb.min_y - eps,
+                bb.max_x + eps,
+                bb.max_y + eps,
+                &mut |i: usize| {
+                    let (u1, u2) = self.segments[i];
+                    match pline_seg_intr(v1, v2, u1, u2, eps) {
+                        PlineSegIntr::NoIntersect => {}
+                        PlineSegIntr::TangentIntersect { point }
+                        | PlineSegIntr::OneIntersect { point } => {
+                            split_params.push(param_of(point));
+                        }
+                        PlineSegIntr::TwoIntersects { point1, point2 }
+                        | PlineSegIntr::OverlappingLines { point1, point2 }
+                        | PlineSegIntr::OverlappingArcs { point1, point2 } => {
+                            split_params.push(param_of(point1));
+                            split_params.push(param_of(point2));
+                        }
+                    }
+                    aabb_index::Control::<()>::Continue
+                },
+                &mut query_stack,
+            );
+
+            split_params.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            for w in split_params.windows(2) {
+                if (w[1] - w[0]) * seg_len <= eps {
+                    continue;
+                }
+                let mid = seg_point_at_parametric(v1, v2, (w[0] + w[1]) / T::two());
+                if self.locate_with_stack(mid, &mut query_stack) == location {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Distance from `point` to the closest point on the region boundary, returns `None` if the
+    /// region is empty.
+    pub fn distance_to_boundary(&self, point: Vector2<T>) -> Option<T> {
+        let index = self.aabb_index.as_ref()?;
+        let eps = self.boundary_eps;
+        let mut closest_dist2 = <T as Real>::max_value();
+        let mut visitor = |i: usize, box_dist2: T| {
+            if box_dist2 > closest_dist2 {
+                // all remaining segments are further away than the closest found
+                return aabb_index::Control::Break(());
+            }
+            let (v1, v2) = self.segments[i];
+            let dist2 = dist_squared(seg_closest_point(v1, v2, point, eps), point);
+            if dist2 < closest_dist2 {
+                closest_dist2 = dist2;
+            }
+            aabb_index::Control::Continue
+        };
+        index.visit_neighbors(point.x, point.y, &mut visitor);
+
+        Some(closest_dist2.sqrt())
+    }
+
+    /// Signed distance from `point` to the region boundary, positive if `point` is inside the
+    /// region (winding number is not zero) and negative if it is outside. Returns `None` if the
+    /// region is empty.
+    pub fn signed_distance(&self, point: Vector2<T>) -> Option<T> {
+        let dist = self.distance_to_boundary(point)?;
+        if self.winding_number(point) != 0 {
+            Some(dist)
+        } else {
+            Some(-dist)
+        }
+    }
+
+    /// Same as [PointLocator::winding_number] but for many points, reusing query buffers.
+    pub fn winding_numbers<I>(&self, points: I) -> Vec<i32>
+    where
+        I: IntoIterator<Item = Vector2<T>>,
+    {
+        let mut query_stack = Vec::new();
+        points
+            .into_iter()
+            .map(|pt| self.winding_number_with_stack(pt, &mut query_stack))
+            .collect()
+    }
+
+    /// Same as [PointLocator::locate] but for many points, reusing query buffers.
+    pub fn locate_all<I>(&self, points: I) -> Vec<PointLocation>
+    where
+        I: IntoIterator<Item = Vector2<T>>,
+    {
+        let mut query_stack = Vec::new();
+        points
+            .into_iter()
+            .map(|pt| self.locate_with_stack(pt, &mut query_stack))
+            .collect()
+    }
+
+    /// Same as [PointLocator::contains] but for many points, reusing query buffers.
+    pub fn contains_all<I>(&self, points: I) -> Vec<bool>
+    where
+        I: IntoIterator<Item = Vector2<T>>,
+    {
+        let mut query_stack = Vec::new();
+        points
+            .into_iter()
+            .map(|pt| self.locate_with_stack(pt, &mut query_stack) != PointLocation::Outside)
+            .collect()
+    }
+}
@@ -0,0 +1,469 @@
+use super::{seg_arc_radius_and_center, PlineSource, PlineVertex};
+use crate::core::{
+    math::{angle, angle_from_bulge, normalize_radians, point_on_circle, Vector2},
+    traits::Real,
+};
+use std::cmp::Ordering;
+
+/// Rule used to determine which points are inside when rasterizing with [rasterize_bitmap] or
+/// [rasterize_coverage].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Points with a winding number not equal to zero are inside (holes must have the opposite
+    /// orientation of the loop containing them).
+    NonZero,
+    /// Points with an odd winding number are inside (orientation is ignored).
+    EvenOdd,
+}
+
+impl FillRule {
+    #[inline]
+    fn is_inside(&self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Returns false for NaN and infinity.
+#[inline]
+fn is_finite<T>(x: T) -> bool
+where
+    T: Real,
+{
+    x.abs() <= <T as Real>::max_value()
+}
+
+/// Pixel grid to rasterize onto.
+///
+/// Pixels are stored row by row (`index = row * width + col`), pixel (0, 0) has its minimum
+/// corner at [RasterGrid::origin] and rows increase in the positive y direction (reverse the rows
+/// for images stored top row first).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterGrid<T = f64> {
+    /// Minimum corner of the grid.
+    pub origin: Vector2<T>,
+    /// Width and height of each pixel.
+    pub pixel_size: T,
+    /// Number of pixel columns.
+    pub width: usize,
+    /// Number of pixel rows.
+    pub height: usize,
+}
+
+impl<T> RasterGrid<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new(origin: Vector2<T>, pixel_size: T, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            pixel_size,
+            width,
+            height,
+        }
+    }
+
+    /// Returns true if the origin is finite and the pixel size is finite and greater than zero,
+    /// nothing is rasterized onto an invalid grid.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        is_finite(self.origin.x)
+            && is_finite(self.origin.y)
+            && is_finite(self.pixel_size)
+            && self.pixel_size > T::zero()
+    }
+
+    /// Center of the pixel at `col` and `row`.
+    #[inline]
+    pub fn pixel_center(&self, col: usize, row: usize) -> Vector2<T> {
+        let half = T::one() / T::two();
+        Vector2::new(
+            self.origin.x + (T::from(col).unwrap() + half) * self.pixel_size,
+            self.origin.y + (T::from(row).unwrap() + half) * self.pixel_size,
+        )
+    }
+}
+
+/// Options for rasterizing closed polylines with [rasterize_bitmap] and [rasterize_coverage].
+#[derive(Debug, Clone)]
+pub struct RasterizeOptions {
+    /// Fill rule used to determine which points are inside.
+    pub fill_rule: FillRule,
+    /// Number of scanlines sampled per pixel row for [rasterize_coverage] (horizontal coverage
+    /// within each scanline is exact).
+    pub coverage_samples: usize,
+}
+
+impl RasterizeOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            fill_rule: FillRule::NonZero,
+            coverage_samples: 16,
+        }
+    }
+}
+
+impl Default for RasterizeOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Y monotone piece of a polyline segment.
+#[derive(Debug, Clone, Copy)]
+struct Edge<T> {
+    y_min: T,
+    y_max: T,
+    /// 1 if the edge goes upward, -1 if it goes downward.
+    dir: i32,
+    kind: EdgeKind<T>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EdgeKind<T> {
+    Line {
+        start: Vector2<T>,
+        end: Vector2<T>,
+    },
+    Arc {
+        center: Vector2<T>,
+        radius: T,
+        /// True if the arc piece is right of the center (otherwise left).
+        right_side: bool,
+    },
+}
+
+impl<T> Edge<T>
+where
+    T: Real,
+{
+    fn new(start: Vector2<T>, end: Vector2<T>, kind: EdgeKind<T>) -> Option<Self> {
+        if start.y == end.y {
+            return None;
+        }
+
+        let (y_min, y_max, dir) = if start.y < end.y {
+            (start.y, end.y, 1)
+        } else {
+            (end.y, start.y, -1)
+        };
+
+        Some(Self {
+            y_min,
+            y_max,
+            dir,
+            kind,
+        })
+    }
+
+    /// X position of the edge at `y` (must be within the edge's y range).
+    fn x_at(&self, y: T) -> T {
+        match self.kind {
+            EdgeKind::Line { start, end } => {
+                start.x + (y - start.y) * (end.x - start.x) / (end.y - start.y)
+            }
+            EdgeKind::Arc {
+                center,
+                radius,
+                right_side,
+            } => {
+                let dy = y - center.y;
+                let dx = num_traits::real::Real::max(radius * radius - dy * dy, T::zero()).sqrt();
+                if right_side {
+                    center.x + dx
+                } else {
+                    center.x - dx
+                }
+            }
+        }
+    }
+}
+
+/// Split a polyline segment into y monotone edges, arcs are split at their top and bottom points.
+fn add_seg_edges<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, edges: &mut Vec<Edge<T>>)
+where
+    T: Real,
+{
+    if ![v1.x, v1.y, v1.bulge, v2.x, v2.y]
+        .iter()
+        .all(|&c| is_finite(c))
+    {
+        // segment cannot be rasterized
+        return;
+    }
+
+    let (start, end) = (v1.pos(), v2.pos());
+    if v1.bulge_is_zero() {
+        edges.extend(Edge::new(start, end, EdgeKind::Line { start, end }));
+        return;
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, start);
+    let sweep = angle_from_bulge(v1.bulge);
+
+    // offsets along the sweep (in the sweep direction) of the top and bottom points of the circle
+    let mut splits = [T::pi() / T::two(), -T::pi() / T::two()]
+        .iter()
+        .map(|&a| {
+            if sweep > T::zero() {
+                normalize_radians(a - start_angle)
+            } else {
+                normalize_radians(start_angle - a)
+            }
+        })
+        .filter(|&d| d > T::zero() && d < sweep.abs())
+        .collect::<Vec<_>>();
+    splits.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let mut piece_start = start;
+    let mut piece_start_offset = T::zero();
+    for i in 0..=splits.len() {
+        let (piece_end, piece_end_offset) = if i < splits.len() {
+            let offset = if sweep > T::zero() {
+                splits[i]
+            } else {
+                -splits[i]
+            };
+            (
+                point_on_circle(radius, center, start_angle + offset),
+                splits[i],
+            )
+        } else {
+            (end, sweep.abs())
+        };
+
+        let mid_offset = (piece_start_offset + piece_end_offset) / T::two();
+        let mid_angle = if sweep > T::zero() {
+            start_angle + mid_offset
+        } else {
+            start_angle - mid_offset
+        };
+        let kind = EdgeKind::Arc {
+            center,
+            radius,
+            right_side: mid_angle.cos() >= T::zero(),
+        };
+        edges.extend(Edge::new(piece_start, piece_end, kind));
+
+        piece_start = piece_end;
+        piece_start_offset = piece_end_offset;
+    }
+}
+
+/// Scanline rasterizer, edges are sorted by minimum y and scanlines must be processed in
+/// increasing y order.
+struct Scanner<T> {
+    edges: Vec<Edge<T>>,
+    next_edge: usize,
+    active: Vec<usize>,
+    crossings: Vec<(T, i32)>,
+    fill_rule: FillRule,
+}
+
+impl<T> Scanner<T>
+where
+    T: Real,
+{
+    fn new<'a, P, I>(plines: I, fill_rule: FillRule) -> Self
+    where
+        P: PlineSource<Num = T> + 'a + ?Sized,
+        I: IntoIterator<Item = &'a P>,
+    {
+        let mut edges = Vec::new();
+        for pline in plines {
+            if !pline.is_closed() || pline.vertex_count() < 2 {
+                continue;
+            }
+            for (v1, v2) in pline.iter_segments() {
+                add_seg_edges(v1, v2, &mut edges);
+            }
+        }
+        edges.sort_by(|a, b| a.y_min.partial_cmp(&b.y_min).unwrap_or(Ordering::Equal));
+
+        Self {
+            edges,
+            next_edge: 0,
+            active: Vec::new(),
+            crossings: Vec::new(),
+            fill_rule,
+        }
+    }
+
+    /// Call `visitor` with the start and end x of each span inside the shapes at scanline `y`.
+    fn scan<F>(&mut self, y: T, mut visitor: F)
+    where
+        F: FnMut(T, T),
+    {
+        // same half open rule as the winding number (edge spans y_min <= y < y_max)
+        while self.next_edge < self.edges.len() && self.edges[self.next_edge].y_min <= y {
+            self.active.push(self.next_edge);
+            self.next_edge += 1;
+        }
+        let edges = &self.edges;
+        self.active.retain(|&e| edges[e].y_max > y);
+
+        self.crossings.clear();
+        for &e in self.active.iter() {
+            let edge = &edges[e];
+            self.crossings.push((edge.x_at(y), edge.dir));
+        }
+        self.crossings
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        // winding number for a point is the sum of directions of crossings to its right, so
+        // going left to right it changes by the negative of each direction crossed
+        let mut winding = 0;
+        let mut span_start = T::zero();
+        for &(x, dir) in self.crossings.iter() {
+            let was_inside = self.fill_rule.is_inside(winding);
+            winding -= dir;
+            let is_inside = self.fill_rule.is_inside(winding);
+            if !was_inside && is_inside {
+                span_start = x;
+            } else if was_inside && !is_inside && x > span_start {
+                visitor(span_start, x);
+            }
+        }
+    }
+}
+
+/// Rasterize closed polylines onto a grid with one byte per pixel, pixels with their center
+/// inside the shapes (according to [RasterizeOptions::fill_rule]) are set to 255 and all others
+/// are 0.
+///
+/// Shapes with holes are given as multiple polylines (for [FillRule::NonZero] holes must have the
+/// opposite orientation of their outer polylines). Arcs are evaluated exactly (no
+/// approximation) and the inside test matches [PlineSource::winding_number] (for points not on
+/// the boundary). Open polylines and segments with non-finite values are ignored, and nothing is
+/// rasterized if the grid is not valid (see [RasterGrid::is_valid]).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::pline_closed;
+/// let square: Polyline = pline_closed![(1.0, 1.0, 0.0), (3.0, 1.0, 0.0), (3.0, 3.0, 0.0), (1.0, 3.0, 0.0)];
+/// let grid = RasterGrid::new(Vector2::zero(), 1.0, 4, 4);
+/// let bitmap = rasterize_bitmap(std::iter::once(&square), &grid, &RasterizeOptions::default());
+/// assert_eq!(bitmap.iter().filter(|&&b| b == 255).count(), 4);
+/// assert_eq!(bitmap[1 * 4 + 1], 255);
+/// assert_eq!(bitmap[0], 0);
+/// ```
+pub fn rasterize_bitmap<'a, P, I, T>(
+    plines: I,
+    grid: &RasterGrid<T>,
+    options: &RasterizeOptions,
+) -> Vec<u8>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let mut result = vec![0u8; grid.width * grid.height];
+    if grid.width == 0 || !grid.is_valid() {
+        return result;
+    }
+
+    let half = T::one() / T::two();
+    let width = T::from(grid.width).unwrap();
+    let mut scanner = Scanner::new(plines, options.fill_rule);
+    for row in 0..grid.height {
+        let y = grid.origin.y + (T::from(row).unwrap() + half) * grid.pixel_size;
+        let row_pixels = &mut result[row * grid.width..(row + 1) * grid.width];
+        scanner.scan(y, |x1, x2| {
+            // pixels with center in [x1, x2)
+            let col_at = |x: T| {
+                let c = ((x - grid.origin.x) / grid.pixel_size - half).ceil();
+                num_traits::real::Real::min(num_traits::real::Real::max(c, T::zero()), width)
+                    .to_usize()
+                    .unwrap()
+            };
+            for pixel in row_pixels[col_at(x1)..col_at(x2)].iter_mut() {
+                *pixel = 255;
+            }
+        });
+    }
+
+    result
+}
+
+/// Rasterize closed polylines onto a grid with anti-aliased coverage values, each pixel is set to
+/// the fraction of its area inside the shapes (0 to 1).
+///
+/// Coverage is found using [RasterizeOptions::coverage_samples] scanlines per pixel row, the
+/// horizontal coverage along each scanline is exact. See [rasterize_bitmap] for how shapes are
+/// defined.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::pline_closed;
+/// let square: Polyline = pline_closed![(0.5, 0.0, 0.0), (1.5, 0.0, 0.0), (1.5, 2.0, 0.0), (0.5, 2.0, 0.0)];
+/// let grid = RasterGrid::new(Vector2::zero(), 1.0, 2, 2);
+/// let coverage = rasterize_coverage(std::iter::once(&square), &grid, &RasterizeOptions::default());
+/// assert!(coverage.iter().all(|&c| (c - 0.5).abs() < 1e-9));
+/// ```
+pub fn rasterize_coverage<'a, P, I, T>(
+    plines: I,
+    grid: &RasterGrid<T>,
+    options: &RasterizeOptions,
+) -> Vec<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let mut result = vec![T::zero(); grid.width * grid.height];
+    if grid.width == 0 || !grid.is_valid() {
+        return result;
+    }
+
+    let samples = options.coverage_samples.max(1);
+    let sample_weight = T::one() / T::from(samples).unwrap();
+    let half = T::one() / T::two();
+    let width = T::from(grid.width).unwrap();
+    let mut scanner = Scanner::new(plines, options.fill_rule);
+    for row in 0..grid.height {
+        let row_pixels = &mut result[row * grid.width..(row + 1) * grid.width];
+        for s in 0..samples {
+            let y = grid.origin.y
+                + (T::from(row).unwrap() + (T::from(s).unwrap() + half) * sample_weight)
+                    * grid.pixel_size;
+            scanner.scan(y, |x1, x2| {
+                // span in pixel units clamped to the grid
+                let clamp = |x: T| {
+                    num_traits::real::Real::min(
+                        num_traits::real::Real::max(
+                            (x - grid.origin.x) / grid.pixel_size,
+                            T::zero(),
+                        ),
+                        width,
+                    )
+                };
+                let (u1, u2) = (clamp(x1), clamp(x2));
+                if u2 <= u1 {
+                    return;
+                }
+                let first = u1.floor().to_usize().unwrap();
+                let last = u2.ceil().to_usize().unwrap().min(grid.width);
+                for (col, pixel) in row_pixels.iter_mut().enumerate().take(last).skip(first) {
+                    let col_start = T::from(col).unwrap();
+                    let overlap = num_traits::real::Real::min(u2, col_start + T::one())
+                        - num_traits::real::Real::max(u1, col_start);
+                    *pixel = *pixel + overlap * sample_weight;
+                }
+            });
+        }
+    }
+
+    result
+}
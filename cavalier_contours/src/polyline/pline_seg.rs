@@ -1,9 +1,9 @@
-use super::PlineVertex;
+use super::{pline_seg_intr, PlineSegIntr, PlineVertex};
 use crate::core::{
     math::{
-        angle, angle_is_within_sweep, bulge_from_angle, delta_angle, delta_angle_signed,
-        dist_squared, line_seg_closest_point, midpoint, min_max, point_on_circle,
-        point_within_arc_sweep, Vector2,
+        angle, angle_from_bulge, angle_is_within_sweep, bulge_from_angle, delta_angle,
+        delta_angle_signed, dist_squared, is_left, is_left_or_equal, line_seg_closest_point,
+        midpoint, min_max, point_on_circle, point_within_arc_sweep, Vector2,
     },
     traits::Real,
 };
@@ -441,3 +441,282 @@ where
     let mid_angle = angle1 + angle_offset;
     point_on_circle(arc_radius, arc_center, mid_angle)
 }
+
+/// Find the point on the polyline segment defined by `v1` to `v2` at the normalized parametric
+/// value `t` given. `t` is the fraction of the segment path length, `t = 0` returns the start point
+/// and `t = 1` returns the end point.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// // counter clockwise half circle arc going from (2, 2) to (4, 2)
+/// let v1 = PlineVertex::new(2.0, 2.0, 1.0);
+/// let v2 = PlineVertex::new(4.0, 2.0, 0.0);
+/// assert!(seg_point_at_parametric(v1, v2, 0.5).fuzzy_eq(Vector2::new(3.0, 1.0)));
+/// // line segment
+/// let v1 = PlineVertex::new(2.0, 2.0, 0.0);
+/// assert!(seg_point_at_parametric(v1, v2, 0.25).fuzzy_eq(Vector2::new(2.5, 2.0)));
+/// ```
+pub fn seg_point_at_parametric<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, t: T) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() || v1.pos().fuzzy_eq(v2.pos()) {
+        return v1.pos() + (v2.pos() - v1.pos()).scale(t);
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let total_sweep_angle = angle_from_bulge(v1.bulge);
+    point_on_circle(radius, center, start_angle + total_sweep_angle * t)
+}
+
+/// Find the closest pair of points between two polyline segments defined by `v1` to `v2` and `u1`
+/// to `u2`. Returns `(point_on_first_seg, point_on_second_seg)`. If the segments intersect then an
+/// intersect point is returned for both points. If there are multiple closest point pairs then one
+/// is chosen (which is chosen is not defined).
+///
+/// `pos_equal_eps` is used for fuzzy float comparisons.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::polyline::*;
+/// // counter clockwise half circle arc going from (0, 0) to (2, 0) bulging downward
+/// let v1 = PlineVertex::new(0.0, 0.0, 1.0);
+/// let v2 = PlineVertex::new(2.0, 0.0, 0.0);
+/// // horizontal line below the arc
+/// let u1 = PlineVertex::new(-5.0, -3.0, 0.0);
+/// let u2 = PlineVertex::new(5.0, -3.0, 0.0);
+/// let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+/// assert!(p1.fuzzy_eq(Vector2::new(1.0, -1.0)));
+/// assert!(p2.fuzzy_eq(Vector2::new(1.0, -3.0)));
+/// ```
+pub fn seg_closest_points<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    u1: PlineVertex<T>,
+    u2: PlineVertex<T>,
+    pos_equal_eps: T,
+) -> (Vector2<T>, Vector2<T>)
+where
+    T: Real,
+{
+    // degenerate segments (points)
+    if v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps) {
+        return (v1.pos(), seg_closest_point(u1, u2, v1.pos(), pos_equal_eps));
+    }
+
+    if u1.pos().fuzzy_eq_eps(u2.pos(), pos_equal_eps) {
+        return (seg_closest_point(v1, v2, u1.pos(), pos_equal_eps), u1.pos());
+    }
+
+    match pline_seg_intr(v1, v2, u1, u2, pos_equal_eps) {
+        PlineSegIntr::NoIntersect => {}
+        PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+            return (point, point);
+        }
+        PlineSegIntr::TwoIntersects { point1, .. }
+        | PlineSegIntr::OverlappingLines { point1, .. }
+        | PlineSegIntr::OverlappingArcs { point1, .. } => {
+            return (point1, point1);
+        }
+    }
+
+    let mut result = (v1.pos(), seg_closest_point(u1, u2, v1.pos(), pos_equal_eps));
+    let mut min_dist2 = dist_squared(result.0, result.1);
+
+    let mut consider = |p: Vector2<T>, q: Vector2<T>| {
+        let d = dist_squared(p, q);
+        if d < min_dist2 {
+            min_dist2 = d;
+            result = (p, q);
+        }
+    };
+
+    // segment end points to the other segment
+    consider(v2.pos(), seg_closest_point(u1, u2, v2.pos(), pos_equal_eps));
+    consider(seg_closest_point(v1, v2, u1.pos(), pos_equal_eps), u1.pos());
+    consider(seg_closest_point(v1, v2, u2.pos(), pos_equal_eps), u2.pos());
+
+    // points on arc that are interior to the arc and may be closest, for an arc and line these are
+    // the points on the arc circle where the radius is perpendicular to the line, for two arcs these
+    // are the points on the arc circles along the line between the arc centers
+    let arc_candidates = |a1: PlineVertex<T>, a2: PlineVertex<T>, dir: Vector2<T>| {
+        let (radius, center) = seg_arc_radius_and_center(a1, a2);
+        let offset = dir.scale(radius);
+        let within_sweep = |p: Vector2<T>| {
+            point_within_arc_sweep(
+                center,
+                a1.pos(),
+                a2.pos(),
+                a1.bulge_is_neg(),
+                p,
+                pos_equal_eps,
+            )
+        };
+        let p1 = center + offset;
+        let p2 = center - offset;
+        [
+            Some(p1).filter(|&p| within_sweep(p)),
+            Some(p2).filter(|&p| within_sweep(p)),
+        ]
+    };
+
+    match (v1.bulge_is_zero(), u1.bulge_is_zero()) {
+        (true, true) => {}
+        (false, true) => {
+            let dir = (u2.pos() - u1.pos()).unit_perp();
+            for &p in arc_candidates(v1, v2, dir).iter().flatten() {
+                consider(p, seg_closest_point(u1, u2, p, pos_equal_eps));
+            }
+        }
+        (true, false) => {
+            let dir = (v2.pos() - v1.pos()).unit_perp();
+            for &q in arc_candidates(u1, u2, dir).iter().flatten() {
+                consider(seg_closest_point(v1, v2, q, pos_equal_eps), q);
+            }
+        }
+        (false, false) => {
+            let (_, center1) = seg_arc_radius_and_center(v1, v2);
+            let (_, center2) = seg_arc_radius_and_center(u1, u2);
+            if !center1.fuzzy_eq_eps(center2, pos_equal_eps) {
+                let dir = (center2 - center1).normalize();
+                for &p in arc_candidates(v1, v2, dir).iter().flatten() {
+                    consider(p, seg_closest_point(u1, u2, p, pos_equal_eps));
+                }
+                for &q in arc_candidates(u1, u2, dir).iter().flatten() {
+                    consider(seg_closest_point(v1, v2, q, pos_equal_eps), q);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the winding number contribution of the polyline segment defined by `v1` to `v2` for
+/// `point`.
+///
+/// Summing the result for every segment of a closed polyline gives the winding number of the
+/// polyline (see [PlineSource::winding_number](crate::polyline::PlineSource::winding_number)). The
+/// result is only non-zero if the segment crosses the horizontal ray going from `point` in the
+/// positive x direction or `point` lies between an arc segment and its chord (in either case the
+/// bounding box of the segment intersects the ray).
+pub fn seg_winding_number<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>) -> i32
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        // line segment
+        let mut result = 0;
+        if v1.y <= point.y {
+            if v2.y > point.y && is_left(v1.pos(), v2.pos(), point) {
+                // left and upward crossing
+                result += 1;
+            }
+        } else if v2.y <= point.y && !is_left(v1.pos(), v2.pos(), point) {
+            // right an downward crossing
+            result -= 1;
+        }
+
+        result
+    } else {
+        // arc segment
+        let is_ccw = v1.bulge_is_pos();
+        let point_is_left = if is_ccw {
+            is_left(v1.pos(), v2.pos(), point)
+        } else {
+            is_left_or_equal(v1.pos(), v2.pos(), point)
+        };
+
+        let dist_to_arc_center_less_than_radius = || {
+            let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
+            let dist2 = dist_squared(arc_center, point);
+            dist2 < arc_radius * arc_radius
+        };
+
+        let mut result = 0;
+
+        if v1.y <= point.y {
+            if v2.y > point.y {
+                // upward crossing of arc chord
+                if is_ccw {
+                    if point_is_left {
+                        // counter clockwise arc left of chord
+                        result += 1;
+                    } else {
+                        // counter clockwise arc right of chord
+                        if dist_to_arc_center_less_than_radius() {
+                            result += 1;
+                        }
+                    }
+                } else if point_is_left {
+                    // clockwise arc left of chord
+                    if !dist_to_arc_center_less_than_radius() {
+                        result += 1;
+                    }
+                    // else clockwise arc right of chord, no crossing
+                }
+            } else {
+                // not crossing arc chord and chord is below, check if point is inside arc sector
+                if is_ccw
+                    && !point_is_left
+                    && v2.x < point.x
+                    && point.x < v1.x
+                    && dist_to_arc_center_less_than_radius()
+                {
+                    result += 1;
+                } else if !is_ccw
+                    && point_is_left
+                    && v1.x < point.x
+                    && point.x < v2.x
+                    && dist_to_arc_center_less_than_radius()
+                {
+                    result -= 1;
+                }
+            }
+        } else if v2.y <= point.y {
+            // downward crossing of arc chord
+            if is_ccw {
+                if !point_is_left {
+                    // counter clockwise arc right of chord
+                    if !dist_to_arc_center_less_than_radius() {
+                        result -= 1;
+                    }
+                }
+            // else counter clockwise arc left of chord, no crossing
+            } else if point_is_left {
+                // clockwise arc left of chord
+                if dist_to_arc_center_less_than_radius() {
+                    result -= 1;
+                }
+            } else {
+                // clockwise arc right of chord
+                result -= 1;
+            }
+        } else {
+            // not crossing arc chord and chord is above, check if point is inside arc sector
+            if is_ccw
+                && !point_is_left
+                && v1.x < point.x
+                && point.x < v2.x
+                && dist_to_arc_center_less_than_radius()
+            {
+                result += 1;
+            } else if !is_ccw
+                && point_is_left
+                && v2.x < point.x
+                && point.x < v1.x
+                && dist_to_arc_center_less_than_radius()
+            {
+                result -= 1;
+            }
+        }
+
+        result
+    }
+}
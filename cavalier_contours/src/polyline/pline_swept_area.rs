@@ -0,0 +1,78 @@
+use super::{
+    pline_minkowski::minkowski_sum_path, pline_no_fit::region_loops, MinkowskiOptions,
+    MinkowskiResult, PlineSource, PlineSourceMut,
+};
+use crate::core::traits::Real;
+
+/// Compute the area swept by a closed `profile` translating (without rotation) along a `path`.
+///
+/// The profile is positioned by its coordinate origin, so the result is the union of the profile
+/// translated to every point on the path (the Minkowski sum of the path with the profile). The
+/// path is usually open, if it is closed the profile is swept around the whole loop. Line and arc
+/// segments in both the path and the profile are handled exactly (no approximation), e.g. sweeping
+/// a circle gives the same boundary as offsetting the path by the radius on both sides with round
+/// end caps.
+///
+/// The profile is given as a set of closed polylines, nesting is determined with
+/// [PlineContainmentTree](super::PlineContainmentTree) so input orientations do not matter and
+/// holes in the profile are accounted for (holes remain in the result where no part of the swept
+/// profile covers them). Result holes are returned in
+/// [neg_plines](MinkowskiResult::neg_plines).
+///
+/// If the path has no length the result is the profile translated to the path start, returns
+/// empty result if the path has no vertexes or the profile has no closed polylines with area.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed, pline_open};
+/// // 2x2 square profile centered on the origin
+/// let profile: Polyline = pline_closed![(-1.0, -1.0, 0.0), (1.0, -1.0, 0.0), (1.0, 1.0, 0.0), (-1.0, 1.0, 0.0)];
+/// let path: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+/// let result = swept_area(std::iter::once(&profile), &path, &MinkowskiOptions::default());
+/// assert_eq!(result.pos_plines.len(), 1);
+/// assert!(result.neg_plines.is_empty());
+/// assert_fuzzy_eq!(result.pos_plines[0].area(), 12.0 * 2.0);
+/// assert_eq!(result.pos_plines[0].winding_number(Vector2::new(5.0, 0.5)), 1);
+/// ```
+pub fn swept_area<'a, P, I, Q, T>(
+    profile: I,
+    path: &Q,
+    options: &MinkowskiOptions<T>,
+) -> MinkowskiResult<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    Q: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let mut profile = region_loops(profile, eps);
+    if profile.is_empty() || path.vertex_count() == 0 {
+        return MinkowskiResult::new();
+    }
+
+    let has_length = path
+        .iter_segments()
+        .any(|(v1, v2)| !v1.pos().fuzzy_eq_eps(v2.pos(), eps));
+    if has_length {
+        return minkowski_sum_path(path, &profile, options);
+    }
+
+    let start = path.at(0);
+    let mut result = MinkowskiResult::new();
+    for pline in profile.iter_mut() {
+        pline.translate_mut(start.x, start.y);
+    }
+    for pline in profile {
+        if pline.area() > T::zero() {
+            result.pos_plines.push(pline);
+        } else {
+            result.neg_plines.push(pline);
+        }
+    }
+
+    result
+}
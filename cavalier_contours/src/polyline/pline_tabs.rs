@@ -0,0 +1,312 @@
+use super::{
+    internal::pline_path_length::{path_range_view_data, vertex_path_lengths, wrap_path_length},
+    seg_tangent_vector, PlineSource, PlineViewData,
+};
+use crate::core::traits::Real;
+
+/// How holding tabs are positioned along a closed polyline, see [TabOptions::placement].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabPlacement<'a, T = f64> {
+    /// Evenly space the number of tabs given along the path.
+    Count(usize),
+    /// Place tabs centered at the path lengths given (measured from the first vertex, wrapped
+    /// around the polyline). Path lengths that are not finite are skipped.
+    AtPathLengths(&'a [T]),
+}
+
+/// Options for inserting holding tabs with [insert_tabs].
+#[derive(Debug, Clone)]
+pub struct TabOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// How the tabs are positioned.
+    pub placement: TabPlacement<'a, T>,
+    /// Path length of each tab.
+    pub width: T,
+    /// If true then the tab ranges are returned as slices marked with [TabSlice::is_tab] (for
+    /// raised passes), otherwise they are left out of the result (gaps).
+    pub raised_tabs: bool,
+    /// If true then tabs are not placed on arc segments.
+    pub avoid_arcs: bool,
+    /// Minimum path length distance between a tab and a corner, if zero then tabs may be placed
+    /// over corners.
+    pub corner_clearance: T,
+    /// Vertexes where the path direction changes by more than this angle (in radians) are
+    /// considered corners.
+    pub corner_angle: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> TabOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            placement: TabPlacement::Count(4),
+            width: T::one(),
+            raised_tabs: false,
+            avoid_arcs: false,
+            corner_clearance: T::zero(),
+            corner_angle: T::from(10.0f64.to_radians()).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for TabOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Slice of a closed polyline produced by [insert_tabs].
+#[derive(Debug, Clone, Copy)]
+pub struct TabSlice<T = f64> {
+    /// View data of the slice (use [PlineViewData::view] with the source polyline).
+    pub view_data: PlineViewData<T>,
+    /// Path length along the source polyline where the slice starts.
+    pub start_path_length: T,
+    /// True if the slice is a tab (only present if [TabOptions::raised_tabs] is true).
+    pub is_tab: bool,
+}
+
+/// Result of [insert_tabs].
+#[derive(Debug, Clone)]
+pub struct TabResult<T = f64> {
+    /// Slices of the polyline ordered by their start path length.
+    pub slices: Vec<TabSlice<T>>,
+    /// Path length of the center of each tab placed, sorted ascending. May have fewer entries than
+    /// requested if tabs could not be placed (e.g. all positions are on arcs or too close to
+    /// corners or other tabs).
+    pub tab_centers: Vec<T>,
+}
+
+/// Cyclic (shortest) distance between two path lengths.
+fn cyclic_dist<T>(a: T, b: T, total: T) -> T
+where
+    T: Real,
+{
+    let d = wrap_path_length(b - a, total);
+    num_traits::real::Real::min(d, total - d)
+}
+
+/// Returns true if `value` is strictly within the open cyclic interval `(start, start + length)`.
+fn in_open_interval<T>(value: T, start: T, length: T, total: T, eps: T) -> bool
+where
+    T: Real,
+{
+    let d = wrap_path_length(value - start, total);
+    d > eps && d < length - eps
+}
+
+/// Split a closed polyline into slices with holding tabs.
+///
+/// Tabs of [TabOptions::width] path length are positioned according to
+/// [TabOptions::placement]. If a requested tab position overlaps an arc segment (when
+/// [TabOptions::avoid_arcs] is set), lies within [TabOptions::corner_clearance] of a corner, or
+/// overlaps a previously placed tab then the tab is moved to the closest valid position along the
+/// path (or skipped if there is none).
+///
+/// The polyline is sliced at the tab boundaries using [PlineViewData], the slices between tabs
+/// are the cut pieces and the tab slices are either left out (gaps) or included and marked (raised
+/// passes) based on [TabOptions::raised_tabs].
+///
+/// Returns `None` if the polyline is open, has less than 2 vertexes, or has zero (or not finite)
+/// path length, or if [TabOptions::width], [TabOptions::corner_clearance] or
+/// [TabOptions::corner_angle] is not finite.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let square: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let options = TabOptions {
+///     placement: TabPlacement::Count(2),
+///     width: 2.0,
+///     ..Default::default()
+/// };
+/// let result = insert_tabs(&square, &options).unwrap();
+/// // tabs centered at path lengths 10 and 30 (corners of the square)
+/// assert_eq!(result.tab_centers, vec![10.0, 30.0]);
+/// assert_eq!(result.slices.len(), 2);
+/// let piece = result.slices[0].view_data.view(&square);
+/// assert_fuzzy_eq!(piece.path_length(), 18.0);
+///
+/// // keep tabs away from corners
+/// let options = TabOptions {
+///     corner_clearance: 1.0,
+///     ..options
+/// };
+/// let result = insert_tabs(&square, &options).unwrap();
+/// assert_eq!(result.tab_centers, vec![8.0, 28.0]);
+/// ```
+pub fn insert_tabs<P, T>(pline: &P, options: &TabOptions<T>) -> Option<TabResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let is_finite = |x: T| x.abs() <= <T as Real>::max_value();
+    if !pline.is_closed()
+        || pline.vertex_count() < 2
+        || !is_finite(options.width)
+        || !is_finite(options.corner_clearance)
+        || !is_finite(options.corner_angle)
+    {
+        return None;
+    }
+
+    let lengths = vertex_path_lengths(pline);
+    let total = lengths[lengths.len() - 1];
+    if total <= eps || !is_finite(total) {
+        return None;
+    }
+
+    let width = num_traits::real::Real::max(options.width, T::zero());
+    let half_width = width / T::two();
+
+    let requested = match options.placement {
+        TabPlacement::Count(count) => {
+            let spacing = total / T::from(count.max(1)).unwrap();
+            (0..count)
+                .map(|i| (T::from(i).unwrap() + T::from(0.5).unwrap()) * spacing)
+                .collect::<Vec<_>>()
+        }
+        TabPlacement::AtPathLengths(positions) => positions
+            .iter()
+            .filter(|&&p| is_finite(p))
+            .map(|&p| wrap_path_length(p, total))
+            .collect(),
+    };
+
+    // forbidden cyclic intervals for tab centers as (start, length)
+    let mut forbidden = Vec::new();
+    let vc = pline.vertex_count();
+    for i in 0..vc {
+        let v1 = pline.at(i);
+        let v2 = pline.at(pline.next_wrapping_index(i));
+        if options.avoid_arcs && !v1.bulge_is_zero() && lengths[i + 1] - lengths[i] > eps {
+            forbidden.push((lengths[i] - half_width, lengths[i + 1] - lengths[i] + width));
+        }
+
+        if options.corner_clearance > T::zero() {
+            let prev = pline.at(pline.prev_wrapping_index(i));
+            let dir_in = seg_tangent_vector(prev, v1, v1.pos()).normalize();
+            let dir_out = seg_tangent_vector(v1, v2, v1.pos()).normalize();
+            let turn = dir_in.perp_dot(dir_out).atan2(dir_in.dot(dir_out)).abs();
+            if turn > options.corner_angle {
+                let clearance = half_width + options.corner_clearance;
+                forbidden.push((lengths[i] - clearance, clearance + clearance));
+            }
+        }
+    }
+
+    let mut tab_centers: Vec<T> = Vec::with_capacity(requested.len());
+    let min_tab_dist = width + eps;
+    // tabs must leave some of the path to cut
+    let requested = if width < total - eps {
+        requested
+    } else {
+        Vec::new()
+    };
+    for &target in requested.iter() {
+        let is_valid = |c: T, forbidden: &[(T, T)], tab_centers: &[T]| {
+            forbidden
+                .iter()
+                .all(|&(s, l)| !in_open_interval(c, s, l, total, eps))
+                && tab_centers
+                    .iter()
+                    .all(|&t| cyclic_dist(c, t, total) >= min_tab_dist - eps)
+        };
+
+        // candidates are the target and all the boundaries of the forbidden intervals
+        let tab_bounds = tab_centers
+            .iter()
+            .flat_map(|&t| {
+                std::iter::once(t - min_tab_dist).chain(std::iter::once(t + min_tab_dist))
+            })
+            .collect::<Vec<_>>();
+        let best = std::iter::once(target)
+            .chain(
+                forbidden
+                    .iter()
+                    .flat_map(|&(s, l)| std::iter::once(s).chain(std::iter::once(s + l))),
+            )
+            .chain(tab_bounds)
+            .map(|c| wrap_path_length(c, total))
+            .filter(|&c| is_valid(c, &forbidden, &tab_centers))
+            .min_by(|&a, &b| {
+                cyclic_dist(a, target, total)
+                    .partial_cmp(&cyclic_dist(b, target, total))
+                    .unwrap()
+            });
+
+        if let Some(c) = best {
+            tab_centers.push(c);
+        }
+    }
+
+    tab_centers.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut slices = Vec::with_capacity(2 * tab_centers.len());
+    if tab_centers.is_empty() {
+        slices.push(TabSlice {
+            view_data: PlineViewData::from_entire_pline(pline),
+            start_path_length: T::zero(),
+            is_tab: false,
+        });
+        return Some(TabResult {
+            slices,
+            tab_centers,
+        });
+    }
+
+    let mut add_slice = |start: T, end: T, is_tab: bool| {
+        if end - start <= eps {
+            return;
+        }
+        if let Some(view_data) = path_range_view_data(pline, &lengths, start, end, eps) {
+            slices.push(TabSlice {
+                view_data,
+                start_path_length: wrap_path_length(start, total),
+                is_tab,
+            });
+        }
+    };
+
+    let count = tab_centers.len();
+    for (i, &c) in tab_centers.iter().enumerate() {
+        let tab_start = c - half_width;
+        let tab_end = c + half_width;
+        if options.raised_tabs && width > eps {
+            add_slice(tab_start, tab_end, true);
+        }
+
+        let next_tab_start = if i + 1 < count {
+            tab_centers[i + 1] - half_width
+        } else {
+            tab_centers[0] - half_width + total
+        };
+        add_slice(tab_end, next_tab_start, false);
+    }
+
+    slices.sort_unstable_by(|a, b| {
+        a.start_path_length
+            .partial_cmp(&b.start_path_length)
+            .unwrap()
+    });
+
+    Some(TabResult {
+        slices,
+        tab_centers,
+    })
+}
@@ -0,0 +1,179 @@
+use super::{
+    PlineContainmentTree, PlineCreation, PlineOffsetOptions, PlineOrientation, PlineSource,
+    Polyline,
+};
+use crate::core::traits::Real;
+
+/// Side of a closed polyline the tool cuts on, see [CompensatedLoop::side].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CutSide {
+    /// Tool cuts outside the polyline (outer boundary of a part).
+    Outside,
+    /// Tool cuts inside the polyline (hole in a part).
+    Inside,
+}
+
+/// Options for tool radius compensation with [compensate_tool_radius].
+#[derive(Debug, Clone)]
+pub struct ToolCompensationOptions<T = f64> {
+    /// Radius of the tool (or half the kerf width), polylines are offset by this distance.
+    pub tool_radius: T,
+    /// If true then self intersecting polylines are offset correctly, see
+    /// [PlineOffsetOptions::handle_self_intersects].
+    pub handle_self_intersects: bool,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> ToolCompensationOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            tool_radius: T::one(),
+            handle_self_intersects: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ToolCompensationOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compensated toolpath loop created by [compensate_tool_radius].
+#[derive(Debug, Clone)]
+pub struct CompensatedLoop<T = f64> {
+    /// Index of the input polyline the loop was offset from.
+    pub source_index: usize,
+    /// Side of the input polyline the tool cuts on.
+    pub side: CutSide,
+    /// Offset loop the tool center follows (same orientation as the input polyline).
+    pub pline: Polyline<T>,
+}
+
+/// Result of [compensate_tool_radius].
+#[derive(Debug, Clone)]
+pub struct ToolCompensationResult<T = f64> {
+    /// Compensated loops, ordered by source index. A hole may produce more than one loop if it
+    /// pinches off when offset.
+    pub loops: Vec<CompensatedLoop<T>>,
+    /// Indexes of the input polylines that vanished because the tool does not fit inside them.
+    /// Polylines that only partly vanish (the tool does not fit into some part of them) are not
+    /// listed, see [compensate_tool_radius].
+    pub vanished: Vec<usize>,
+}
+
+/// Offset a set of closed polylines by the tool radius to get the tool center paths for cutting
+/// out parts with holes.
+///
+/// Which side each polyline is cut on is inferred from nesting (using [PlineContainmentTree]):
+/// polylines at even depth are outer boundaries of parts and are cut on the outside, polylines at
+/// odd depth are holes and are cut on the inside (polylines at depth 2 are parts nested inside a
+/// hole, etc.). The offset direction is then found from the orientation of each polyline so the
+/// input orientations do not need to be consistent.
+///
+/// Holes too small for the tool vanish when offset and are reported in
+/// [ToolCompensationResult::vanished]. Open polylines and polylines with less than 2 vertexes are
+/// ignored.
+///
+/// Only features that vanish entirely are reported. If the tool does not fit into part of a
+/// polyline (e.g. a slot narrower than the tool in a hole, or a narrow notch in a part) the offset
+/// loop does not enter that part and it is left uncut without being reported. To find uncut areas
+/// offset the compensated loops back by the tool radius and compare them with the input.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let part: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let hole: Polyline = pline_closed![(2.0, 2.0, 0.0), (6.0, 2.0, 0.0), (6.0, 6.0, 0.0), (2.0, 6.0, 0.0)];
+/// let small_hole: Polyline = pline_closed![(7.0, 7.0, 0.0), (8.0, 7.0, 0.0), (8.0, 8.0, 0.0), (7.0, 8.0, 0.0)];
+/// let plines = vec![part, hole, small_hole];
+/// let options = ToolCompensationOptions {
+///     tool_radius: 1.0,
+///     ..Default::default()
+/// };
+/// let result = compensate_tool_radius(&plines, &options);
+/// assert_eq!(result.loops.len(), 2);
+/// assert_eq!(result.loops[0].side, CutSide::Outside);
+/// // square grown by 1 with rounded corners
+/// assert_fuzzy_eq!(result.loops[0].pline.area(), 144.0 + std::f64::consts::PI - 4.0);
+/// assert_eq!(result.loops[1].side, CutSide::Inside);
+/// assert_fuzzy_eq!(result.loops[1].pline.area(), 4.0);
+/// // tool does not fit inside the 1x1 hole
+/// assert_eq!(result.vanished, vec![2]);
+/// ```
+pub fn compensate_tool_radius<'a, P, I, T>(
+    plines: I,
+    options: &ToolCompensationOptions<T>,
+) -> ToolCompensationResult<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let plines = plines.into_iter().collect::<Vec<_>>();
+    let tree = PlineContainmentTree::build(plines.iter().copied(), options.pos_equal_eps);
+
+    let offset_options = PlineOffsetOptions {
+        handle_self_intersects: options.handle_self_intersects,
+        pos_equal_eps: options.pos_equal_eps,
+        ..Default::default()
+    };
+
+    let mut loops = Vec::new();
+    let mut vanished = Vec::new();
+    for (i, pline) in plines.iter().enumerate() {
+        if !pline.is_closed() || pline.vertex_count() < 2 {
+            continue;
+        }
+
+        let side = if tree.nodes[i].is_hole() {
+            CutSide::Inside
+        } else {
+            CutSide::Outside
+        };
+
+        if options.tool_radius <= options.pos_equal_eps {
+            loops.push(CompensatedLoop {
+                source_index: i,
+                side,
+                pline: Polyline::create_from(*pline),
+            });
+            continue;
+        }
+
+        // positive offset is to the left of the polyline direction which is inside for counter
+        // clockwise polylines
+        let inside_is_left = pline.orientation() != PlineOrientation::Clockwise;
+        let offset = if (side == CutSide::Inside) == inside_is_left {
+            options.tool_radius
+        } else {
+            -options.tool_radius
+        };
+
+        let results = pline.parallel_offset_opt(offset, &offset_options);
+        if results.is_empty() {
+            vanished.push(i);
+            continue;
+        }
+
+        loops.extend(results.iter().map(|p| CompensatedLoop {
+            source_index: i,
+            side,
+            pline: Polyline::create_from(p),
+        }));
+    }
+
+    ToolCompensationResult { loops, vanished }
+}
@@ -0,0 +1,336 @@
+use super::{PlineContainmentTree, PlineCreation, PlineSource, Polyline};
+use crate::core::{math::Vector2, traits::Real};
+
+/// Options for ordering toolpaths with [order_toolpaths].
+#[derive(Debug, Clone)]
+pub struct ToolpathOrderOptions<T = f64> {
+    /// Position of the tool before cutting the first toolpath.
+    pub start_point: Vector2<T>,
+    /// If true then closed polylines are rotated (using [PlineSource::rotate_start]) to start at
+    /// the point closest to the end of the previous toolpath, otherwise they start at their
+    /// existing first vertex.
+    pub optimize_start_points: bool,
+    /// If true then closed polylines contained by another closed polyline are always cut before
+    /// the polyline containing them.
+    pub inner_before_outer: bool,
+    /// Maximum number of 2-opt improvement passes run after the greedy nearest neighbor ordering,
+    /// 0 disables 2-opt improvement.
+    pub two_opt_max_passes: usize,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> ToolpathOrderOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            start_point: Vector2::zero(),
+            optimize_start_points: true,
+            inner_before_outer: true,
+            two_opt_max_passes: 10,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ToolpathOrderOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rapid travel move between two toolpaths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToolpathTravel<T = f64> {
+    /// Position the travel starts at (end of the previous toolpath or the start point).
+    pub from: Vector2<T>,
+    /// Position the travel ends at (start of the next toolpath).
+    pub to: Vector2<T>,
+}
+
+impl<T> ToolpathTravel<T>
+where
+    T: Real,
+{
+    /// Straight line length of the travel move.
+    #[inline]
+    pub fn length(&self) -> T {
+        (self.to - self.from).length()
+    }
+}
+
+/// Result of [order_toolpaths].
+#[derive(Debug, Clone)]
+pub struct ToolpathOrderResult<T = f64> {
+    /// Indexes of the input polylines in cutting order.
+    pub order: Vec<usize>,
+    /// Polylines in cutting order, closed polylines are rotated to start at their chosen start
+    /// point.
+    pub toolpaths: Vec<Polyline<T>>,
+    /// Travel moves, `travels[i]` is the travel arriving at the start of `toolpaths[i]`.
+    pub travels: Vec<ToolpathTravel<T>>,
+    /// Sum of all the travel move lengths.
+    pub total_travel_length: T,
+}
+
+/// Entry segment index and position of a toolpath when arriving from `from`.
+fn entry_point<P, T>(
+    pline: &P,
+    from: Vector2<T>,
+    options: &ToolpathOrderOptions<T>,
+) -> (usize, Vector2<T>)
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if options.optimize_start_points && pline.is_closed() {
+        if let Some(cp) = pline.closest_point(from, options.pos_equal_eps) {
+            return (cp.seg_start_index, cp.seg_point);
+        }
+    }
+
+    (0, pline.at(0).pos())
+}
+
+/// Exit position of a toolpath entered at `entry`.
+fn exit_point<P, T>(pline: &P, entry: Vector2<T>) -> Vector2<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline.is_closed() {
+        entry
+    } else {
+        pline.last().unwrap().pos()
+    }
+}
+
+/// Order toolpaths to minimize rapid travel between them.
+///
+/// A greedy nearest neighbor ordering is built starting from [ToolpathOrderOptions::start_point]
+/// then improved with 2-opt (reversing runs of the order when it shortens the total travel). If
+/// [ToolpathOrderOptions::inner_before_outer] is true then the containment precedence (found
+/// with [PlineContainmentTree]) is respected throughout: a closed polyline is only cut after all
+/// the closed polylines it contains.
+///
+/// Closed polylines enter (and exit) at the point closest to the previous position when
+/// [ToolpathOrderOptions::optimize_start_points] is true, open polylines are always cut from
+/// their first vertex to their last vertex. Polylines with no vertexes are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::pline_closed;
+/// let outer: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let inner: Polyline = pline_closed![(2.0, 2.0, 0.0), (8.0, 2.0, 0.0), (8.0, 8.0, 0.0), (2.0, 8.0, 0.0)];
+/// let far: Polyline = pline_closed![(20.0, 0.0, 0.0), (22.0, 0.0, 0.0), (22.0, 2.0, 0.0), (20.0, 2.0, 0.0)];
+/// let plines = vec![outer, far, inner];
+/// let result = order_toolpaths(&plines, &ToolpathOrderOptions::default());
+/// // inner loop cut before the outer loop containing it
+/// assert_eq!(result.order, vec![2, 0, 1]);
+/// assert!(result.toolpaths[0][0].pos().fuzzy_eq(Vector2::new(2.0, 2.0)));
+/// assert!(result.travels[2].to.fuzzy_eq(Vector2::new(20.0, 0.0)));
+/// ```
+pub fn order_toolpaths<'a, P, I, T>(
+    plines: I,
+    options: &ToolpathOrderOptions<T>,
+) -> ToolpathOrderResult<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let plines = plines.into_iter().collect::<Vec<_>>();
+    let count = plines.len();
+
+    // parent of each polyline that must be cut after it
+    let parents = if options.inner_before_outer {
+        let tree = PlineContainmentTree::build(plines.iter().copied(), eps);
+        tree.nodes.iter().map(|n| n.parent).collect::<Vec<_>>()
+    } else {
+        vec![None; count]
+    };
+
+    let mut remaining_children = vec![0usize; count];
+    for p in parents.iter().flatten() {
+        remaining_children[*p] += 1;
+    }
+
+    // greedy nearest neighbor
+    let mut order = Vec::with_capacity(count);
+    let mut entries = vec![Vector2::zero(); count];
+    let mut is_done = plines
+        .iter()
+        .map(|p| p.vertex_count() == 0)
+        .collect::<Vec<_>>();
+    let mut current = options.start_point;
+    loop {
+        let mut best: Option<(usize, T, Vector2<T>)> = None;
+        for (i, pline) in plines.iter().enumerate() {
+            if is_done[i] || remaining_children[i] != 0 {
+                continue;
+            }
+            let (_, entry) = entry_point(*pline, current, options);
+            let dist = (entry - current).length();
+            if best.map_or(true, |(_, best_dist, _)| dist < best_dist) {
+                best = Some((i, dist, entry));
+            }
+        }
+
+        let (i, _, entry) = match best {
+            Some(b) => b,
+            None => break,
+        };
+
+        is_done[i] = true;
+        if let Some(p) = parents[i] {
+            remaining_children[p] -= 1;
+        }
+        entries[i] = entry;
+        order.push(i);
+        current = exit_point(plines[i], entry);
+    }
+
+    // 2-opt improvement with entry points held fixed
+    let travel_cost = |from: Option<usize>, to: usize| {
+        let from_pos = match from {
+            Some(f) => exit_point(plines[f], entries[f]),
+            None => options.start_point,
+        };
+        (entries[to] - from_pos).length()
+    };
+
+    // prefix sums of the travel costs along the order going forward and with each travel
+    // reversed, so the cost of a reversed run is found in constant time
+    let prefix_costs = |order: &[usize], forward: &mut Vec<T>, backward: &mut Vec<T>| {
+        forward.clear();
+        backward.clear();
+        forward.push(T::zero());
+        backward.push(T::zero());
+        for w in order.windows(2) {
+            let f = forward[forward.len() - 1] + travel_cost(Some(w[0]), w[1]);
+            let b = backward[backward.len() - 1] + travel_cost(Some(w[1]), w[0]);
+            forward.push(f);
+            backward.push(b);
+        }
+    };
+
+    let mut children = vec![Vec::new(); count];
+    for (i, p) in parents.iter().enumerate() {
+        if let Some(p) = *p {
+            children[p].push(i);
+        }
+    }
+
+    let n = order.len();
+    let mut position = vec![0usize; count];
+    // position of the parent of the polyline at each position in the order (usize::MAX if none),
+    // always after the position since children are cut first
+    let mut parent_position = vec![usize::MAX; n];
+    let mut forward = Vec::with_capacity(n);
+    let mut backward = Vec::with_capacity(n);
+    for _ in 0..options.two_opt_max_passes {
+        let mut improved = false;
+        for (pos, &idx) in order.iter().enumerate() {
+            position[idx] = pos;
+        }
+        for (pos, &idx) in order.iter().enumerate() {
+            parent_position[pos] = parents[idx].map_or(usize::MAX, |p| position[p]);
+        }
+        prefix_costs(&order, &mut forward, &mut backward);
+
+        for i in 0..n {
+            // minimum parent position in the run i..=k, reversing the run breaks the precedence
+            // if any parent is within the run (and for all longer runs)
+            let mut min_parent_position = usize::MAX;
+            for k in i..n {
+                min_parent_position = min_parent_position.min(parent_position[k]);
+                if min_parent_position <= k {
+                    break;
+                }
+                if k == i {
+                    continue;
+                }
+
+                let before = if i == 0 { None } else { Some(order[i - 1]) };
+                let after = order.get(k + 1).copied();
+
+                let mut old_cost = travel_cost(before, order[i]) + (forward[k] - forward[i]);
+                let mut new_cost = travel_cost(before, order[k]) + (backward[k] - backward[i]);
+                if let Some(a) = after {
+                    old_cost = old_cost + travel_cost(Some(order[k]), a);
+                    new_cost = new_cost + travel_cost(Some(order[i]), a);
+                }
+
+                if new_cost < old_cost - eps {
+                    order[i..=k].reverse();
+                    for pos in i..=k {
+                        position[order[pos]] = pos;
+                    }
+                    for pos in i..=k {
+                        let idx = order[pos];
+                        parent_position[pos] = parents[idx].map_or(usize::MAX, |p| position[p]);
+                        for &c in children[idx].iter() {
+                            parent_position[position[c]] = pos;
+                        }
+                    }
+                    min_parent_position = parent_position[i..=k]
+                        .iter()
+                        .copied()
+                        .min()
+                        .unwrap_or(usize::MAX);
+                    prefix_costs(&order, &mut forward, &mut backward);
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    // final pass choosing start points along the final order and building the result
+    let mut toolpaths = Vec::with_capacity(n);
+    let mut travels = Vec::with_capacity(n);
+    let mut total_travel_length = T::zero();
+    let mut current = options.start_point;
+    for &i in order.iter() {
+        let pline = plines[i];
+        let (seg_index, entry) = entry_point(pline, current, options);
+        let toolpath = if pline.is_closed() {
+            match pline.rotate_start(seg_index, entry, eps) {
+                Some(rotated) => Polyline::create_from(&rotated),
+                None => Polyline::create_from(pline),
+            }
+        } else {
+            Polyline::create_from(pline)
+        };
+
+        let travel = ToolpathTravel {
+            from: current,
+            to: toolpath.at(0).pos(),
+        };
+        total_travel_length = total_travel_length + travel.length();
+        travels.push(travel);
+        current = exit_point(&toolpath, travel.to);
+        toolpaths.push(toolpath);
+    }
+
+    ToolpathOrderResult {
+        order,
+        toolpaths,
+        travels,
+        total_travel_length,
+    }
+}
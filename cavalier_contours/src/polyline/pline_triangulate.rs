@@ -0,0 +1,560 @@
+use super::{PlineContainmentTree, PlineCreation, PlineSource, Polyline};
+use crate::core::{math::Vector2, traits::Real};
+use std::collections::HashMap;
+
+/// Options for triangulating closed polylines with [triangulate].
+#[derive(Debug, Clone)]
+pub struct TriangulateOptions<T = f64> {
+    /// Maximum distance between an arc and the line segments approximating it (see
+    /// [PlineSource::arcs_to_approx_lines]).
+    pub arc_error_distance: T,
+    /// Maximum length of any triangle edge, if `Some` then boundary edges are subdivided and
+    /// triangles are refined by bisecting their longest edge until all edges are no longer than
+    /// this length.
+    pub max_edge_length: Option<T>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> TriangulateOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            arc_error_distance: T::from(1e-2).unwrap(),
+            max_edge_length: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for TriangulateOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Indexed triangle mesh created by [triangulate].
+#[derive(Debug, Clone, Default)]
+pub struct TriangleMesh<T = f64> {
+    /// Vertex positions.
+    pub vertexes: Vec<Vector2<T>>,
+    /// Triangles as indexes into [TriangleMesh::vertexes], all triangles are counter clockwise.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<T> TriangleMesh<T>
+where
+    T: Real,
+{
+    /// Total area of all the triangles.
+    pub fn area(&self) -> T {
+        self.triangles.iter().fold(T::zero(), |acc, t| {
+            let a = self.vertexes[t[0]];
+            acc + (self.vertexes[t[1]] - a).perp_dot(self.vertexes[t[2]] - a) / T::two()
+        })
+    }
+
+    /// Flat triangle index buffer (3 indexes per triangle).
+    pub fn index_buffer(&self) -> Vec<usize> {
+        self.triangles
+            .iter()
+            .flat_map(|t| t.iter().copied())
+            .collect()
+    }
+}
+
+#[inline]
+fn orient<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> T
+where
+    T: Real,
+{
+    (b - a).perp_dot(c - a)
+}
+
+/// Returns true if `a`, `b`, `c` form a counter clockwise triangle with `b` further than `eps`
+/// from the line through `a` and `c`.
+#[inline]
+fn is_convex<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>, eps: T) -> bool
+where
+    T: Real,
+{
+    orient(a, b, c) > eps * (c - a).length()
+}
+
+/// Circular doubly linked polygon ring used for ear clipping, nodes reference mesh vertexes
+/// (hole bridges duplicate nodes but share vertexes).
+struct Ring {
+    vertex: Vec<usize>,
+    prev: Vec<usize>,
+    next: Vec<usize>,
+}
+
+impl Ring {
+    fn new() -> Self {
+        Self {
+            vertex: Vec::new(),
+            prev: Vec::new(),
+            next: Vec::new(),
+        }
+    }
+
+    /// Add a closed loop of vertexes as a new ring, returns the first node.
+    fn add_loop(&mut self, vertexes: std::ops::Range<usize>) -> usize {
+        let start = self.vertex.len();
+        let count = vertexes.len();
+        for (i, v) in vertexes.enumerate() {
+            self.vertex.push(v);
+            self.prev.push(start + (i + count - 1) % count);
+            self.next.push(start + (i + 1) % count);
+        }
+        start
+    }
+
+    fn duplicate(&mut self, node: usize) -> usize {
+        self.vertex.push(self.vertex[node]);
+        self.prev.push(node);
+        self.next.push(node);
+        self.vertex.len() - 1
+    }
+
+    fn remove(&mut self, node: usize) {
+        let p = self.prev[node];
+        let n = self.next[node];
+        self.next[p] = n;
+        self.prev[n] = p;
+    }
+}
+
+/// Join the hole ring starting at `hole_node` into the ring containing `outer_node` with a
+/// bridge edge.
+fn bridge_hole<T>(ring: &mut Ring, points: &[Vector2<T>], outer_node: usize, hole_node: usize)
+where
+    T: Real,
+{
+    let pos = |node: usize| points[ring.vertex[node]];
+
+    // rightmost vertex of the hole
+    let mut m = hole_node;
+    let mut node = ring.next[hole_node];
+    while node != hole_node {
+        if pos(node).x > pos(m).x {
+            m = node;
+        }
+        node = ring.next[node];
+    }
+    let mp = pos(m);
+
+    // cast ray in +x direction from the hole vertex and find the closest edge hit
+    let mut hit_x = <T as Real>::max_value();
+    let mut bridge = None;
+    let mut p = outer_node;
+    loop {
+        let q = ring.next[p];
+        let (pp, qp) = (pos(p), pos(q));
+        if (pp.y <= mp.y && qp.y > mp.y) || (qp.y <= mp.y && pp.y > mp.y) {
+            let x = pp.x + (mp.y - pp.y) * (qp.x - pp.x) / (qp.y - pp.y);
+            if x >= mp.x && x < hit_x {
+                hit_x = x;
+                bridge = Some(if pp.x > qp.x { p } else { q });
+            }
+        }
+        p = q;
+        if p == outer_node {
+            break;
+        }
+    }
+
+    let mut bridge = match bridge {
+        Some(b) => b,
+        None => return,
+    };
+
+    // edge end point may not be visible if other vertexes lie inside the triangle formed by the
+    // hole vertex, ray hit, and edge end point, choose the one with the smallest angle to the ray
+    let hit = Vector2::new(hit_x, mp.y);
+    let bp = pos(bridge);
+    if !bp.fuzzy_eq(hit) {
+        let (t0, t1, t2) = if bp.y < mp.y {
+            (mp, bp, hit)
+        } else {
+            (mp, hit, bp)
+        };
+        let mut best_tan = <T as Real>::max_value();
+        let mut node = outer_node;
+        loop {
+            let np = pos(node);
+            let inside = orient(t0, t1, np) >= T::zero()
+                && orient(t1, t2, np) >= T::zero()
+                && orient(t2, t0, np) >= T::zero();
+            if node != bridge && inside && np.x > mp.x && !np.fuzzy_eq(mp) {
+                let tan = (np.y - mp.y).abs() / (np.x - mp.x);
+                if tan < best_tan || (tan == best_tan && np.x < pos(bridge).x) {
+                    best_tan = tan;
+                    bridge = node;
+                }
+            }
+            node = ring.next[node];
+            if node == outer_node {
+                break;
+            }
+        }
+    }
+
+    // splice: bridge -> m -> ...hole... -> m2 -> bridge2 -> next of bridge
+    let bridge2 = ring.duplicate(bridge);
+    let m2 = ring.duplicate(m);
+    let bridge_next = ring.next[bridge];
+    let m_prev = ring.prev[m];
+
+    ring.next[bridge] = m;
+    ring.prev[m] = bridge;
+
+    ring.next[bridge2] = bridge_next;
+    ring.prev[bridge_next] = bridge2;
+
+    ring.next[m2] = bridge2;
+    ring.prev[bridge2] = m2;
+
+    ring.next[m_prev] = m2;
+    ring.prev[m2] = m_prev;
+}
+
+/// Ear clip the ring containing `start` appending triangles to `triangles`.
+fn ear_clip<T>(
+    ring: &mut Ring,
+    points: &[Vector2<T>],
+    start: usize,
+    triangles: &mut Vec<[usize; 3]>,
+    eps: T,
+) where
+    T: Real,
+{
+    let pos = |ring: &Ring, node: usize| points[ring.vertex[node]];
+
+    let mut remaining = 1;
+    let mut node = ring.next[start];
+    while node != start {
+        remaining += 1;
+        node = ring.next[node];
+    }
+
+    let is_ear = |ring: &Ring, b: usize| {
+        let a = ring.prev[b];
+        let c = ring.next[b];
+        let (pa, pb, pc) = (pos(ring, a), pos(ring, b), pos(ring, c));
+        if !is_convex(pa, pb, pc, eps) {
+            return false;
+        }
+
+        let mut node = ring.next[c];
+        while node != a {
+            let p = pos(ring, node);
+            // node coincident with a corner (from a hole bridge or touching loops), not an ear if
+            // either of its edges goes into the triangle through the corner
+            let corner_edges = if p.fuzzy_eq_eps(pa, eps) {
+                Some(((pa, pb), (pc, pa)))
+            } else if p.fuzzy_eq_eps(pb, eps) {
+                Some(((pa, pb), (pb, pc)))
+            } else if p.fuzzy_eq_eps(pc, eps) {
+                Some(((pb, pc), (pc, pa)))
+            } else {
+                None
+            };
+
+            match corner_edges {
+                Some(((e1s, e1e), (e2s, e2e))) => {
+                    for &n in [ring.prev[node], ring.next[node]].iter() {
+                        let np = pos(ring, n);
+                        if orient(e1s, e1e, np) > T::zero() && orient(e2s, e2e, np) > T::zero() {
+                            return false;
+                        }
+                    }
+                }
+                None => {
+                    // points within eps of the triangle edges are considered inside
+                    let left =
+                        |s: Vector2<T>, e: Vector2<T>| orient(s, e, p) >= -eps * (e - s).length();
+                    if left(pa, pb) && left(pb, pc) && left(pc, pa) {
+                        return false;
+                    }
+                }
+            }
+            node = ring.next[node];
+        }
+
+        true
+    };
+
+    let mut ear = start;
+    let mut stop = start;
+    while remaining > 3 {
+        if is_ear(ring, ear) {
+            let a = ring.prev[ear];
+            let c = ring.next[ear];
+            triangles.push([ring.vertex[a], ring.vertex[ear], ring.vertex[c]]);
+            ring.remove(ear);
+            remaining -= 1;
+            ear = c;
+            stop = c;
+            continue;
+        }
+
+        ear = ring.next[ear];
+        if ear == stop {
+            // no ear found (degenerate or numerically inconsistent input), remove the vertex
+            // forming the smallest area to guarantee progress
+            let mut best = ear;
+            let mut best_area = <T as Real>::max_value();
+            let mut node = ring.next[ear];
+            loop {
+                let area = orient(
+                    pos(ring, ring.prev[node]),
+                    pos(ring, node),
+                    pos(ring, ring.next[node]),
+                )
+                .abs();
+                if area < best_area {
+                    best_area = area;
+                    best = node;
+                }
+                if node == ear {
+                    break;
+                }
+                node = ring.next[node];
+            }
+            let a = ring.prev[best];
+            let c = ring.next[best];
+            if is_convex(pos(ring, a), pos(ring, best), pos(ring, c), eps) {
+                triangles.push([ring.vertex[a], ring.vertex[best], ring.vertex[c]]);
+            }
+            ring.remove(best);
+            remaining -= 1;
+            ear = c;
+            stop = c;
+        }
+    }
+
+    if remaining == 3 {
+        let a = ring.prev[ear];
+        let c = ring.next[ear];
+        if is_convex(pos(ring, a), pos(ring, ear), pos(ring, c), eps) {
+            triangles.push([ring.vertex[a], ring.vertex[ear], ring.vertex[c]]);
+        }
+    }
+}
+
+/// Refine triangles by longest edge bisection until all edges are no longer than `max_length`.
+///
+/// Triangles are only split across an edge which is the longest edge of all triangles sharing it
+/// (the longest edge propagation path is followed to find it) so the mesh stays conforming and
+/// triangle quality does not degrade.
+fn refine<T>(mesh: &mut TriangleMesh<T>, max_length: T)
+where
+    T: Real,
+{
+    let max_length2 = max_length * max_length;
+    let edge_key = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+
+    let mut edge_tris: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (i, t) in mesh.triangles.iter().enumerate() {
+        for k in 0..3 {
+            edge_tris
+                .entry(edge_key(t[k], t[(k + 1) % 3]))
+                .or_default()
+                .push(i);
+        }
+    }
+
+    // longest edge of a triangle and its squared length (ties broken by edge key so the choice is
+    // consistent between triangles)
+    let longest_edge = |mesh: &TriangleMesh<T>, ti: usize| {
+        let t = mesh.triangles[ti];
+        (0..3)
+            .map(|k| {
+                let key = edge_key(t[k], t[(k + 1) % 3]);
+                let length2 = (mesh.vertexes[key.1] - mesh.vertexes[key.0]).length_squared();
+                (key, length2)
+            })
+            .fold(None, |acc: Option<((usize, usize), T)>, x| match acc {
+                Some(a) if a.1 > x.1 || (a.1 == x.1 && a.0 > x.0) => Some(a),
+                _ => Some(x),
+            })
+            .unwrap()
+    };
+
+    let mut stack = (0..mesh.triangles.len()).collect::<Vec<_>>();
+    while let Some(ti) = stack.pop() {
+        if longest_edge(mesh, ti).1 <= max_length2 {
+            continue;
+        }
+
+        // follow longest edge propagation path until reaching an edge which is the longest edge
+        // of all triangles sharing it
+        let mut current = ti;
+        let (a, b) = loop {
+            let (key, _) = longest_edge(mesh, current);
+            let neighbor = edge_tris[&key].iter().copied().find(|&n| n != current);
+            match neighbor {
+                Some(n) if longest_edge(mesh, n).0 != key => current = n,
+                _ => break key,
+            }
+        };
+
+        let mid = mesh.vertexes.len();
+        mesh.vertexes
+            .push((mesh.vertexes[a] + mesh.vertexes[b]).scale(T::one() / T::two()));
+
+        let shared = edge_tris.remove(&(a, b)).unwrap_or_default();
+        for si in shared {
+            let st = mesh.triangles[si];
+            // rotate so the split edge is p -> q
+            let r = (0..3)
+                .find(|&r| edge_key(st[r], st[(r + 1) % 3]) == (a, b))
+                .unwrap();
+            let (p, q, o) = (st[r], st[(r + 1) % 3], st[(r + 2) % 3]);
+
+            let new_index = mesh.triangles.len();
+            mesh.triangles[si] = [p, mid, o];
+            mesh.triangles.push([mid, q, o]);
+
+            if let Some(tris) = edge_tris.get_mut(&edge_key(q, o)) {
+                for x in tris.iter_mut() {
+                    if *x == si {
+                        *x = new_index;
+                    }
+                }
+            }
+            edge_tris.entry(edge_key(p, mid)).or_default().push(si);
+            edge_tris
+                .entry(edge_key(mid, q))
+                .or_default()
+                .push(new_index);
+            let center_edge = edge_tris.entry(edge_key(mid, o)).or_default();
+            center_edge.push(si);
+            center_edge.push(new_index);
+
+            stack.push(si);
+            stack.push(new_index);
+        }
+
+        // triangle may not have been split if the path led elsewhere
+        stack.push(ti);
+    }
+}
+
+/// Triangulate the regions defined by a set of closed polylines.
+///
+/// Nesting is determined with [PlineContainmentTree] so the input orientations do not need to be
+/// consistent: polylines at even depth are outer boundaries and polylines at odd depth are holes
+/// (islands inside holes are triangulated as separate regions). Arcs are converted to lines
+/// using [PlineSource::arcs_to_approx_lines] with [TriangulateOptions::arc_error_distance], holes
+/// are joined to their outer boundary by bridge edges and the resulting polygon is triangulated
+/// by ear clipping. If [TriangulateOptions::max_edge_length] is set then triangles are refined
+/// until no edge is longer than the length given.
+///
+/// Open polylines and polylines with less than 3 vertexes after converting arcs are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::{assert_fuzzy_eq, pline_closed};
+/// let outer: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let hole: Polyline = pline_closed![(4.0, 4.0, 0.0), (6.0, 4.0, 0.0), (6.0, 6.0, 0.0), (4.0, 6.0, 0.0)];
+/// let mesh = triangulate(&[outer, hole], &TriangulateOptions::default());
+/// assert_eq!(mesh.vertexes.len(), 8);
+/// assert_eq!(mesh.triangles.len(), 8);
+/// assert_fuzzy_eq!(mesh.area(), 96.0);
+/// ```
+pub fn triangulate<'a, P, I, T>(plines: I, options: &TriangulateOptions<T>) -> TriangleMesh<T>
+where
+    P: PlineSource<Num = T> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a P>,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let max_edge_length = options.max_edge_length.filter(|&l| l > eps);
+
+    // convert arcs to lines
+    let mut loops = plines
+        .into_iter()
+        .filter(|p| p.is_closed())
+        .filter_map(|p| p.arcs_to_approx_lines(options.arc_error_distance))
+        .map(|p| match p.remove_repeat_pos(eps) {
+            Some(r) => Polyline::create_from(&r),
+            None => Polyline::create_from(&p),
+        })
+        .filter(|p: &Polyline<T>| p.vertex_count() >= 3 && p.area().abs() > eps)
+        .collect::<Vec<_>>();
+
+    let tree = PlineContainmentTree::build(&loops, eps);
+    tree.fix_orientations(&mut loops);
+
+    let mut mesh = TriangleMesh {
+        vertexes: Vec::new(),
+        triangles: Vec::new(),
+    };
+
+    // add loop vertexes to the mesh (subdividing long edges) and returns the vertex range
+    let add_loop = |mesh: &mut TriangleMesh<T>, pline: &Polyline<T>| {
+        let start = mesh.vertexes.len();
+        for (v1, v2) in pline.iter_segments() {
+            let p1 = v1.pos();
+            mesh.vertexes.push(p1);
+            if let Some(max_length) = max_edge_length {
+                let length = (v2.pos() - p1).length();
+                let count = num_traits::cast::<T, usize>((length / max_length).ceil()).unwrap_or(1);
+                for j in 1..count {
+                    let t = T::from(j).unwrap() / T::from(count).unwrap();
+                    mesh.vertexes.push(p1 + (v2.pos() - p1).scale(t));
+                }
+            }
+        }
+        start..mesh.vertexes.len()
+    };
+
+    for (outer, holes) in tree.outer_with_holes() {
+        let mut ring = Ring::new();
+        let range = add_loop(&mut mesh, &loops[outer]);
+        let outer_node = ring.add_loop(range);
+
+        // join holes from rightmost to leftmost so bridges do not cross
+        let mut hole_nodes = holes
+            .iter()
+            .map(|&h| {
+                let range = add_loop(&mut mesh, &loops[h]);
+                let max_x = mesh.vertexes[range.clone()]
+                    .iter()
+                    .fold(<T as Real>::min_value(), |acc, p| {
+                        num_traits::real::Real::max(acc, p.x)
+                    });
+                (ring.add_loop(range), max_x)
+            })
+            .collect::<Vec<_>>();
+        hole_nodes.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        for (hole_node, _) in hole_nodes {
+            bridge_hole(&mut ring, &mesh.vertexes, outer_node, hole_node);
+        }
+
+        ear_clip(
+            &mut ring,
+            &mesh.vertexes,
+            outer_node,
+            &mut mesh.triangles,
+            eps,
+        );
+    }
+
+    if let Some(max_length) = max_edge_length {
+        refine(&mut mesh, max_length);
+    }
+
+    mesh
+}
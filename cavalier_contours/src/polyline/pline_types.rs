@@ -24,7 +24,18 @@ pub enum PlineOrientation {
     CounterClockwise,
 }
 
-/// Result from calling [PlineSource::closest_point].
+/// Represents which side of a polyline a point lies on relative to the polyline direction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlineSide {
+    /// Point lies to the left of the polyline direction of travel.
+    Left,
+    /// Point lies to the right of the polyline direction of travel.
+    Right,
+    /// Point lies on the polyline (or the polyline has no segments to define a direction).
+    Coincident,
+}
+
+/// Result from calling [PlineSource::closest_point] or [PlineSource::closest_point_opt].
 #[derive(Debug, Copy, Clone)]
 pub struct ClosestPointResult<T = f64>
 where
@@ -36,6 +47,242 @@ where
     pub seg_point: Vector2<T>,
     /// The distance between the points.
     pub distance: T,
+    /// Path length along the polyline from its start to `seg_point`.
+    pub path_length: T,
+    /// Normalized parametric value (from 0 to 1) along the closest segment where `seg_point` lies,
+    /// this is the fraction of the segment path length traversed to reach `seg_point`.
+    pub seg_parametric: T,
+    /// Which side of the polyline the point lies on relative to the polyline direction.
+    pub side: PlineSide,
+}
+
+/// Struct to hold options parameters when finding the closest point on a polyline.
+#[derive(Debug, Clone)]
+pub struct PlineClosestPointOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index of all the polyline segment bounding boxes (or boxes no smaller, e.g. using
+    /// [PlineSource::create_approx_aabb_index] is valid). If `None` is given then all the polyline
+    /// segments are scanned linearly.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Path length from the start of the polyline to each vertex, with the total path length as
+    /// the last entry (e.g. using
+    /// [vertex_path_lengths](crate::polyline::internal::pline_path_length::vertex_path_lengths)).
+    /// If `None` is given then the path length up to the closest segment is summed segment by
+    /// segment, which is linear in the number of segments even when a spatial index is given.
+    pub vertex_path_lengths: Option<&'a [T]>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> PlineClosestPointOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            vertex_path_lengths: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineClosestPointOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result from calling [PlineSource::distance_to] or [PlineSource::distance_to_opt].
+#[derive(Debug, Copy, Clone)]
+pub struct PlineDistanceResult<T = f64>
+where
+    T: Real,
+{
+    /// The minimum distance between the polylines (zero if the polylines intersect).
+    pub distance: T,
+    /// Start vertex index of the segment on the first polyline (`self`) the closest point lies on.
+    pub seg_start_index1: usize,
+    /// Closest point on the first polyline (`self`).
+    pub point1: Vector2<T>,
+    /// Start vertex index of the segment on the second polyline (`other`) the closest point lies
+    /// on.
+    pub seg_start_index2: usize,
+    /// Closest point on the second polyline (`other`).
+    pub point2: Vector2<T>,
+}
+
+/// Struct to hold options parameters when finding the minimum distance between two polylines.
+#[derive(Debug, Clone)]
+pub struct PlineDistanceOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for `self` or first polyline argument. If `None` is given then it will be
+    /// computed internally.
+    pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Spatial index for `other` or second polyline argument. If `None` is given then it will be
+    /// computed internally.
+    pub pline2_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> PlineDistanceOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pline1_aabb_index: None,
+            pline2_aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineDistanceOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result from calling [PlineSource::hausdorff_distance] or
+/// [PlineSource::directed_hausdorff_distance] (and their `_opt` variants).
+#[derive(Debug, Copy, Clone)]
+pub struct PlineHausdorffResult<T = f64>
+where
+    T: Real,
+{
+    /// The Hausdorff distance found.
+    pub distance: T,
+    /// Point on the first polyline (`self`) of the pair of points realizing the distance.
+    pub point1: Vector2<T>,
+    /// Point on the second polyline (`other`) of the pair of points realizing the distance.
+    pub point2: Vector2<T>,
+}
+
+/// Struct to hold options parameters when computing the Hausdorff distance between two polylines.
+#[derive(Debug, Clone)]
+pub struct PlineHausdorffOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for `self` or first polyline argument. If `None` is given then it will be
+    /// computed internally (if required).
+    pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Spatial index for `other` or second polyline argument. If `None` is given then it will be
+    /// computed internally.
+    pub pline2_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Maximum error allowed in the distance computed, the distance returned is never greater than
+    /// the true distance and never less than the true distance minus this tolerance. Must be
+    /// finite and greater than zero (otherwise no result is returned).
+    pub tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> PlineHausdorffOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pline1_aabb_index: None,
+            pline2_aabb_index: None,
+            tolerance: T::from(1e-4).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineHausdorffOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Describes how a ray or line crosses a polyline at a hit, see [PlineRayHit].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PlineRayCrossing {
+    /// Ray enters the polyline region (for closed polylines the interior, for open polylines the
+    /// left side of the polyline direction).
+    Entering,
+    /// Ray exits the polyline region (for closed polylines the interior, for open polylines the
+    /// left side of the polyline direction).
+    Exiting,
+    /// Ray touches the polyline without crossing it (tangent to an arc, passing through a vertex
+    /// without crossing, running along a segment, or at the end point of an open polyline).
+    Touching,
+}
+
+/// Hit found when intersecting a ray or line with a polyline, see [PlineSource::ray_intersects]
+/// and [PlineSource::line_intersects].
+#[derive(Debug, Copy, Clone)]
+pub struct PlineRayHit<T = f64> {
+    /// Starting vertex index of the segment hit. If the hit is at a vertex then this is the index
+    /// of the vertex (segment starting at the vertex) unless it is the last vertex of an open
+    /// polyline in which case it is the index of the last segment.
+    pub seg_start_index: usize,
+    /// Point of the hit.
+    pub point: Vector2<T>,
+    /// Signed distance along the ray or line direction from the ray origin (or first line point)
+    /// to the hit point.
+    pub distance: T,
+    /// How the ray crosses the polyline at the hit.
+    pub crossing: PlineRayCrossing,
+}
+
+/// Struct to hold options parameters when intersecting a ray or line with a polyline.
+#[derive(Debug, Clone)]
+pub struct PlineRayOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for the polyline. If `None` is given then it will be computed internally.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> PlineRayOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineRayOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Struct to hold options parameters when performing polyline offset.
@@ -250,6 +497,74 @@ where
     }
 }
 
+/// Spatial relationship between two closed polylines, see [PlineSource::relate].
+///
+/// Relationships are in terms of the closed regions (interior plus boundary) enclosed by the
+/// polylines, similar to a reduced form of the DE-9IM model.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PlineRelation {
+    /// Input was not valid to determine relationship (either polyline is open or has less than 2
+    /// vertexes).
+    InvalidInput,
+    /// Polylines do not share any points.
+    Disjoint,
+    /// Polylines share boundary points but their interiors do not intersect.
+    Touches,
+    /// Interiors intersect but neither polyline is contained by the other.
+    Overlaps,
+    /// Pline1 (`self`) is inside of pline2 (`other`), boundaries may touch or overlap.
+    Within,
+    /// Pline2 (`other`) is inside of pline1 (`self`), boundaries may touch or overlap.
+    Contains,
+    /// Both polylines have the same geometric path (boundaries completely overlap).
+    Equal,
+}
+
+impl PlineRelation {
+    /// Returns true if the polylines share any points (not [PlineRelation::Disjoint] or
+    /// [PlineRelation::InvalidInput]).
+    #[inline]
+    pub fn intersects(&self) -> bool {
+        !matches!(self, PlineRelation::Disjoint | PlineRelation::InvalidInput)
+    }
+}
+
+/// Struct to hold options parameters when determining the relationship between two polylines.
+#[derive(Debug, Clone)]
+pub struct PlineRelateOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for `self` or first polyline argument. If `None` is given then it will be
+    /// computed internally.
+    pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<'a, T> PlineRelateOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pline1_aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<'a, T> Default for PlineRelateOptions<'a, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Enum to control which self intersects to include.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SelfIntersectsInclude {
@@ -296,7 +611,6 @@ where
     T: Real,
 {
     #[inline]
-
     fn default() -> Self {
         Self::new()
     }
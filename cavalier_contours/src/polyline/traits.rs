@@ -1,11 +1,9 @@
+use static_aabb2d_index as aabb_index;
 use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder, AABB};
 
 use crate::{
     core::{
-        math::{
-            angle, angle_from_bulge, bulge_from_angle, delta_angle, dist_squared, is_left,
-            is_left_or_equal, point_on_circle, Vector2,
-        },
+        math::{angle, angle_from_bulge, bulge_from_angle, delta_angle, point_on_circle, Vector2},
         traits::{ControlFlow, FuzzyEq, FuzzyOrd, Real},
     },
     polyline::{seg_arc_radius_and_center, SelfIntersectsInclude},
@@ -15,15 +13,25 @@ use super::{
     arc_seg_bounding_box,
     internal::{
         pline_boolean::polyline_boolean,
+        pline_canonical::{canonical_form, geometric_eq},
+        pline_distance::{
+            directed_hausdorff_distance, discrete_frechet_distance, find_min_distance,
+            hausdorff_distance,
+        },
         pline_intersects::{
             find_intersects, visit_global_self_intersects, visit_local_self_intersects,
         },
         pline_offset::parallel_offset,
+        pline_ray::{line_intersects, ray_intersects},
+        pline_relate::relate,
     },
     seg_bounding_box, seg_closest_point, seg_fast_approx_bounding_box, seg_length,
-    seg_split_at_point, BooleanOp, BooleanResult, ClosestPointResult, FindIntersectsOptions,
-    PlineBooleanOptions, PlineIntersectVisitor, PlineIntersectsCollection, PlineOffsetOptions,
-    PlineOrientation, PlineSelfIntersectOptions, PlineVertex,
+    seg_point_at_parametric, seg_split_at_point, seg_tangent_vector, seg_winding_number, BooleanOp,
+    BooleanResult, ClosestPointResult, FindIntersectsOptions, PlineBooleanOptions,
+    PlineClosestPointOptions, PlineDistanceOptions, PlineDistanceResult, PlineHausdorffOptions,
+    PlineHausdorffResult, PlineIntersectVisitor, PlineIntersectsCollection, PlineOffsetOptions,
+    PlineOrientation, PlineRayHit, PlineRayOptions, PlineRelateOptions, PlineRelation,
+    PlineSelfIntersectOptions, PlineSide, PlineVertex,
 };
 use num_traits::cast::NumCast;
 use num_traits::One;
@@ -103,6 +111,51 @@ pub trait PlineSource {
         self.fuzzy_eq_eps(other, Self::Num::fuzzy_epsilon())
     }
 
+    /// Geometric compare with another polyline using `eps` epsilon value for fuzzy comparisons.
+    ///
+    /// Unlike [PlineSource::fuzzy_eq_eps] this ignores redundant vertexes (see
+    /// [PlineSource::remove_redundant]), direction, and for closed polylines which vertex is the
+    /// start. Returns true if both polylines trace out the same path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (2.0, 1.0, 0.0),
+    ///     (0.0, 1.0, 0.0),
+    /// ];
+    /// // same rectangle starting at a different vertex, going clockwise, with a redundant vertex
+    /// let other = pline_closed![
+    ///     (2.0, 1.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (1.0, 0.0, 0.0),
+    ///     (0.0, 0.0, 0.0),
+    ///     (0.0, 1.0, 0.0),
+    /// ];
+    /// assert!(!rectangle.fuzzy_eq(&other));
+    /// assert!(rectangle.geometric_eq_eps(&other, 1e-5));
+    /// ```
+    #[inline]
+    fn geometric_eq_eps<P>(&self, other: &P, eps: Self::Num) -> bool
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        geometric_eq(self, other, eps)
+    }
+
+    /// Same as [PlineSource::geometric_eq_eps] but uses default `Self::Num::fuzzy_epsilon()`.
+    #[inline]
+    fn geometric_eq<P>(&self, other: &P) -> bool
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.geometric_eq_eps(other, Self::Num::fuzzy_epsilon())
+    }
+
     /// Get the last vertex of the polyline or `None` if polyline is empty.
     #[inline]
     fn last(&self) -> Option<PlineVertex<Self::Num>> {
@@ -882,6 +935,32 @@ pub trait PlineSource {
         Some(result)
     }
 
+    /// Returns the canonical form of the polyline.
+    ///
+    /// Redundant vertexes are removed (see [PlineSource::remove_redundant]), open polylines are
+    /// directed such that their vertexes are lexicographically smallest (comparing x, y, then
+    /// bulge), and closed polylines are directed counter clockwise and rotated to start at the
+    /// vertex with the lexicographically smallest position. Polylines which are equal according to
+    /// [PlineSource::geometric_eq_eps] have fuzzy equal canonical forms, so the canonical form
+    /// (with coordinates rounded to a grid) may be used for hashing and removing duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let triangle1 = pline_closed![(1.0, 1.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 0.0)];
+    /// let triangle2 = pline_closed![(1.0, 1.0, 0.0), (0.0, 0.0, 0.0), (0.0, 1.0, 0.0)];
+    /// let canonical1 = triangle1.canonical_form(1e-5);
+    /// let canonical2 = triangle2.canonical_form(1e-5);
+    /// assert!(canonical1.fuzzy_eq(&canonical2));
+    /// assert!(canonical1.fuzzy_eq(&pline_closed![(0.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]));
+    /// ```
+    #[inline]
+    fn canonical_form(&self, pos_equal_eps: Self::Num) -> Self::OutputPolyline {
+        canonical_form(self, pos_equal_eps)
+    }
+
     /// Creates a fast approximate spatial index of all the polyline segments.
     ///
     /// The starting vertex index position is used as the key to the segment bounding box in the
@@ -951,6 +1030,9 @@ pub trait PlineSource {
     ///
     /// `pos_equal_eps` is epsilon value used for fuzzy float comparisons.
     ///
+    /// All the polyline segments are scanned, see [PlineSource::closest_point_opt] to use a spatial
+    /// index of the segments instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -965,45 +1047,208 @@ pub trait PlineSource {
     /// assert!(result.seg_point.fuzzy_eq(polyline[0].pos()));
     /// assert!(result.distance.fuzzy_eq(1.0));
     /// ```
+    #[inline]
     fn closest_point(
         &self,
         point: Vector2<Self::Num>,
         pos_equal_eps: Self::Num,
+    ) -> Option<ClosestPointResult<Self::Num>> {
+        let options = PlineClosestPointOptions {
+            pos_equal_eps,
+            ..Default::default()
+        };
+        self.closest_point_opt(point, &options)
+    }
+
+    /// Find the closest segment point on a polyline to a `point` given using the options provided.
+    ///
+    /// If the polyline is empty then `None` is returned.
+    ///
+    /// If a spatial index is given in `options` then it is used to only check segments that may be
+    /// closer than the closest found so far (segments are visited in order of bounding box distance
+    /// to `point`) rather than scanning all the segments. The path length up to the closest segment
+    /// is summed segment by segment unless [PlineClosestPointOptions::vertex_path_lengths] is
+    /// given, so to find many closest points in sub-linear time both should be given.
+    ///
+    /// The [ClosestPointResult::side] is determined using the tangent direction of the closest
+    /// segment at the closest point, if the closest point lies on a vertex joining two segments then
+    /// both segment tangents are used to determine the side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0)];
+    /// let aabb_index = polyline.create_approx_aabb_index().unwrap();
+    /// let path_lengths = [0.0, 2.0, 4.0];
+    /// let options = PlineClosestPointOptions {
+    ///     aabb_index: Some(&aabb_index),
+    ///     vertex_path_lengths: Some(&path_lengths),
+    ///     ..Default::default()
+    /// };
+    /// let result = polyline.closest_point_opt(Vector2::new(1.0, 1.5), &options).unwrap();
+    /// assert_eq!(result.seg_start_index, 1);
+    /// assert!(result.seg_point.fuzzy_eq(Vector2::new(2.0, 1.5)));
+    /// assert!(result.distance.fuzzy_eq(1.0));
+    /// assert!(result.path_length.fuzzy_eq(3.5));
+    /// assert!(result.seg_parametric.fuzzy_eq(0.75));
+    /// assert_eq!(result.side, PlineSide::Left);
+    /// ```
+    fn closest_point_opt(
+        &self,
+        point: Vector2<Self::Num>,
+        options: &PlineClosestPointOptions<Self::Num>,
     ) -> Option<ClosestPointResult<Self::Num>> {
         use num_traits::real::Real;
         if self.is_empty() {
             return None;
         }
 
-        let mut result = ClosestPointResult {
-            seg_start_index: 0,
-            seg_point: self.at(0).pos(),
-            distance: Real::max_value(),
-        };
+        let pos_equal_eps = options.pos_equal_eps;
 
         if self.vertex_count() == 1 {
-            result.distance = (result.seg_point - point).length();
-            return Some(result);
+            let seg_point = self.at(0).pos();
+            return Some(ClosestPointResult {
+                seg_start_index: 0,
+                seg_point,
+                distance: (seg_point - point).length(),
+                path_length: Self::Num::zero(),
+                seg_parametric: Self::Num::zero(),
+                side: PlineSide::Coincident,
+            });
         }
 
-        let mut dist_squared = Real::max_value();
+        let mut closest_index = 0;
+        let mut closest_point = self.at(0).pos();
+        let mut closest_dist2 = Real::max_value();
 
-        for (i, j) in self.iter_segment_indexes() {
+        let seg_closest = |i: usize| {
             let v1 = self.at(i);
-            let v2 = self.at(j);
+            let v2 = self.at(self.next_wrapping_index(i));
             let cp = seg_closest_point(v1, v2, point, pos_equal_eps);
-            let diff_v = point - cp;
-            let dist2 = diff_v.length_squared();
-            if dist2 < dist_squared {
-                result.seg_start_index = i;
-                result.seg_point = cp;
-                dist_squared = dist2;
+            (cp, (point - cp).length_squared())
+        };
+
+        match options.aabb_index {
+            Some(aabb_index) => {
+                let mut visitor = |i: usize, box_dist2: Self::Num| {
+                    if box_dist2 > closest_dist2 {
+                        // all remaining segments are further away than the closest found
+                        return aabb_index::Control::Break(());
+                    }
+                    let (cp, dist2) = seg_closest(i);
+                    if dist2 < closest_dist2 {
+                        closest_index = i;
+                        closest_point = cp;
+                        closest_dist2 = dist2;
+                    }
+                    aabb_index::Control::Continue
+                };
+                aabb_index.visit_neighbors(point.x, point.y, &mut visitor);
+            }
+            None => {
+                for (i, _) in self.iter_segment_indexes() {
+                    let (cp, dist2) = seg_closest(i);
+                    if dist2 < closest_dist2 {
+                        closest_index = i;
+                        closest_point = cp;
+                        closest_dist2 = dist2;
+                    }
+                }
             }
         }
 
-        result.distance = dist_squared.sqrt();
+        let distance = closest_dist2.sqrt();
 
-        Some(result)
+        let v1 = self.at(closest_index);
+        let v2 = self.at(self.next_wrapping_index(closest_index));
+
+        // path length along the closest segment up to the closest point
+        let seg_total_length = seg_length(v1, v2);
+        let seg_partial_length = {
+            let split = seg_split_at_point(v1, v2, closest_point, pos_equal_eps);
+            seg_length(split.updated_start, split.split_vertex)
+        };
+
+        let seg_parametric = if seg_total_length > Self::Num::zero() {
+            num_traits::clamp(
+                seg_partial_length / seg_total_length,
+                Self::Num::zero(),
+                Self::Num::one(),
+            )
+        } else {
+            Self::Num::zero()
+        };
+
+        let path_length = match options.vertex_path_lengths {
+            Some(lengths) => lengths[closest_index] + seg_partial_length,
+            None => self
+                .iter_segments()
+                .take(closest_index)
+                .fold(seg_partial_length, |acc, (u1, u2)| acc + seg_length(u1, u2)),
+        };
+
+        let side = if distance <= pos_equal_eps {
+            PlineSide::Coincident
+        } else {
+            let to_point = point - closest_point;
+            let is_left_of =
+                |tangent: Vector2<Self::Num>| tangent.perp_dot(to_point) > Self::Num::zero();
+
+            let seg_tangent = seg_tangent_vector(v1, v2, closest_point);
+            let vc = self.vertex_count();
+            // tangents of the segments joined at a vertex when the closest point lies on it
+            let joined_tangents = if closest_point.fuzzy_eq_eps(v1.pos(), pos_equal_eps)
+                && (self.is_closed() || closest_index != 0)
+            {
+                let prev_v = self.at(self.prev_wrapping_index(closest_index));
+                Some((
+                    seg_tangent_vector(prev_v, v1, v1.pos()),
+                    seg_tangent_vector(v1, v2, v1.pos()),
+                ))
+            } else if closest_point.fuzzy_eq_eps(v2.pos(), pos_equal_eps)
+                && (self.is_closed() || closest_index + 2 != vc)
+            {
+                let next_v = self.at(self.fwd_wrapping_index(closest_index, 2));
+                Some((
+                    seg_tangent_vector(v1, v2, v2.pos()),
+                    seg_tangent_vector(v2, next_v, v2.pos()),
+                ))
+            } else {
+                None
+            };
+
+            let left = match joined_tangents {
+                Some((tangent_in, tangent_out)) => {
+                    if tangent_in.perp_dot(tangent_out) > Self::Num::zero() {
+                        // polyline turns left at vertex, left side region is the intersection
+                        is_left_of(tangent_in) && is_left_of(tangent_out)
+                    } else {
+                        // polyline turns right at vertex, left side region is the union
+                        is_left_of(tangent_in) || is_left_of(tangent_out)
+                    }
+                }
+                None => is_left_of(seg_tangent),
+            };
+
+            if left {
+                PlineSide::Left
+            } else {
+                PlineSide::Right
+            }
+        };
+
+        Some(ClosestPointResult {
+            seg_start_index: closest_index,
+            seg_point: closest_point,
+            distance,
+            path_length,
+            seg_parametric,
+            side,
+        })
     }
 
     /// Calculate the winding number for a `point` relative to the polyline.
@@ -1064,131 +1309,9 @@ pub trait PlineSource {
             return 0;
         }
 
-        // Helper function for processing a line segment when computing the winding number.
-        let process_line_winding =
-            |v1: PlineVertex<Self::Num>, v2: PlineVertex<Self::Num>, point: Vector2<Self::Num>| {
-                let mut result = 0;
-                if v1.y <= point.y {
-                    if v2.y > point.y && is_left(v1.pos(), v2.pos(), point) {
-                        // left and upward crossing
-                        result += 1;
-                    }
-                } else if v2.y <= point.y && !is_left(v1.pos(), v2.pos(), point) {
-                    // right an downward crossing
-                    result -= 1;
-                }
-
-                result
-            };
-
-        // Helper function for processing an arc segment when computing the winding number.
-        let process_arc_winding =
-            |v1: PlineVertex<Self::Num>, v2: PlineVertex<Self::Num>, point: Vector2<Self::Num>| {
-                let is_ccw = v1.bulge_is_pos();
-                let point_is_left = if is_ccw {
-                    is_left(v1.pos(), v2.pos(), point)
-                } else {
-                    is_left_or_equal(v1.pos(), v2.pos(), point)
-                };
-
-                let dist_to_arc_center_less_than_radius = || {
-                    let (arc_radius, arc_center) = seg_arc_radius_and_center(v1, v2);
-                    let dist2 = dist_squared(arc_center, point);
-                    dist2 < arc_radius * arc_radius
-                };
-
-                let mut result = 0;
-
-                if v1.y <= point.y {
-                    if v2.y > point.y {
-                        // upward crossing of arc chord
-                        if is_ccw {
-                            if point_is_left {
-                                // counter clockwise arc left of chord
-                                result += 1;
-                            } else {
-                                // counter clockwise arc right of chord
-                                if dist_to_arc_center_less_than_radius() {
-                                    result += 1;
-                                }
-                            }
-                        } else if point_is_left {
-                            // clockwise arc left of chord
-                            if !dist_to_arc_center_less_than_radius() {
-                                result += 1;
-                            }
-                            // else clockwise arc right of chord, no crossing
-                        }
-                    } else {
-                        // not crossing arc chord and chord is below, check if point is inside arc sector
-                        if is_ccw
-                            && !point_is_left
-                            && v2.x < point.x
-                            && point.x < v1.x
-                            && dist_to_arc_center_less_than_radius()
-                        {
-                            result += 1;
-                        } else if !is_ccw
-                            && point_is_left
-                            && v1.x < point.x
-                            && point.x < v2.x
-                            && dist_to_arc_center_less_than_radius()
-                        {
-                            result -= 1;
-                        }
-                    }
-                } else if v2.y <= point.y {
-                    // downward crossing of arc chord
-                    if is_ccw {
-                        if !point_is_left {
-                            // counter clockwise arc right of chord
-                            if !dist_to_arc_center_less_than_radius() {
-                                result -= 1;
-                            }
-                        }
-                    // else counter clockwise arc left of chord, no crossing
-                    } else if point_is_left {
-                        // clockwise arc left of chord
-                        if dist_to_arc_center_less_than_radius() {
-                            result -= 1;
-                        }
-                    } else {
-                        // clockwise arc right of chord
-                        result -= 1;
-                    }
-                } else {
-                    // not crossing arc chord and chord is above, check if point is inside arc sector
-                    if is_ccw
-                        && !point_is_left
-                        && v1.x < point.x
-                        && point.x < v2.x
-                        && dist_to_arc_center_less_than_radius()
-                    {
-                        result += 1;
-                    } else if !is_ccw
-                        && point_is_left
-                        && v2.x < point.x
-                        && point.x < v1.x
-                        && dist_to_arc_center_less_than_radius()
-                    {
-                        result -= 1;
-                    }
-                }
-
-                result
-            };
-
-        let mut winding = 0;
-
-        for (v1, v2) in self.iter_segments() {
-            if v1.bulge_is_zero() {
-                winding += process_line_winding(v1, v2, point);
-            } else {
-                winding += process_arc_winding(v1, v2, point);
-            }
-        }
-
-        winding
+        self.iter_segments()
+            .map(|(v1, v2)| seg_winding_number(v1, v2, point))
+            .sum()
     }
 
     /// Returns a new polyline with all arc segments converted to line segments with some
@@ -1328,6 +1451,310 @@ pub trait PlineSource {
         find_intersects(self, other, options)
     }
 
+    /// Find the minimum distance and closest point pair between this polyline and another using
+    /// default options.
+    ///
+    /// See [PlineSource::distance_to_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// // circle with radius 1 centered at (5, 1)
+    /// let circle = pline_closed![(4.0, 1.0, 1.0), (6.0, 1.0, 1.0)];
+    /// let result = rectangle.distance_to(&circle).unwrap();
+    /// assert!(result.distance.fuzzy_eq(2.0));
+    /// assert_eq!(result.seg_start_index1, 1);
+    /// assert!(result.point1.fuzzy_eq(Vector2::new(2.0, 1.0)));
+    /// assert!(result.point2.fuzzy_eq(Vector2::new(4.0, 1.0)));
+    /// ```
+    #[inline]
+    fn distance_to<P>(&self, other: &P) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.distance_to_opt(other, &Default::default())
+    }
+
+    /// Find the minimum distance and closest point pair between this polyline and another using
+    /// the options provided.
+    ///
+    /// If the polylines intersect then the distance returned is zero and both points are at an
+    /// intersect. The spatial indexes of both polylines are used to perform a branch and bound
+    /// search over segment pairs. For the result `1` refers to `self`, and `2` refers to `other`.
+    ///
+    /// Returns `None` if either polyline is empty.
+    #[inline]
+    fn distance_to_opt<P>(
+        &self,
+        other: &P,
+        options: &PlineDistanceOptions<Self::Num>,
+    ) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        find_min_distance(self, other, options)
+    }
+
+    /// Find all the hits between a ray and the polyline using default options.
+    ///
+    /// See [PlineSource::ray_intersects_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle with radius 1 centered at (5, 0)
+    /// let circle = pline_closed![(4.0, 0.0, 1.0), (6.0, 0.0, 1.0)];
+    /// let hits = circle.ray_intersects(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert_eq!(hits.len(), 2);
+    /// assert!(hits[0].point.fuzzy_eq(Vector2::new(4.0, 0.0)));
+    /// assert!(hits[0].distance.fuzzy_eq(4.0));
+    /// assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+    /// assert!(hits[1].point.fuzzy_eq(Vector2::new(6.0, 0.0)));
+    /// assert_eq!(hits[1].crossing, PlineRayCrossing::Exiting);
+    /// ```
+    #[inline]
+    fn ray_intersects(
+        &self,
+        origin: Vector2<Self::Num>,
+        direction: Vector2<Self::Num>,
+    ) -> Vec<PlineRayHit<Self::Num>> {
+        self.ray_intersects_opt(origin, direction, &Default::default())
+    }
+
+    /// Find all the hits between the ray starting at `origin` going in `direction` and the
+    /// polyline using the options provided.
+    ///
+    /// Hits are sorted by distance from `origin`, each hit holds the segment index, point,
+    /// distance, and whether the ray is entering, exiting, or touching the polyline (see
+    /// [PlineRayCrossing]). Hits at a vertex are only returned once. The spatial index of the
+    /// polyline is used to only test segments that may intersect the ray.
+    ///
+    /// Returns an empty vector if `direction` is zero length or the polyline has less than 2
+    /// vertexes.
+    #[inline]
+    fn ray_intersects_opt(
+        &self,
+        origin: Vector2<Self::Num>,
+        direction: Vector2<Self::Num>,
+        options: &PlineRayOptions<Self::Num>,
+    ) -> Vec<PlineRayHit<Self::Num>> {
+        ray_intersects(self, origin, direction, options)
+    }
+
+    /// Find all the hits between an infinite line and the polyline using default options.
+    ///
+    /// See [PlineSource::line_intersects_opt] for more information.
+    #[inline]
+    fn line_intersects(
+        &self,
+        p0: Vector2<Self::Num>,
+        p1: Vector2<Self::Num>,
+    ) -> Vec<PlineRayHit<Self::Num>> {
+        self.line_intersects_opt(p0, p1, &Default::default())
+    }
+
+    /// Find all the hits between the infinite line passing through `p0` and `p1` and the polyline
+    /// using the options provided.
+    ///
+    /// Same as [PlineSource::ray_intersects_opt] but the line extends in both directions, hits are
+    /// sorted by signed distance from `p0` in the direction of `p1` (hits before `p0` have negative
+    /// distance).
+    ///
+    /// Returns an empty vector if `p0` and `p1` are the same point or the polyline has less than 2
+    /// vertexes.
+    #[inline]
+    fn line_intersects_opt(
+        &self,
+        p0: Vector2<Self::Num>,
+        p1: Vector2<Self::Num>,
+        options: &PlineRayOptions<Self::Num>,
+    ) -> Vec<PlineRayHit<Self::Num>> {
+        line_intersects(self, p0, p1, options)
+    }
+
+    /// Determine the spatial relationship between this closed polyline and another using default
+    /// options.
+    ///
+    /// See [PlineSource::relate_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (-1.0, -2.0, 0.0),
+    ///     (3.0, -2.0, 0.0),
+    ///     (3.0, 2.0, 0.0),
+    ///     (-1.0, 2.0, 0.0),
+    /// ];
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// assert_eq!(rectangle.relate(&circle), PlineRelation::Contains);
+    /// assert_eq!(circle.relate(&rectangle), PlineRelation::Within);
+    /// // circle touching the right side of the rectangle from the outside
+    /// let touching_circle = pline_closed![(3.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    /// assert_eq!(rectangle.relate(&touching_circle), PlineRelation::Touches);
+    /// ```
+    #[inline]
+    fn relate<P>(&self, other: &P) -> PlineRelation
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.relate_opt(other, &Default::default())
+    }
+
+    /// Determine the spatial relationship between this closed polyline and another using the
+    /// options provided.
+    ///
+    /// Relationships are in terms of the closed regions enclosed by the polylines (see
+    /// [PlineRelation]), coincident overlapping segments are treated as shared boundary. Direction
+    /// of the polylines does not matter, a point is considered inside a polyline if its winding
+    /// number is not zero.
+    ///
+    /// Returns [PlineRelation::InvalidInput] if either polyline is open or has less than 2
+    /// vertexes.
+    #[inline]
+    fn relate_opt<P>(&self, other: &P, options: &PlineRelateOptions<Self::Num>) -> PlineRelation
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        relate(self, other, options)
+    }
+
+    /// Compute the symmetric Hausdorff distance between this polyline and another using default
+    /// options.
+    ///
+    /// See [PlineSource::hausdorff_distance_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// let line = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+    /// // line shifted up by 1 and extended by 2 to the right
+    /// let longer_line = pline_open![(0.0, 1.0, 0.0), (6.0, 1.0, 0.0)];
+    /// let result = line.hausdorff_distance(&longer_line).unwrap();
+    /// assert!(result.distance.fuzzy_eq_eps(5.0f64.sqrt(), 1e-4));
+    /// assert!(result.point1.fuzzy_eq_eps(Vector2::new(4.0, 0.0), 1e-4));
+    /// assert!(result.point2.fuzzy_eq_eps(Vector2::new(6.0, 1.0), 1e-4));
+    /// ```
+    #[inline]
+    fn hausdorff_distance<P>(&self, other: &P) -> Option<PlineHausdorffResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.hausdorff_distance_opt(other, &Default::default())
+    }
+
+    /// Compute the symmetric Hausdorff distance between this polyline and another using the
+    /// options provided.
+    ///
+    /// The symmetric Hausdorff distance is the maximum of the directed Hausdorff distance in both
+    /// directions (see [PlineSource::directed_hausdorff_distance_opt]). `point1` of the result is
+    /// always on `self` and `point2` is always on `other`.
+    ///
+    /// Returns `None` if either polyline is empty or [PlineHausdorffOptions::tolerance] is not a finite number
+    /// greater than zero.
+    #[inline]
+    fn hausdorff_distance_opt<P>(
+        &self,
+        other: &P,
+        options: &PlineHausdorffOptions<Self::Num>,
+    ) -> Option<PlineHausdorffResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        hausdorff_distance(self, other, options)
+    }
+
+    /// Compute the directed Hausdorff distance from this polyline to another using default
+    /// options.
+    ///
+    /// See [PlineSource::directed_hausdorff_distance_opt] for more information.
+    #[inline]
+    fn directed_hausdorff_distance<P>(&self, other: &P) -> Option<PlineHausdorffResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.directed_hausdorff_distance_opt(other, &Default::default())
+    }
+
+    /// Compute the directed Hausdorff distance from this polyline to another using the options
+    /// provided.
+    ///
+    /// The directed Hausdorff distance is the maximum distance from any point on `self` to the
+    /// closest point on `other`. `point1` of the result is the point on `self` that attains the
+    /// distance and `point2` is its closest point on `other`.
+    ///
+    /// Segments (both lines and arcs) are recursively subdivided using a branch and bound search
+    /// until the distance returned is within [PlineHausdorffOptions::tolerance] of the true
+    /// distance (the distance returned is never greater than the true distance).
+    ///
+    /// Returns `None` if either polyline is empty or [PlineHausdorffOptions::tolerance] is not a finite number
+    /// greater than zero.
+    #[inline]
+    fn directed_hausdorff_distance_opt<P>(
+        &self,
+        other: &P,
+        options: &PlineHausdorffOptions<Self::Num>,
+    ) -> Option<PlineHausdorffResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        directed_hausdorff_distance(self, other, options)
+    }
+
+    /// Compute the discrete Fréchet distance between this polyline and another.
+    ///
+    /// Both polylines are sampled at their vertexes plus additional points along each segment such
+    /// that consecutive samples are at most `sample_spacing` apart in path length (if
+    /// `sample_spacing` is zero then only vertexes are used). Closed polylines are sampled starting
+    /// and ending at their first vertex. The distance returned is the minimum over all in order
+    /// couplings of the samples of the maximum coupled distance, it converges to the continuous
+    /// Fréchet distance as `sample_spacing` approaches zero.
+    ///
+    /// Returns `None` if either polyline is empty or `sample_spacing` is negative or not a finite number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// let pline1 = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+    /// let pline2 = pline_open![(0.0, 1.0, 0.0), (4.0, 1.0, 0.0)];
+    /// let dist = pline1.discrete_frechet_distance(&pline2, 0.5).unwrap();
+    /// assert!(dist.fuzzy_eq(1.0));
+    /// // direction matters for the Fréchet distance
+    /// let mut pline3 = pline2.clone();
+    /// pline3.invert_direction_mut();
+    /// let dist = pline1.discrete_frechet_distance(&pline3, 0.5).unwrap();
+    /// assert!(dist.fuzzy_eq(17.0f64.sqrt()));
+    /// ```
+    #[inline]
+    fn discrete_frechet_distance<P>(
+        &self,
+        other: &P,
+        sample_spacing: Self::Num,
+    ) -> Option<Self::Num>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        discrete_frechet_distance(self, other, sample_spacing)
+    }
+
     /// Compute the parallel offset polylines of the polyline using default options.
     ///
     /// `offset` determines what offset polylines are generated, if it is positive then the
@@ -1494,21 +1921,7 @@ pub trait PlineSource {
 
             // parametric value (from 0 to 1) along the segment where the point lies
             let t = (target_path_length - acc_length) / seg_len;
-
-            if v1.bulge_is_zero() {
-                // line segment
-                let pt = v1.pos() + (v2.pos() - v1.pos()).scale(t);
-                return Ok((i, pt));
-            } else {
-                // arc segment
-                let (radius, center) = seg_arc_radius_and_center(v1, v2);
-                let start_angle = angle(center, v1.pos());
-                let total_sweep_angle = angle_from_bulge(v1.bulge);
-                let target_angle = start_angle + total_sweep_angle * t;
-
-                let pt = point_on_circle(radius, center, target_angle);
-                return Ok((i, pt));
-            }
+            return Ok((i, seg_point_at_parametric(v1, v2, t)));
         }
 
         Err(acc_length)
@@ -119,6 +119,7 @@ fn tangent_intersect_horizontal() {
 }
 
 #[test]
+#[allow(clippy::excessive_precision)]
 fn tangent_at_start_point() {
     // this is a case that previously failed due to numeric stability issues
     let p0 = Vector2::new(161.28999999999999, 113.66500000000001);
@@ -0,0 +1,218 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::{math::Vector2, traits::FuzzyEq},
+    medial_axis::{MedialAxis, MedialAxisOptions},
+    pline_closed, pline_open,
+    polyline::{PlineSource, PlineSourceMut, PointLocator, Polyline},
+};
+use std::f64::consts::PI;
+
+fn medial_axis(plines: &[Polyline], spacing: f64) -> MedialAxis {
+    let options = MedialAxisOptions {
+        sample_spacing: Some(spacing),
+        ..Default::default()
+    };
+    MedialAxis::new(plines, &options)
+}
+
+/// Every node must be inside the region with radius equal to its distance to the boundary.
+fn assert_nodes_valid(axis: &MedialAxis, plines: &[Polyline]) {
+    let locator = PointLocator::new(plines, 1e-5);
+    for node in axis.nodes.iter() {
+        assert!(node.radius > 0.0);
+        let dist = locator.distance_to_boundary(node.pos).unwrap();
+        assert_fuzzy_eq!(node.radius, dist);
+        assert!(locator.winding_number(node.pos) != 0);
+    }
+}
+
+#[test]
+fn empty_input() {
+    let axis = medial_axis(&[], 0.1);
+    assert!(axis.nodes.is_empty());
+    assert!(axis.edges.is_empty());
+    assert!(axis.to_plines().is_empty());
+
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    let axis = medial_axis(&[open], 0.1);
+    assert!(axis.nodes.is_empty());
+}
+
+#[test]
+fn rectangle() {
+    let rect: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ];
+    let plines = vec![rect];
+    let axis = medial_axis(&plines, 0.1);
+    assert_nodes_valid(&axis, &plines);
+
+    let degrees = axis.node_degrees();
+    assert_eq!(degrees.iter().filter(|&&d| d == 1).count(), 4);
+    assert_eq!(degrees.iter().filter(|&&d| d == 3).count(), 2);
+    assert!(degrees.iter().all(|&d| (1..=3).contains(&d)));
+
+    // branch points where the corner bisectors meet
+    for (i, &d) in degrees.iter().enumerate() {
+        if d == 3 {
+            let pos = axis.nodes[i].pos;
+            assert!(pos.fuzzy_eq(Vector2::new(1.0, 1.0)) || pos.fuzzy_eq(Vector2::new(9.0, 1.0)));
+            assert_fuzzy_eq!(axis.nodes[i].radius, 1.0);
+        }
+    }
+
+    // center line plus four corner branches, each a single line edge
+    assert_eq!(axis.edges.len(), 5);
+    assert!(axis.edges.iter().all(|e| e.bulge == 0.0));
+    let chains = axis.to_plines();
+    assert_eq!(chains.len(), 5);
+    let center_line = chains
+        .iter()
+        .find(|p| p.path_length().fuzzy_eq(8.0))
+        .unwrap();
+    assert!(!center_line.is_closed());
+    assert!(center_line.iter_vertexes().all(|v| v.y.fuzzy_eq(1.0)));
+}
+
+#[test]
+fn annulus_with_hole() {
+    // hole has the same orientation as the outer loop, nesting determines it is a hole
+    let outer: Polyline = pline_closed![(-10.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let hole: Polyline = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    let plines = vec![outer, hole];
+    let axis = medial_axis(&plines, 0.2);
+    assert_nodes_valid(&axis, &plines);
+
+    assert!(axis.node_degrees().iter().all(|&d| d == 2));
+    // concentric circle bisector is fitted with arc edges
+    assert!(axis.edges.len() <= 4);
+    assert!(axis.edges.iter().all(|e| e.bulge != 0.0));
+    for node in axis.nodes.iter() {
+        assert!((node.pos.length() - 7.5).abs() < 1e-2);
+        assert!((node.radius - 2.5).abs() < 1e-2);
+    }
+
+    let chains = axis.to_plines();
+    assert_eq!(chains.len(), 1);
+    assert!(chains[0].is_closed());
+    assert!((chains[0].path_length() - 2.0 * PI * 7.5).abs() < 1e-2);
+}
+
+#[test]
+fn circle_is_single_node() {
+    let mut circle: Polyline = pline_closed![(-10.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    circle.invert_direction_mut();
+    let axis = medial_axis(std::slice::from_ref(&circle), 0.2);
+    assert_eq!(axis.nodes.len(), 1);
+    assert!(axis.edges.is_empty());
+    assert!(axis.nodes[0].pos.fuzzy_eq_eps(Vector2::zero(), 1e-6));
+    assert_fuzzy_eq!(axis.nodes[0].radius, 10.0);
+}
+
+#[test]
+fn reflex_corner_has_no_branch() {
+    let l_shape: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 3.0, 0.0),
+        (3.0, 3.0, 0.0),
+        (3.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let plines = vec![l_shape];
+    let axis = medial_axis(&plines, 0.1);
+    assert_nodes_valid(&axis, &plines);
+
+    // one branch into each of the 5 convex corners, none into the reflex corner at (3, 3)
+    let degrees = axis.node_degrees();
+    assert_eq!(degrees.iter().filter(|&&d| d == 1).count(), 5);
+    let min_dist_to_reflex = axis
+        .nodes
+        .iter()
+        .map(|n| (n.pos - Vector2::new(3.0, 3.0)).length())
+        .fold(f64::MAX, f64::min);
+    assert!(min_dist_to_reflex > 1.0);
+
+    // branch point equidistant to the two outer sides and the reflex corner
+    let expected = 3.0 * 2.0f64.sqrt() / (1.0 + 2.0f64.sqrt());
+    assert!(axis.nodes.iter().any(|n| {
+        n.pos.fuzzy_eq_eps(Vector2::new(expected, expected), 1e-2)
+            && (n.radius - expected).abs() < 1e-2
+    }));
+}
+
+#[test]
+fn obround_with_arcs() {
+    let obround: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 4.0, 0.0),
+        (0.0, 4.0, 1.0),
+    ];
+    let plines = vec![obround];
+    let axis = medial_axis(&plines, 0.1);
+    assert_nodes_valid(&axis, &plines);
+
+    // single center line between the arc centers with constant clearance
+    let chains = axis.to_plines();
+    assert_eq!(chains.len(), 1);
+    assert!((chains[0].path_length() - 10.0).abs() < 1e-6);
+    for node in axis.nodes.iter() {
+        assert!((node.pos.y - 2.0).abs() < 1e-6);
+        assert!((node.radius - 2.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn loops_touching_at_vertex() {
+    // two squares sharing a corner give coincident boundary samples
+    let plines: Vec<Polyline> = vec![
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0)
+        ],
+        pline_closed![
+            (1.0, 1.0, 0.0),
+            (2.0, 1.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (1.0, 2.0, 0.0)
+        ],
+    ];
+    let axis = medial_axis(&plines, 0.05);
+    assert_nodes_valid(&axis, &plines);
+    for &center in [Vector2::new(0.5, 0.5), Vector2::new(1.5, 1.5)].iter() {
+        let node = axis
+            .nodes
+            .iter()
+            .find(|n| n.pos.fuzzy_eq_eps(center, 1e-6))
+            .unwrap();
+        assert_fuzzy_eq!(node.radius, 0.5);
+    }
+}
+
+#[test]
+fn sample_count_bounded() {
+    let rect: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ];
+    let plines = vec![rect];
+    // would need millions of samples without the bound
+    let options = MedialAxisOptions {
+        sample_spacing: Some(1e-5),
+        max_sample_count: 2000,
+        ..Default::default()
+    };
+    let axis = MedialAxis::new(&plines, &options);
+    assert_nodes_valid(&axis, &plines);
+    assert_eq!(axis.edges.len(), 5);
+    let chains = axis.to_plines();
+    assert!(chains.iter().any(|p| (p.path_length() - 8.0).abs() < 1e-3));
+}
@@ -0,0 +1,281 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{PlineArrangement, PlineArrangementOptions, PlineSource, PlineSourceMut, Polyline},
+};
+use std::f64::consts::PI;
+use test_utils::rect;
+
+fn arrange(plines: &[Polyline]) -> PlineArrangement {
+    PlineArrangement::new(plines, &PlineArrangementOptions::default())
+}
+
+fn region_area(plines: &[Polyline]) -> f64 {
+    plines.iter().map(|p| p.area()).sum()
+}
+
+/// Assert every point not close to an input is in at most one face, and that face has the
+/// winding numbers of the inputs at the point.
+fn assert_faces_match_winding(plines: &[Polyline], arrangement: &PlineArrangement) {
+    let steps = 60;
+    for i in 0..=steps {
+        for j in 0..=steps {
+            let p = Vector2::new(
+                -2.0 + 16.0 * i as f64 / steps as f64,
+                -2.0 + 16.0 * j as f64 / steps as f64,
+            );
+            let near_input = plines
+                .iter()
+                .any(|pline| pline.closest_point(p, 1e-5).unwrap().distance < 1e-3);
+            if near_input {
+                continue;
+            }
+            let containing = arrangement
+                .faces
+                .iter()
+                .filter(|f| {
+                    f.boundary.winding_number(p) != 0
+                        && f.holes.iter().all(|h| h.winding_number(p) == 0)
+                })
+                .collect::<Vec<_>>();
+            let expected = plines
+                .iter()
+                .map(|pline| {
+                    if pline.is_closed() {
+                        pline.winding_number(p)
+                    } else {
+                        0
+                    }
+                })
+                .collect::<Vec<_>>();
+            match containing.as_slice() {
+                [] => assert!(expected.iter().all(|&w| w == 0), "point {:?}", p),
+                [face] => assert_eq!(face.winding_numbers, expected, "point {:?}", p),
+                _ => panic!("point {:?} in {} faces", p, containing.len()),
+            }
+        }
+    }
+}
+
+#[test]
+fn empty_input() {
+    let empty: Vec<Polyline> = Vec::new();
+    let arrangement = arrange(&empty);
+    assert!(arrangement.vertexes.is_empty());
+    assert!(arrangement.edges.is_empty());
+    assert!(arrangement.faces.is_empty());
+    assert!(arrangement.select_region(|_| true).is_empty());
+
+    // open polylines without crossings have no faces
+    let arrangement = arrange(&[pline_open![
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (1.0, 1.0, 0.0)
+    ]]);
+    assert_eq!(arrangement.vertexes.len(), 3);
+    assert_eq!(arrangement.edges.len(), 2);
+    assert!(arrangement.faces.is_empty());
+}
+
+#[test]
+fn non_finite_segments_skipped() {
+    let with_nan: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (f64::NAN, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let plines = vec![with_nan, rect(10.0, 0.0, 12.0, 2.0)];
+    let arrangement = arrange(&plines);
+    // only the two finite segments of the first polyline remain
+    assert_eq!(arrangement.edges.len(), 6);
+    assert_eq!(arrangement.faces.len(), 1);
+    assert_eq!(arrangement.faces[0].winding_numbers, [0, 1]);
+    assert_fuzzy_eq!(arrangement.faces[0].boundary.area(), 4.0);
+}
+
+#[test]
+fn overlapping_squares() {
+    let plines = vec![rect(0.0, 0.0, 4.0, 4.0), rect(2.0, 2.0, 6.0, 6.0)];
+    let arrangement = arrange(&plines);
+    assert_eq!(arrangement.vertexes.len(), 10);
+    assert_eq!(arrangement.edges.len(), 12);
+    assert_eq!(arrangement.faces.len(), 3);
+    for face in arrangement.faces.iter() {
+        assert!(face.holes.is_empty());
+        assert_eq!(face.boundary_edges.len(), face.boundary.vertex_count());
+        let expected_area = if face.winding_numbers == [1, 1] {
+            4.0
+        } else {
+            12.0
+        };
+        assert_fuzzy_eq!(face.boundary.area(), expected_area);
+    }
+
+    let union = arrangement.select_region(|w| w.iter().any(|&w| w != 0));
+    assert_eq!(union.len(), 1);
+    assert_fuzzy_eq!(union[0].area(), 28.0);
+    let intersect = arrangement.select_region(|w| w.iter().all(|&w| w != 0));
+    assert_eq!(intersect.len(), 1);
+    assert_fuzzy_eq!(intersect[0].area(), 4.0);
+    let difference = arrangement.select_region(|w| w[0] != 0 && w[1] == 0);
+    assert_eq!(difference.len(), 1);
+    assert_fuzzy_eq!(difference[0].area(), 12.0);
+    let xor = arrangement.select_region(|w| (w[0] != 0) != (w[1] != 0));
+    assert_eq!(xor.len(), 2);
+    assert_fuzzy_eq!(region_area(&xor), 24.0);
+
+    assert_faces_match_winding(&plines, &arrangement);
+}
+
+#[test]
+fn nested_and_reversed() {
+    let mut inner = rect(3.0, 3.0, 6.0, 6.0);
+    inner.invert_direction_mut();
+    let plines = vec![rect(0.0, 0.0, 10.0, 10.0), inner];
+    let arrangement = arrange(&plines);
+    assert_eq!(arrangement.faces.len(), 2);
+    let outer_face = arrangement
+        .faces
+        .iter()
+        .find(|f| f.winding_numbers == [1, 0])
+        .unwrap();
+    assert_eq!(outer_face.holes.len(), 1);
+    assert_fuzzy_eq!(outer_face.boundary.area(), 100.0);
+    assert_fuzzy_eq!(outer_face.holes[0].area(), -9.0);
+    assert!(arrangement
+        .faces
+        .iter()
+        .any(|f| f.winding_numbers == [1, -1]));
+
+    // region with a hole
+    let region = arrangement.select_region(|w| w.iter().sum::<i32>() > 0);
+    assert_eq!(region.len(), 2);
+    assert_fuzzy_eq!(region_area(&region), 91.0);
+
+    assert_faces_match_winding(&plines, &arrangement);
+}
+
+#[test]
+fn open_polylines_split_faces() {
+    let plines = vec![
+        rect(0.0, 0.0, 10.0, 10.0),
+        pline_open![(-1.0, 5.0, 0.0), (11.0, 5.0, 0.0)],
+        // dangling into the square from its boundary
+        pline_open![(5.0, 10.0, 0.0), (5.0, 7.0, 0.0)],
+    ];
+    let arrangement = arrange(&plines);
+    assert_eq!(arrangement.faces.len(), 2);
+    for face in arrangement.faces.iter() {
+        assert_eq!(face.winding_numbers, [1, 0, 0]);
+        assert_fuzzy_eq!(face.boundary.area(), 50.0);
+        assert!(face.holes.is_empty());
+    }
+    // cutting edge is from the open polyline
+    let cut_edges = arrangement
+        .edges
+        .iter()
+        .filter(|e| e.sources.iter().any(|s| s.pline_index == 1))
+        .count();
+    assert_eq!(cut_edges, 3);
+
+    // edges between selected faces are dissolved
+    let region = arrangement.select_region(|w| w[0] != 0);
+    assert_eq!(region.len(), 1);
+    assert_fuzzy_eq!(region[0].area(), 100.0);
+
+    assert_faces_match_winding(&plines, &arrangement);
+}
+
+#[test]
+fn overlapping_edges() {
+    // squares sharing an edge and a square overlapping along part of an edge
+    let plines = vec![
+        rect(0.0, 0.0, 2.0, 2.0),
+        rect(2.0, 0.0, 4.0, 2.0),
+        rect(1.0, 2.0, 3.0, 3.0),
+    ];
+    let arrangement = arrange(&plines);
+    assert_eq!(arrangement.faces.len(), 3);
+    let shared = arrangement
+        .edges
+        .iter()
+        .filter(|e| e.sources.len() == 2)
+        .collect::<Vec<_>>();
+    // vertical shared edge plus two parts of the top edges
+    assert_eq!(shared.len(), 3);
+    for edge in shared {
+        let directions = edge
+            .sources
+            .iter()
+            .map(|s| s.same_direction)
+            .collect::<Vec<_>>();
+        assert_ne!(directions[0], directions[1]);
+    }
+
+    let union = arrangement.select_region(|w| w.iter().any(|&w| w != 0));
+    assert_eq!(union.len(), 1);
+    assert_fuzzy_eq!(union[0].area(), 10.0);
+
+    assert_faces_match_winding(&plines, &arrangement);
+}
+
+#[test]
+fn arcs() {
+    let plines: Vec<Polyline> = vec![
+        pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)],
+        pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+    ];
+    let arrangement = arrange(&plines);
+    assert_eq!(arrangement.faces.len(), 3);
+    let lens = arrangement
+        .faces
+        .iter()
+        .find(|f| f.winding_numbers == [1, 1])
+        .unwrap();
+    assert_fuzzy_eq!(lens.boundary.area(), 2.0 * PI / 3.0 - 3.0f64.sqrt() / 2.0);
+    let union = arrangement.select_region(|w| w.iter().any(|&w| w != 0));
+    assert_eq!(union.len(), 1);
+    assert_fuzzy_eq!(union[0].area(), 2.0 * PI - lens.boundary.area());
+}
+
+#[test]
+fn many_inputs_match_winding() {
+    let mut figure_eight: Polyline = pline_closed![
+        (6.0, 6.0, 0.0),
+        (12.0, 12.0, 0.0),
+        (12.0, 6.0, 0.0),
+        (6.0, 12.0, 0.0)
+    ];
+    figure_eight.invert_direction_mut();
+    let plines = vec![
+        pline_closed![
+            (0.0, 0.0, 0.3),
+            (8.0, 0.0, 0.0),
+            (8.0, 6.0, -0.5),
+            (3.0, 4.0, 0.0),
+            (0.0, 6.0, 0.0)
+        ],
+        rect(2.0, 2.0, 10.0, 9.0),
+        pline_closed![(4.0, 5.0, 1.0), (9.0, 5.0, 1.0)],
+        figure_eight,
+        pline_open![(-1.0, 3.0, 0.5), (13.0, 11.0, 0.0), (7.0, 13.0, 0.0)],
+        rect(2.0, 2.0, 5.0, 9.0),
+    ];
+    let arrangement = arrange(&plines);
+    assert!(arrangement.faces.len() > 10);
+    assert_faces_match_winding(&plines, &arrangement);
+
+    // sum of face areas is the area covered by any input
+    let covered = arrangement.select_region(|w| w.iter().any(|&w| w != 0));
+    let face_area: f64 = arrangement
+        .faces
+        .iter()
+        .filter(|f| f.winding_numbers.iter().any(|&w| w != 0))
+        .map(|f| f.boundary.area() + region_area(&f.holes))
+        .sum();
+    assert_fuzzy_eq!(region_area(&covered), face_area);
+}
@@ -0,0 +1,204 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        internal::pline_path_length::vertex_path_lengths, PlineClosestPointOptions, PlineSide,
+        PlineSource, PlineSourceMut, Polyline,
+    },
+};
+use std::f64::consts::PI;
+
+#[test]
+fn single_vertex() {
+    let polyline = pline_open![(1.0, 1.0, 0.0)];
+    let result = polyline
+        .closest_point(Vector2::new(1.0, 3.0), 1e-5)
+        .unwrap();
+    assert_eq!(result.seg_start_index, 0);
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(1.0, 1.0));
+    assert_fuzzy_eq!(result.distance, 2.0);
+    assert_fuzzy_eq!(result.path_length, 0.0);
+    assert_fuzzy_eq!(result.seg_parametric, 0.0);
+    assert_eq!(result.side, PlineSide::Coincident);
+}
+
+#[test]
+fn open_lines() {
+    let polyline = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0)];
+
+    let result = polyline
+        .closest_point(Vector2::new(1.0, 1.0), 1e-5)
+        .unwrap();
+    assert_eq!(result.seg_start_index, 0);
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(1.0, 0.0));
+    assert_fuzzy_eq!(result.distance, 1.0);
+    assert_fuzzy_eq!(result.path_length, 1.0);
+    assert_fuzzy_eq!(result.seg_parametric, 0.25);
+    assert_eq!(result.side, PlineSide::Left);
+
+    let result = polyline
+        .closest_point(Vector2::new(5.0, 3.0), 1e-5)
+        .unwrap();
+    assert_eq!(result.seg_start_index, 1);
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(4.0, 3.0));
+    assert_fuzzy_eq!(result.distance, 1.0);
+    assert_fuzzy_eq!(result.path_length, 7.0);
+    assert_fuzzy_eq!(result.seg_parametric, 0.75);
+    assert_eq!(result.side, PlineSide::Right);
+
+    // point on the polyline
+    let result = polyline
+        .closest_point(Vector2::new(2.0, 0.0), 1e-5)
+        .unwrap();
+    assert_fuzzy_eq!(result.distance, 0.0);
+    assert_fuzzy_eq!(result.path_length, 2.0);
+    assert_eq!(result.side, PlineSide::Coincident);
+
+    // closest to end points
+    let result = polyline
+        .closest_point(Vector2::new(-1.0, 1.0), 1e-5)
+        .unwrap();
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(0.0, 0.0));
+    assert_fuzzy_eq!(result.path_length, 0.0);
+    assert_fuzzy_eq!(result.seg_parametric, 0.0);
+    assert_eq!(result.side, PlineSide::Left);
+
+    let result = polyline
+        .closest_point(Vector2::new(5.0, 5.0), 1e-5)
+        .unwrap();
+    assert_eq!(result.seg_start_index, 1);
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(4.0, 4.0));
+    assert_fuzzy_eq!(result.path_length, 8.0);
+    assert_fuzzy_eq!(result.seg_parametric, 1.0);
+    assert_eq!(result.side, PlineSide::Right);
+}
+
+#[test]
+fn corner_vertex_side() {
+    // polyline turns left at (4, 0)
+    let polyline = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0)];
+    // outside of the corner, right of both segments
+    let result = polyline
+        .closest_point(Vector2::new(5.0, -1.0), 1e-5)
+        .unwrap();
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(4.0, 0.0));
+    assert_fuzzy_eq!(result.path_length, 4.0);
+    assert_eq!(result.side, PlineSide::Right);
+
+    // same corner but direction inverted (polyline turns right at (4, 0))
+    let mut inverted = polyline.clone();
+    inverted.invert_direction_mut();
+    let result = inverted
+        .closest_point(Vector2::new(5.0, -1.0), 1e-5)
+        .unwrap();
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(4.0, 0.0));
+    assert_fuzzy_eq!(result.path_length, 4.0);
+    assert_eq!(result.side, PlineSide::Left);
+}
+
+#[test]
+fn closed_rectangle_sides() {
+    let rectangle = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (4.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ];
+
+    // counter clockwise so inside is left
+    let inside = Vector2::new(1.0, 1.5);
+    let outside = Vector2::new(-1.0, -1.0);
+    assert_eq!(
+        rectangle.closest_point(inside, 1e-5).unwrap().side,
+        PlineSide::Left
+    );
+    let result = rectangle.closest_point(outside, 1e-5).unwrap();
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(0.0, 0.0));
+    assert_eq!(result.side, PlineSide::Right);
+
+    let result = rectangle
+        .closest_point(Vector2::new(-1.0, 1.0), 1e-5)
+        .unwrap();
+    assert_eq!(result.seg_start_index, 3);
+    assert_fuzzy_eq!(result.path_length, 11.0);
+    assert_fuzzy_eq!(result.seg_parametric, 0.5);
+    assert_eq!(result.side, PlineSide::Right);
+
+    let mut cw_rectangle = rectangle.clone();
+    cw_rectangle.invert_direction_mut();
+    assert_eq!(
+        cw_rectangle.closest_point(inside, 1e-5).unwrap().side,
+        PlineSide::Right
+    );
+    assert_eq!(
+        cw_rectangle.closest_point(outside, 1e-5).unwrap().side,
+        PlineSide::Left
+    );
+}
+
+#[test]
+fn arc_segments() {
+    // counter clockwise circle with radius 1 centered at (1, 0)
+    let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+
+    let result = circle.closest_point(Vector2::new(1.0, -2.0), 1e-5).unwrap();
+    assert_eq!(result.seg_start_index, 0);
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(1.0, -1.0));
+    assert_fuzzy_eq!(result.distance, 1.0);
+    assert_fuzzy_eq!(result.path_length, 0.5 * PI);
+    assert_fuzzy_eq!(result.seg_parametric, 0.5);
+    assert_eq!(result.side, PlineSide::Right);
+
+    let result = circle.closest_point(Vector2::new(1.0, 0.5), 1e-5).unwrap();
+    assert_eq!(result.seg_start_index, 1);
+    assert_fuzzy_eq!(result.seg_point, Vector2::new(1.0, 1.0));
+    assert_fuzzy_eq!(result.distance, 0.5);
+    assert_fuzzy_eq!(result.path_length, 1.5 * PI);
+    assert_fuzzy_eq!(result.seg_parametric, 0.5);
+    assert_eq!(result.side, PlineSide::Left);
+}
+
+#[test]
+fn using_aabb_index_matches_linear_scan() {
+    // zig zag polyline with arcs
+    let mut polyline = Polyline::new();
+    for i in 0..200 {
+        let x = i as f64;
+        let y = if i % 2 == 0 { 0.0 } else { 1.0 };
+        let bulge = if i % 3 == 0 { 0.5 } else { 0.0 };
+        polyline.add(x, y, bulge);
+    }
+
+    let approx_index = polyline.create_approx_aabb_index().unwrap();
+    let index = polyline.create_aabb_index().unwrap();
+
+    let test_points = [
+        Vector2::new(-5.0, -5.0),
+        Vector2::new(50.3, 0.25),
+        Vector2::new(100.7, 4.0),
+        Vector2::new(150.0, -3.0),
+        Vector2::new(210.0, 0.5),
+        Vector2::new(33.3, 0.75),
+    ];
+
+    let path_lengths = vertex_path_lengths(&polyline);
+    for &aabb_index in [&approx_index, &index].iter() {
+        for &lengths in [None, Some(path_lengths.as_slice())].iter() {
+            let options = PlineClosestPointOptions {
+                aabb_index: Some(aabb_index),
+                vertex_path_lengths: lengths,
+                ..Default::default()
+            };
+
+            for &pt in test_points.iter() {
+                let expected = polyline.closest_point(pt, 1e-5).unwrap();
+                let result = polyline.closest_point_opt(pt, &options).unwrap();
+                assert_fuzzy_eq!(result.seg_point, expected.seg_point);
+                assert_fuzzy_eq!(result.distance, expected.distance);
+                assert_fuzzy_eq!(result.path_length, expected.path_length);
+                assert_eq!(result.side, expected.side);
+            }
+        }
+    }
+}
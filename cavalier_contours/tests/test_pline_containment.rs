@@ -0,0 +1,112 @@
+mod test_utils;
+
+use cavalier_contours::{
+    pline_closed, pline_open,
+    polyline::{
+        BooleanOp, PlineContainmentTree, PlineOrientation, PlineSource, PlineSourceMut, Polyline,
+    },
+};
+use test_utils::square;
+
+#[test]
+fn empty_and_single() {
+    let plines: Vec<Polyline> = Vec::new();
+    let tree = PlineContainmentTree::build(&plines, 1e-5);
+    assert!(tree.nodes.is_empty());
+    assert!(tree.roots.is_empty());
+
+    let plines = vec![square(0.0, 0.0, 1.0)];
+    let tree = PlineContainmentTree::build(&plines, 1e-5);
+    assert_eq!(tree.roots, vec![0]);
+    assert_eq!(tree.nodes[0].depth, 0);
+    assert!(tree.nodes[0].is_outer());
+}
+
+#[test]
+fn nested_and_siblings() {
+    let plines = vec![
+        square(2.0, 2.0, 2.0),   // 0: hole in 3
+        square(30.0, 0.0, 5.0),  // 1: separate outer
+        square(2.5, 2.5, 1.0),   // 2: island in 0
+        square(0.0, 0.0, 20.0),  // 3: outer
+        square(10.0, 10.0, 5.0), // 4: hole in 3
+        square(31.0, 1.0, 1.0),  // 5: hole in 1
+    ];
+
+    let tree = PlineContainmentTree::build(&plines, 1e-5);
+    assert_eq!(tree.roots, vec![1, 3]);
+    let depths = tree.nodes.iter().map(|n| n.depth).collect::<Vec<_>>();
+    assert_eq!(depths, vec![1, 0, 2, 0, 1, 1]);
+    let parents = tree.nodes.iter().map(|n| n.parent).collect::<Vec<_>>();
+    assert_eq!(
+        parents,
+        vec![Some(3), None, Some(0), None, Some(3), Some(1)]
+    );
+    assert_eq!(tree.nodes[3].children, vec![0, 4]);
+
+    let regions = tree.outer_with_holes();
+    assert_eq!(regions, vec![(1, vec![5]), (2, vec![]), (3, vec![0, 4])]);
+}
+
+#[test]
+fn touching_loops() {
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        // inside touching the outer boundary along an edge
+        square(0.0, 0.0, 5.0),
+        // outside touching along an edge
+        square(10.0, 0.0, 5.0),
+        // duplicate of the first
+        square(0.0, 0.0, 10.0),
+    ];
+
+    let tree = PlineContainmentTree::build(&plines, 1e-5);
+    assert_eq!(tree.roots, vec![0, 2]);
+    assert_eq!(tree.nodes[3].parent, Some(0));
+    assert_eq!(tree.nodes[1].parent, Some(3));
+    assert_eq!(tree.nodes[1].depth, 2);
+}
+
+#[test]
+fn open_polylines_ignored() {
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        pline_open![(1.0, 1.0, 0.0), (2.0, 2.0, 0.0)],
+    ];
+    let tree = PlineContainmentTree::build(&plines, 1e-5);
+    assert_eq!(tree.roots, vec![0, 1]);
+    assert!(tree.nodes[0].children.is_empty());
+}
+
+#[test]
+fn fix_orientations_from_depth() {
+    let mut plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(2.0, 2.0, 6.0),
+        square(4.0, 4.0, 2.0),
+    ];
+    plines[0].invert_direction_mut();
+    let tree = PlineContainmentTree::build(&plines, 1e-5);
+    tree.fix_orientations(&mut plines);
+    assert_eq!(plines[0].orientation(), PlineOrientation::CounterClockwise);
+    assert_eq!(plines[1].orientation(), PlineOrientation::Clockwise);
+    assert_eq!(plines[2].orientation(), PlineOrientation::CounterClockwise);
+}
+
+#[test]
+fn from_boolean_result() {
+    let outer = square(0.0, 0.0, 10.0);
+    let hole = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    let result = outer.boolean(&hole, BooleanOp::Not);
+    let tree = PlineContainmentTree::build(
+        result
+            .pos_plines
+            .iter()
+            .chain(result.neg_plines.iter())
+            .map(|r| &r.pline),
+        1e-5,
+    );
+    assert_eq!(tree.roots, vec![0]);
+    assert_eq!(tree.nodes[1].parent, Some(0));
+    assert!(tree.nodes[1].is_hole());
+}
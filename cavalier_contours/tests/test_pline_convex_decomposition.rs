@@ -0,0 +1,204 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        convex_decomposition, seg_tangent_vector, ConvexDecompositionOptions, PlineSource, Polyline,
+    },
+};
+use std::f64::consts::PI;
+use test_utils::{circle, rect};
+
+fn decompose(plines: &[Polyline]) -> Vec<Polyline> {
+    convex_decomposition(plines, &ConvexDecompositionOptions::default())
+}
+
+fn total_area(plines: &[Polyline]) -> f64 {
+    plines.iter().map(|p| p.area()).sum()
+}
+
+/// Assert all pieces are closed, counter clockwise and turn left (or go straight) at every
+/// vertex.
+fn assert_convex(pieces: &[Polyline]) {
+    for piece in pieces.iter() {
+        assert!(piece.is_closed());
+        assert!(piece.area() > 0.0);
+        let n = piece.vertex_count();
+        for i in 0..n {
+            let prev = piece.at((i + n - 1) % n);
+            let v = piece.at(i);
+            let next = piece.at((i + 1) % n);
+            let incoming = seg_tangent_vector(prev, v, v.pos()).normalize();
+            let outgoing = seg_tangent_vector(v, next, v.pos()).normalize();
+            assert!(
+                incoming.perp_dot(outgoing) > -1e-6,
+                "piece {:?} not convex at {}",
+                piece,
+                i
+            );
+        }
+    }
+}
+
+/// Assert every sampled point further than `tolerance` from the region boundary is in exactly
+/// one piece if it is in the region and no piece otherwise.
+fn assert_covers_region(region: &[Polyline], pieces: &[Polyline], tolerance: f64) {
+    let extents = region[0].extents().unwrap();
+    let steps = 50;
+    for i in 0..=steps {
+        for j in 0..=steps {
+            // offset so samples do not fall on the diagonals between pieces
+            let p = Vector2::new(
+                extents.min_x + (extents.max_x - extents.min_x) * i as f64 / steps as f64 + 1.3e-3,
+                extents.min_y + (extents.max_y - extents.min_y) * j as f64 / steps as f64 + 0.7e-3,
+            );
+            let near_boundary = region
+                .iter()
+                .any(|pline| pline.closest_point(p, 1e-5).unwrap().distance < tolerance);
+            if near_boundary {
+                continue;
+            }
+            let in_region = region.iter().filter(|r| r.winding_number(p) != 0).count() % 2 == 1;
+            let containing = pieces
+                .iter()
+                .filter(|piece| piece.winding_number(p) != 0)
+                .count();
+            assert_eq!(containing, usize::from(in_region), "point {:?}", p);
+        }
+    }
+}
+
+#[test]
+fn empty_input() {
+    let empty: Vec<Polyline> = Vec::new();
+    assert!(decompose(&empty).is_empty());
+    assert!(decompose(&[pline_open![
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (1.0, 1.0, 0.0)
+    ]])
+    .is_empty());
+    assert!(decompose(&[rect(0.0, 0.0, 1.0, 0.0)]).is_empty());
+}
+
+#[test]
+fn convex_input_is_one_piece() {
+    let mut square = rect(0.0, 0.0, 2.0, 2.0);
+    let pieces = decompose(std::slice::from_ref(&square));
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].vertex_count(), 4);
+    assert_fuzzy_eq!(pieces[0].area(), 4.0);
+
+    // orientation does not matter
+    square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (0.0, 2.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 0.0, 0.0)
+    ];
+    let pieces = decompose(&[square]);
+    assert_eq!(pieces.len(), 1);
+    assert_fuzzy_eq!(pieces[0].area(), 4.0);
+
+    // arcs are kept
+    let pieces = decompose(&[circle(1.0, 1.0, 2.0)]);
+    assert_eq!(pieces.len(), 1);
+    assert_fuzzy_eq!(pieces[0].area(), 4.0 * PI);
+    assert!(pieces[0].iter_vertexes().all(|v| v.bulge > 0.0));
+
+    let stadium: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 1.0),
+        (4.0, 2.0, 0.0),
+        (0.0, 2.0, 1.0)
+    ];
+    let pieces = decompose(std::slice::from_ref(&stadium));
+    assert_eq!(pieces.len(), 1);
+    assert_fuzzy_eq!(pieces[0].area(), stadium.area());
+}
+
+#[test]
+fn l_shape() {
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (4.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let region = vec![pline];
+    let pieces = decompose(&region);
+    assert_eq!(pieces.len(), 2);
+    assert_convex(&pieces);
+    assert_fuzzy_eq!(total_area(&pieces), 7.0);
+    assert_covers_region(&region, &pieces, 1e-3);
+}
+
+#[test]
+fn square_with_hole() {
+    let region = vec![rect(0.0, 0.0, 10.0, 10.0), rect(4.0, 4.0, 6.0, 6.0)];
+    let pieces = decompose(&region);
+    assert!(pieces.len() >= 4);
+    assert_convex(&pieces);
+    assert_fuzzy_eq!(total_area(&pieces), 96.0);
+    assert_covers_region(&region, &pieces, 1e-3);
+}
+
+#[test]
+fn convex_and_concave_arcs() {
+    // right side bulges out (convex), left side bulges in (concave)
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.5),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, -0.5)
+    ];
+    let region = vec![pline];
+    let pieces = decompose(&region);
+    assert_convex(&pieces);
+    assert!(pieces
+        .iter()
+        .any(|p| p.iter_vertexes().any(|v| v.bulge > 0.0)));
+    assert!(pieces
+        .iter()
+        .all(|p| p.iter_vertexes().all(|v| v.bulge >= 0.0)));
+    // concave arc approximation only adds area within the error distance of the arc
+    let arc_length = 4.0 * 0.5f64.atan() * 12.5 / 2.0;
+    let area = total_area(&pieces);
+    assert!(area >= region[0].area() - 1e-5);
+    assert!(area <= region[0].area() + 1e-2 * arc_length);
+    assert_covers_region(&region, &pieces, 2e-2);
+
+    // circular hole is approximated
+    let region = vec![rect(-5.0, -5.0, 5.0, 5.0), circle(0.0, 0.0, 2.0)];
+    let options = ConvexDecompositionOptions {
+        arc_error_distance: 1e-3,
+        ..Default::default()
+    };
+    let pieces = convex_decomposition(&region, &options);
+    assert_convex(&pieces);
+    let area = total_area(&pieces);
+    let expected = 100.0 - 4.0 * PI;
+    assert!(area >= expected - 1e-5 && area <= expected + 1e-3 * 4.0 * PI);
+    assert_covers_region(&region, &pieces, 2e-3);
+}
+
+#[test]
+fn island_in_hole() {
+    let region = vec![
+        rect(0.0, 0.0, 10.0, 10.0),
+        rect(2.0, 2.0, 8.0, 8.0),
+        circle(5.0, 5.0, 1.0),
+    ];
+    let pieces = decompose(&region);
+    assert_convex(&pieces);
+    // island is a separate convex piece with its arcs kept
+    assert!(pieces.iter().any(|p| p.vertex_count() == 2
+        && p.iter_vertexes().all(|v| v.bulge > 0.0)
+        && (p.area() - PI).abs() < 1e-5));
+    assert_fuzzy_eq!(total_area(&pieces), 100.0 - 36.0 + PI);
+    assert_covers_region(&region, &pieces, 1e-3);
+}
@@ -0,0 +1,220 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        add_corner_relief, CornerReliefKind, CornerReliefOptions, PlineIntersect, PlineOrientation,
+        PlineSource, PlineSourceMut, Polyline,
+    },
+};
+use std::f64::consts::PI;
+
+/// L shape with one concave corner at (5, 5) (vertex index 3).
+fn l_shape() -> Polyline {
+    pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 5.0, 0.0),
+        (5.0, 5.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ]
+}
+
+fn self_intersect_count(pline: &Polyline) -> usize {
+    let mut count = 0;
+    pline.visit_self_intersects(&mut |_: PlineIntersect<f64>| count += 1);
+    count
+}
+
+fn has_vertex_at(pline: &Polyline, point: Vector2) -> bool {
+    pline.iter_vertexes().any(|v| v.pos().fuzzy_eq(point))
+}
+
+#[test]
+fn invalid_input() {
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(add_corner_relief(&open, &CornerReliefOptions::default()).is_none());
+    let collapsed: Polyline = pline_closed![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    assert!(add_corner_relief(&collapsed, &CornerReliefOptions::default()).is_none());
+}
+
+#[test]
+fn zero_tool_radius() {
+    let pline = l_shape();
+    let options = CornerReliefOptions {
+        tool_radius: 0.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert!(result.relieved_corners.is_empty());
+    assert_eq!(result.pline.vertex_count(), pline.vertex_count());
+}
+
+#[test]
+fn convex_square_unchanged() {
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let result = add_corner_relief(&pline, &CornerReliefOptions::default()).unwrap();
+    assert!(result.relieved_corners.is_empty());
+    assert!(result.skipped_corners.is_empty());
+    assert_eq!(result.pline.vertex_count(), 4);
+    assert_fuzzy_eq!(result.pline.area(), 100.0);
+}
+
+#[test]
+fn dogbone_l_shape() {
+    let pline = l_shape();
+    let options = CornerReliefOptions {
+        kind: CornerReliefKind::Dogbone,
+        tool_radius: 1.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert_eq!(result.relieved_corners, vec![3]);
+    assert!(result.skipped_corners.is_empty());
+
+    let relieved = &result.pline;
+    assert_eq!(relieved.vertex_count(), 8);
+    let chord = 2.0f64.sqrt();
+    assert!(has_vertex_at(relieved, Vector2::new(5.0 + chord, 5.0)));
+    assert!(has_vertex_at(relieved, Vector2::new(5.0, 5.0)));
+    assert!(has_vertex_at(relieved, Vector2::new(5.0, 5.0 + chord)));
+    // two quarter circle arcs cut into the material
+    assert_fuzzy_eq!(relieved.area(), 75.0 - (PI / 2.0 - 1.0));
+    assert_eq!(relieved.orientation(), PlineOrientation::CounterClockwise);
+    assert_eq!(self_intersect_count(relieved), 0);
+}
+
+#[test]
+fn dogbone_clockwise_l_shape() {
+    let mut pline = l_shape();
+    pline.invert_direction_mut();
+    let options = CornerReliefOptions {
+        tool_radius: 1.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert_eq!(result.relieved_corners.len(), 1);
+    assert_fuzzy_eq!(result.pline.area(), -(75.0 - (PI / 2.0 - 1.0)));
+    assert_eq!(self_intersect_count(&result.pline), 0);
+}
+
+#[test]
+fn t_bone_l_shape() {
+    let pline = l_shape();
+    let options = CornerReliefOptions {
+        kind: CornerReliefKind::TBoneIncoming,
+        tool_radius: 1.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert_eq!(result.relieved_corners, vec![3]);
+    let relieved = &result.pline;
+    // half circle from the corner extending past the corner along the incoming direction
+    assert_eq!(relieved.vertex_count(), 7);
+    assert_fuzzy_eq!(relieved[3].pos(), Vector2::new(5.0, 5.0));
+    assert_fuzzy_eq!(relieved[3].bulge, -1.0);
+    assert_fuzzy_eq!(relieved[4].pos(), Vector2::new(5.0, 7.0));
+    assert_fuzzy_eq!(relieved.area(), 75.0 - PI / 2.0);
+    assert_eq!(self_intersect_count(relieved), 0);
+
+    let options = CornerReliefOptions {
+        kind: CornerReliefKind::TBoneOutgoing,
+        tool_radius: 1.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    let relieved = &result.pline;
+    assert_eq!(relieved.vertex_count(), 7);
+    assert_fuzzy_eq!(relieved[3].pos(), Vector2::new(7.0, 5.0));
+    assert_fuzzy_eq!(relieved[3].bulge, -1.0);
+    assert_fuzzy_eq!(relieved[4].pos(), Vector2::new(5.0, 5.0));
+    assert_fuzzy_eq!(relieved.area(), 75.0 - PI / 2.0);
+    assert_eq!(self_intersect_count(relieved), 0);
+}
+
+#[test]
+fn material_outside_pocket() {
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let options = CornerReliefOptions {
+        tool_radius: 1.0,
+        material_outside: true,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert_eq!(result.relieved_corners, vec![0, 1, 2, 3]);
+    assert_eq!(result.pline.vertex_count(), 12);
+    assert_fuzzy_eq!(result.pline.area(), 100.0 + 4.0 * (PI / 2.0 - 1.0));
+    assert_eq!(self_intersect_count(&result.pline), 0);
+}
+
+#[test]
+fn relief_too_large_skipped() {
+    let pline = l_shape();
+    let options = CornerReliefOptions {
+        tool_radius: 4.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert!(result.relieved_corners.is_empty());
+    assert_eq!(result.skipped_corners, vec![3]);
+    assert_fuzzy_eq!(result.pline.area(), 75.0);
+}
+
+#[test]
+fn overlapping_neighbor_relief_skipped() {
+    // narrow slot cut into the top of a rectangle, the slot bottom is too short for both reliefs
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (5.75, 10.0, 0.0),
+        (5.75, 5.0, 0.0),
+        (4.25, 5.0, 0.0),
+        (4.25, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let options = CornerReliefOptions {
+        tool_radius: 1.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert_eq!(result.relieved_corners, vec![4]);
+    assert_eq!(result.skipped_corners, vec![5]);
+    assert_eq!(self_intersect_count(&result.pline), 0);
+}
+
+#[test]
+fn relief_intersecting_polyline_skipped() {
+    // slot cut into the bottom arm leaving a thin wall below the concave corner at (5, 5)
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (5.0, 4.8, 0.0),
+        (6.5, 4.8, 0.0),
+        (6.5, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 5.0, 0.0),
+        (5.0, 5.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let options = CornerReliefOptions {
+        tool_radius: 1.0,
+        ..Default::default()
+    };
+    let result = add_corner_relief(&pline, &options).unwrap();
+    assert!(result.skipped_corners.contains(&7));
+    assert!(!result.relieved_corners.contains(&7));
+    assert_eq!(self_intersect_count(&result.pline), 0);
+}
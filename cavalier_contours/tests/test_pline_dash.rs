@@ -0,0 +1,235 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        dash_pattern, dash_pattern_views, DashOptions, PlineCreation, PlineSource, PlineSourceMut,
+        Polyline,
+    },
+};
+use std::f64::consts::PI;
+
+fn dash_lengths(pline: &Polyline, options: &DashOptions) -> Vec<f64> {
+    dash_pattern(pline, options)
+        .iter()
+        .map(|d| d.path_length())
+        .collect()
+}
+
+#[test]
+fn empty_input() {
+    let options = DashOptions {
+        pattern: &[1.0, 1.0],
+        ..Default::default()
+    };
+    assert!(dash_pattern_views(&Polyline::<f64>::new(), &options).is_empty());
+    let point: Polyline = pline_open![(1.0, 1.0, 0.0)];
+    assert!(dash_pattern(&point, &options).is_empty());
+    let zero_length: Polyline = pline_open![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    assert!(dash_pattern(&zero_length, &options).is_empty());
+}
+
+#[test]
+fn open_line() {
+    let pline: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let options = DashOptions {
+        pattern: &[2.0, 1.0],
+        ..Default::default()
+    };
+    let dashes = dash_pattern(&pline, &options);
+    assert_eq!(dashes.len(), 4);
+    for (i, dash) in dashes.iter().enumerate() {
+        assert!(!dash.is_closed());
+        assert_fuzzy_eq!(dash.at(0).x, 3.0 * i as f64);
+    }
+    assert_eq!(dash_lengths(&pline, &options), vec![2.0, 2.0, 2.0, 1.0]);
+
+    // phase starts part way into the pattern
+    let options = DashOptions {
+        pattern: &[2.0, 1.0],
+        phase: 1.0,
+        ..Default::default()
+    };
+    let dashes = dash_pattern(&pline, &options);
+    assert_eq!(dashes.len(), 4);
+    assert_fuzzy_eq!(dashes[0].path_length(), 1.0);
+    assert_fuzzy_eq!(dashes[1].at(0).x, 2.0);
+    assert_fuzzy_eq!(dashes[3].at(0).x, 8.0);
+    assert_fuzzy_eq!(dashes[3].path_length(), 2.0);
+
+    // negative phase wraps, starting in the gap
+    let options = DashOptions {
+        pattern: &[2.0, 1.0],
+        phase: -0.5,
+        ..Default::default()
+    };
+    let dashes = dash_pattern(&pline, &options);
+    assert_fuzzy_eq!(dashes[0].at(0).x, 0.5);
+
+    // odd pattern is repeated
+    let options = DashOptions {
+        pattern: &[1.0],
+        ..Default::default()
+    };
+    assert_eq!(dash_lengths(&pline, &options), vec![1.0; 5]);
+
+    // no pattern is a solid line
+    let options = DashOptions::default();
+    let dashes = dash_pattern(&pline, &options);
+    assert_eq!(dashes.len(), 1);
+    assert_fuzzy_eq!(dashes[0].path_length(), 10.0);
+}
+
+#[test]
+fn dashes_over_vertexes_and_arcs() {
+    let pline: Polyline = pline_open![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 1.0),
+        (4.0, 4.0, 0.0),
+        (0.0, 4.0, -0.5),
+        (0.0, 8.0, 0.0)
+    ];
+    let options = DashOptions {
+        pattern: &[1.5, 0.5],
+        phase: 0.3,
+        ..Default::default()
+    };
+    let views = dash_pattern_views(&pline, &options);
+    let dashes = dash_pattern(&pline, &options);
+    assert_eq!(views.len(), dashes.len());
+
+    let total = pline.path_length();
+    let mut dash_total = 0.0;
+    for (view_data, dash) in views.iter().zip(dashes.iter()) {
+        let view = view_data.view(&pline);
+        assert_eq!(view.vertex_count(), dash.vertex_count());
+        assert_fuzzy_eq!(view.path_length(), dash.path_length());
+        // dash ends lie on the polyline
+        for v in [dash.at(0), dash.last().unwrap()].iter() {
+            let closest = pline.closest_point(v.pos(), 1e-5).unwrap();
+            assert!(closest.distance < 1e-5);
+        }
+        assert!(dash.path_length() <= 1.5 + 1e-5);
+        dash_total += dash.path_length();
+    }
+
+    // pattern covers 3/4 of the path except for the partial dashes at the ends
+    let expected: f64 = {
+        let mut sum = 0.0;
+        let mut start = -0.3;
+        while start < total {
+            let s = start.max(0.0);
+            let e = (start + 1.5).min(total);
+            if e > s {
+                sum += e - s;
+            }
+            start += 2.0;
+        }
+        sum
+    };
+    assert_fuzzy_eq!(dash_total, expected);
+}
+
+#[test]
+fn closed_joins_across_start() {
+    let square: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let options = DashOptions {
+        pattern: &[3.0, 1.0],
+        ..Default::default()
+    };
+    assert_eq!(dash_lengths(&square, &options), vec![3.0; 10]);
+
+    // dash running through the last vertex continues past the first vertex
+    let options = DashOptions {
+        pattern: &[3.0, 1.0],
+        phase: 2.0,
+        ..Default::default()
+    };
+    let dashes = dash_pattern(&square, &options);
+    assert_eq!(dashes.len(), 10);
+    for dash in dashes.iter() {
+        assert_fuzzy_eq!(dash.path_length(), 3.0);
+    }
+    assert!(dashes[0].at(0).pos().fuzzy_eq(Vector2::new(0.0, 2.0)));
+    assert!(dashes[0].at(1).pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    assert!(dashes[0]
+        .last()
+        .unwrap()
+        .pos()
+        .fuzzy_eq(Vector2::new(1.0, 0.0)));
+
+    // pattern without gaps gives the whole loop
+    let options = DashOptions {
+        pattern: &[3.0, 0.0],
+        ..Default::default()
+    };
+    let dashes = dash_pattern(&square, &options);
+    assert_eq!(dashes.len(), 1);
+    assert_fuzzy_eq!(dashes[0].path_length(), 40.0);
+}
+
+#[test]
+fn fit_closed() {
+    let circle: Polyline = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    let circumference = 10.0 * PI;
+    let options = DashOptions {
+        pattern: &[2.0, 1.0],
+        phase: 0.5,
+        fit_closed: true,
+        ..Default::default()
+    };
+    // 31.4 / 3 rounds to 10 repeats
+    let scale = circumference / 30.0;
+    let lengths = dash_lengths(&circle, &options);
+    assert_eq!(lengths.len(), 10);
+    for length in lengths {
+        assert_fuzzy_eq!(length, 2.0 * scale);
+    }
+
+    // without fitting the dash joined across the start is longer
+    let options = DashOptions {
+        fit_closed: false,
+        ..options
+    };
+    let lengths = dash_lengths(&circle, &options);
+    assert_eq!(lengths.len(), 10);
+    assert_fuzzy_eq!(lengths[0], circumference - 29.5 + 1.5);
+    for &length in lengths[1..].iter() {
+        assert_fuzzy_eq!(length, 2.0);
+    }
+
+    // fitting does not affect open polylines
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let options = DashOptions {
+        pattern: &[2.0, 2.0],
+        fit_closed: true,
+        ..Default::default()
+    };
+    assert_eq!(dash_lengths(&open, &options), vec![2.0, 2.0, 2.0]);
+}
+
+#[test]
+fn many_vertexes() {
+    // zig zag with many vertexes and many dashes
+    let mut pline = Polyline::with_capacity(2000, false);
+    for i in 0..2000 {
+        let y = if i % 2 == 0 { 0.0 } else { 1.0 };
+        pline.add(i as f64, y, 0.0);
+    }
+    let options = DashOptions {
+        pattern: &[0.3, 0.2],
+        ..Default::default()
+    };
+    let dashes = dash_pattern(&pline, &options);
+    let total = pline.path_length();
+    let full_periods = (total / 0.5).floor();
+    assert!(dashes.len() as f64 >= full_periods);
+    let dash_total: f64 = dashes.iter().map(|d| d.path_length()).sum();
+    let remainder = total - full_periods * 0.5;
+    assert_fuzzy_eq!(dash_total, full_periods * 0.3 + remainder.min(0.3), 1e-6);
+}
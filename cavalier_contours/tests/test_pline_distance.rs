@@ -0,0 +1,188 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::{dist_squared, Vector2},
+    pline_closed, pline_open,
+    polyline::{
+        seg_closest_points, PlineDistanceOptions, PlineSource, PlineSourceMut, PlineVertex,
+        Polyline,
+    },
+};
+
+/// Brute force minimum distance over all segment pairs used to check results.
+fn brute_force_distance(pline1: &Polyline, pline2: &Polyline) -> f64 {
+    let mut min_dist2 = f64::MAX;
+    for (v1, v2) in pline1.iter_segments() {
+        for (u1, u2) in pline2.iter_segments() {
+            let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+            min_dist2 = min_dist2.min(dist_squared(p1, p2));
+        }
+    }
+    min_dist2.sqrt()
+}
+
+#[test]
+fn seg_closest_points_lines() {
+    let v1 = PlineVertex::new(0.0, 0.0, 0.0);
+    let v2 = PlineVertex::new(2.0, 0.0, 0.0);
+    // parallel line above
+    let u1 = PlineVertex::new(1.0, 1.0, 0.0);
+    let u2 = PlineVertex::new(5.0, 1.0, 0.0);
+    let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+    assert_fuzzy_eq!(dist_squared(p1, p2), 1.0);
+
+    // crossing line
+    let u1 = PlineVertex::new(1.0, -1.0, 0.0);
+    let u2 = PlineVertex::new(1.0, 1.0, 0.0);
+    let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+    assert_fuzzy_eq!(p1, Vector2::new(1.0, 0.0));
+    assert_fuzzy_eq!(p2, Vector2::new(1.0, 0.0));
+
+    // end point to end point
+    let u1 = PlineVertex::new(3.0, 1.0, 0.0);
+    let u2 = PlineVertex::new(4.0, 2.0, 0.0);
+    let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+    assert_fuzzy_eq!(p1, Vector2::new(2.0, 0.0));
+    assert_fuzzy_eq!(p2, Vector2::new(3.0, 1.0));
+}
+
+#[test]
+fn seg_closest_points_arcs() {
+    // arc bulging up from (0, 0) to (2, 0), top at (1, 1)
+    let v1 = PlineVertex::new(0.0, 0.0, -1.0);
+    let v2 = PlineVertex::new(2.0, 0.0, 0.0);
+    // arc bulging down from (0, 3) to (2, 3), bottom at (1, 2)
+    let u1 = PlineVertex::new(0.0, 3.0, 1.0);
+    let u2 = PlineVertex::new(2.0, 3.0, 0.0);
+    let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+    assert_fuzzy_eq!(p1, Vector2::new(1.0, 1.0));
+    assert_fuzzy_eq!(p2, Vector2::new(1.0, 2.0));
+
+    // line above the arc
+    let u1 = PlineVertex::new(-3.0, 2.0, 0.0);
+    let u2 = PlineVertex::new(3.0, 2.0, 0.0);
+    let (p1, p2) = seg_closest_points(v1, v2, u1, u2, 1e-5);
+    assert_fuzzy_eq!(p1, Vector2::new(1.0, 1.0));
+    assert_fuzzy_eq!(p2, Vector2::new(1.0, 2.0));
+    let (p2, p1) = seg_closest_points(u1, u2, v1, v2, 1e-5);
+    assert_fuzzy_eq!(p1, Vector2::new(1.0, 1.0));
+    assert_fuzzy_eq!(p2, Vector2::new(1.0, 2.0));
+}
+
+#[test]
+fn empty_and_single_vertex() {
+    let empty: Polyline = Polyline::new();
+    let rectangle = pline_closed![
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ];
+    assert!(empty.distance_to(&rectangle).is_none());
+    assert!(rectangle.distance_to(&empty).is_none());
+
+    let point = pline_open![(1.0, 5.0, 0.0)];
+    let result = point.distance_to(&rectangle).unwrap();
+    assert_fuzzy_eq!(result.distance, 3.0);
+    assert_fuzzy_eq!(result.point1, Vector2::new(1.0, 5.0));
+    assert_fuzzy_eq!(result.point2, Vector2::new(1.0, 2.0));
+    assert_eq!(result.seg_start_index2, 2);
+
+    let result = rectangle.distance_to(&point).unwrap();
+    assert_fuzzy_eq!(result.distance, 3.0);
+    assert_fuzzy_eq!(result.point1, Vector2::new(1.0, 2.0));
+    assert_eq!(result.seg_start_index1, 2);
+    assert_fuzzy_eq!(result.point2, Vector2::new(1.0, 5.0));
+}
+
+#[test]
+fn intersecting_is_zero() {
+    let rectangle = pline_closed![
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ];
+    let circle = pline_closed![(1.0, 1.0, 1.0), (3.0, 1.0, 1.0)];
+    let result = rectangle.distance_to(&circle).unwrap();
+    assert_fuzzy_eq!(result.distance, 0.0);
+    assert_fuzzy_eq!(result.point1, result.point2);
+    assert!(
+        rectangle
+            .closest_point(result.point1, 1e-5)
+            .unwrap()
+            .distance
+            < 1e-5
+    );
+    assert!(circle.closest_point(result.point2, 1e-5).unwrap().distance < 1e-5);
+}
+
+#[test]
+fn nested_polylines() {
+    let outer = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    // circle with radius 2 centered at (7, 5)
+    let inner = pline_closed![(5.0, 5.0, 1.0), (9.0, 5.0, 1.0)];
+    let result = outer.distance_to(&inner).unwrap();
+    assert_fuzzy_eq!(result.distance, 1.0);
+    assert_eq!(result.seg_start_index1, 1);
+    assert_fuzzy_eq!(result.point1, Vector2::new(10.0, 5.0));
+    assert_fuzzy_eq!(result.point2, Vector2::new(9.0, 5.0));
+
+    let result = inner.distance_to(&outer).unwrap();
+    assert_fuzzy_eq!(result.distance, 1.0);
+    assert_fuzzy_eq!(result.point1, Vector2::new(9.0, 5.0));
+    assert_fuzzy_eq!(result.point2, Vector2::new(10.0, 5.0));
+}
+
+#[test]
+fn with_provided_indexes() {
+    let pline1 = pline_open![(0.0, 0.0, 0.5), (5.0, 0.0, -0.5), (10.0, 0.0, 0.0)];
+    let pline2 = pline_open![(0.0, 4.0, -0.3), (5.0, 4.0, 0.3), (10.0, 4.0, 0.0)];
+    let index1 = pline1.create_aabb_index().unwrap();
+    let index2 = pline2.create_approx_aabb_index().unwrap();
+    let options = PlineDistanceOptions {
+        pline1_aabb_index: Some(&index1),
+        pline2_aabb_index: Some(&index2),
+        ..Default::default()
+    };
+    let result = pline1.distance_to_opt(&pline2, &options).unwrap();
+    assert_fuzzy_eq!(result.distance, brute_force_distance(&pline1, &pline2));
+    assert_fuzzy_eq!((result.point1 - result.point2).length(), result.distance);
+}
+
+#[test]
+fn matches_brute_force() {
+    // two wavy polylines with many segments offset from each other
+    let create_wave = |y_offset: f64, phase: f64, is_closed: bool| {
+        let mut pline = Polyline::new();
+        for i in 0..100 {
+            let x = i as f64 * 0.5;
+            let y = y_offset + (x * 0.7 + phase).sin() * 2.0;
+            let bulge = if i % 4 == 0 { 0.3 } else { -0.1 };
+            pline.add(x, y, bulge);
+        }
+        pline.set_is_closed(is_closed);
+        pline
+    };
+
+    let cases = [
+        (create_wave(0.0, 0.0, false), create_wave(5.0, 1.3, false)),
+        (create_wave(0.0, 0.0, false), create_wave(3.0, 2.1, true)),
+        (create_wave(0.0, 0.5, true), create_wave(-6.0, 0.0, false)),
+        (create_wave(0.0, 0.0, false), create_wave(1.0, 0.0, false)),
+    ];
+
+    for (pline1, pline2) in cases.iter() {
+        let result = pline1.distance_to(pline2).unwrap();
+        let expected = brute_force_distance(pline1, pline2);
+        assert_fuzzy_eq!(result.distance, expected);
+        assert_fuzzy_eq!((result.point1 - result.point2).length(), result.distance);
+        // reversed arguments give the same distance
+        let result = pline2.distance_to(pline1).unwrap();
+        assert_fuzzy_eq!(result.distance, expected);
+    }
+}
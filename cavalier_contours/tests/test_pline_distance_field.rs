@@ -0,0 +1,101 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{DistanceField, PlineSource, PlineSourceMut, Polyline, RasterGrid},
+};
+use test_utils::square;
+
+#[test]
+fn empty_input() {
+    let empty: Vec<Polyline> = Vec::new();
+    let field = DistanceField::new(&empty, 1e-5);
+    assert!(field.distance(Vector2::zero()).is_none());
+    assert!(field.signed_distance(Vector2::zero()).is_none());
+    assert!(field.closest_point(Vector2::zero()).is_none());
+    assert!(field.aabb_index().is_none());
+    assert!(field
+        .sample_grid(&RasterGrid::new(Vector2::zero(), 1.0, 4, 4))
+        .is_empty());
+}
+
+#[test]
+fn square_with_hole() {
+    let mut hole = square(4.0, 4.0, 2.0);
+    hole.invert_direction_mut();
+    let plines = vec![square(0.0, 0.0, 10.0), hole];
+    let field = DistanceField::new(&plines, 1e-5);
+
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(1.0, 5.0)).unwrap(), -1.0);
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(5.0, 5.0)).unwrap(), 1.0);
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(13.0, 5.0)).unwrap(), 3.0);
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(10.0, 5.0)).unwrap(), 0.0);
+
+    let closest = field.closest_point(Vector2::new(5.0, 3.0)).unwrap();
+    assert_eq!(closest.pline_index, 1);
+    assert_fuzzy_eq!(closest.point, Vector2::new(5.0, 4.0));
+    assert_fuzzy_eq!(closest.distance, 1.0);
+    let closest = field.closest_point(Vector2::new(5.0, 1.5)).unwrap();
+    assert_eq!(closest.pline_index, 0);
+    assert_eq!(closest.seg_start_index, 0);
+}
+
+#[test]
+fn open_polyline_unsigned() {
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0)];
+    let field = DistanceField::new(std::iter::once(&open), 1e-5);
+    // closed polyline with the same vertexes would contain these points
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(5.0, 0.5)).unwrap(), 0.5);
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(5.0, -2.0)).unwrap(), 2.0);
+    // arc bulges out to the right with center (10, 5) and radius 5
+    assert_fuzzy_eq!(field.distance(Vector2::new(17.0, 5.0)).unwrap(), 2.0);
+    let closest = field.closest_point(Vector2::new(11.0, 5.0)).unwrap();
+    assert_eq!(closest.seg_start_index, 1);
+    assert_fuzzy_eq!(closest.point, Vector2::new(15.0, 5.0));
+
+    // open polylines mixed with closed ones still only contribute to the distance
+    let plines = vec![open, square(20.0, 0.0, 4.0)];
+    let field = DistanceField::new(&plines, 1e-5);
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(5.0, 0.5)).unwrap(), 0.5);
+    assert_fuzzy_eq!(
+        field.signed_distance(Vector2::new(21.0, 2.0)).unwrap(),
+        -1.0
+    );
+    assert_fuzzy_eq!(field.signed_distance(Vector2::new(26.0, 2.0)).unwrap(), 2.0);
+}
+
+#[test]
+fn sample_grid_matches_closest_point() {
+    let plines: Vec<Polyline> = vec![
+        pline_closed![
+            (0.0, 0.0, 0.3),
+            (8.0, 0.0, 0.0),
+            (8.0, 6.0, -0.5),
+            (3.0, 4.0, 0.0),
+            (0.0, 6.0, 0.0)
+        ],
+        pline_open![(10.0, -2.0, 0.0), (12.0, 7.0, 0.8), (15.0, 1.0, 0.0)],
+    ];
+    let field = DistanceField::new(&plines, 1e-5);
+    let grid = RasterGrid::new(Vector2::new(-3.0, -4.0), 0.37, 60, 40);
+    let samples = field.sample_grid(&grid);
+    assert_eq!(samples.len(), 60 * 40);
+
+    for row in 0..grid.height {
+        for col in 0..grid.width {
+            let p = grid.pixel_center(col, row);
+            let brute_force = plines
+                .iter()
+                .map(|pline| pline.closest_point(p, 1e-5).unwrap().distance)
+                .fold(f64::MAX, f64::min);
+            let expected = if plines[0].winding_number(p) != 0 {
+                -brute_force
+            } else {
+                brute_force
+            };
+            assert_fuzzy_eq!(samples[row * grid.width + col], expected);
+        }
+    }
+}
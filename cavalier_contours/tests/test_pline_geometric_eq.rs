@@ -0,0 +1,115 @@
+use cavalier_contours::{
+    pline_closed, pline_open,
+    polyline::{PlineSource, PlineSourceMut, Polyline},
+};
+
+#[test]
+fn empty_and_single_vertex() {
+    let empty1: Polyline = Polyline::new();
+    let empty2: Polyline = Polyline::new();
+    assert!(empty1.geometric_eq(&empty2));
+    assert!(!empty1.geometric_eq(&Polyline::new_closed()));
+
+    let point1: Polyline = pline_open![(1.0, 1.0, 0.0)];
+    let point2: Polyline = pline_open![(1.0, 1.0, 0.5)];
+    // bulge of last vertex of an open polyline is not part of its path
+    assert!(!point1.fuzzy_eq(&point2));
+    assert!(point1.geometric_eq(&point2));
+    assert!(!point1.geometric_eq(&empty1));
+}
+
+#[test]
+fn open_reversed_and_redundant() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.5), (4.0, 2.0, 0.0)];
+    let mut reversed = pline.clone();
+    reversed.invert_direction_mut();
+    assert!(!pline.fuzzy_eq(&reversed));
+    assert!(pline.geometric_eq(&reversed));
+
+    let with_redundant = pline_open![
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 0.0, 0.5),
+        (4.0, 2.0, 0.0)
+    ];
+    assert!(pline.geometric_eq_eps(&with_redundant, 1e-5));
+
+    // different bulge
+    let other = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, -0.5), (4.0, 2.0, 0.0)];
+    assert!(!pline.geometric_eq(&other));
+
+    // closed vs open
+    let mut closed = pline.clone();
+    closed.set_is_closed(true);
+    assert!(!pline.geometric_eq(&closed));
+}
+
+#[test]
+fn closed_rotated_and_reversed() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 1.0),
+        (4.0, 2.0, 0.0),
+        (2.0, 3.0, -0.3),
+        (0.0, 2.0, 0.0)
+    ];
+
+    for start in 0..pline.vertex_count() {
+        let rotated = pline
+            .rotate_start(start, pline.at(start).pos(), 1e-5)
+            .unwrap();
+        assert!(pline.geometric_eq(&rotated));
+        assert!(rotated.geometric_eq(&pline));
+
+        let mut reversed = rotated.clone();
+        reversed.invert_direction_mut();
+        assert!(pline.geometric_eq(&reversed));
+
+        // rotate start to middle of a segment splits it, adding a redundant vertex
+        let (v1, v2) = (pline.at(start), pline.at(pline.next_wrapping_index(start)));
+        let mid = cavalier_contours::polyline::seg_midpoint(v1, v2);
+        let split = pline.rotate_start(start, mid, 1e-5).unwrap();
+        assert_eq!(split.vertex_count(), pline.vertex_count() + 1);
+        assert!(pline.geometric_eq_eps(&split, 1e-5));
+    }
+
+    let mut different = pline.clone();
+    different.set_vertex(3, different.at(3).with_bulge(0.3));
+    assert!(!pline.geometric_eq(&different));
+}
+
+#[test]
+fn canonical_form_is_stable() {
+    let pline = pline_closed![
+        (0.0, 1.0, 0.0),
+        (4.0, 0.0, 1.0),
+        (4.0, 2.0, 0.0),
+        (2.0, 3.0, -0.3),
+        (0.0, 2.0, 0.0),
+        (0.0, 1.5, 0.0)
+    ];
+
+    let canonical = pline.canonical_form(1e-5);
+    assert!(canonical.geometric_eq_eps(&pline, 1e-5));
+    // redundant vertex removed, counter clockwise, starting at smallest position
+    assert_eq!(canonical.vertex_count(), 5);
+    assert!(canonical.area() > 0.0);
+    assert!(canonical.at(0).pos().fuzzy_eq(pline.at(0).pos()));
+
+    for start in 0..pline.vertex_count() {
+        let mut other = pline
+            .rotate_start(start, pline.at(start).pos(), 1e-5)
+            .unwrap();
+        assert!(other.canonical_form(1e-5).fuzzy_eq(&canonical));
+        other.invert_direction_mut();
+        assert!(other.canonical_form(1e-5).fuzzy_eq(&canonical));
+    }
+
+    let open = pline_open![(3.0, 0.0, 0.5), (2.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+    let canonical = open.canonical_form(1e-5);
+    assert!(canonical.at(0).pos().fuzzy_eq(open.at(2).pos()));
+    let mut reversed = open.clone();
+    reversed.invert_direction_mut();
+    assert!(reversed.canonical_form(1e-5).fuzzy_eq(&canonical));
+}
@@ -0,0 +1,318 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        hatch_fill, HatchOptions, PlineSource, PlineSourceMut, PointLocation, PointLocator,
+        Polyline,
+    },
+};
+use test_utils::{circle, square};
+
+fn total_length(plines: &[Polyline]) -> f64 {
+    plines.iter().map(|p| p.path_length()).sum()
+}
+
+/// Assert all vertexes and segment midpoints of the results are inside or on the region.
+fn assert_within_region(results: &[Polyline], region: &[Polyline]) {
+    let locator = PointLocator::new(region, 1e-5);
+    for p in results {
+        assert!(!p.is_closed());
+        for v in p.iter_vertexes() {
+            assert_ne!(locator.locate(v.pos()), PointLocation::Outside);
+        }
+        for (v1, v2) in p.iter_segments() {
+            let mid = cavalier_contours::polyline::seg_midpoint(v1, v2);
+            assert_ne!(locator.locate(mid), PointLocation::Outside);
+        }
+    }
+}
+
+#[test]
+fn empty_and_invalid() {
+    let empty: Vec<Polyline> = Vec::new();
+    assert!(hatch_fill(&empty, &HatchOptions::default()).is_empty());
+
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    assert!(hatch_fill(&[open], &HatchOptions::default()).is_empty());
+
+    let region = [square(0.0, 0.0, 10.0)];
+    for &spacing in [0.0, -1.0, f64::NAN, f64::INFINITY].iter() {
+        let options = HatchOptions {
+            spacing,
+            ..Default::default()
+        };
+        assert!(hatch_fill(&region, &options).is_empty());
+    }
+    for &phase in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+        let options = HatchOptions {
+            phase,
+            ..Default::default()
+        };
+        assert!(hatch_fill(&region, &options).is_empty());
+    }
+    // spacing too small for the line indexes to be represented
+    let options = HatchOptions {
+        spacing: 1e-300,
+        pos_equal_eps: 1e-310,
+        ..Default::default()
+    };
+    assert!(hatch_fill(&region, &options).is_empty());
+}
+
+#[test]
+fn square_lines() {
+    let options = HatchOptions {
+        spacing: 2.0,
+        phase: 1.0,
+        ..Default::default()
+    };
+    let lines = hatch_fill(&[square(0.0, 0.0, 10.0)], &options);
+    assert_eq!(lines.len(), 5);
+    for (i, l) in lines.iter().enumerate() {
+        let y = 1.0 + 2.0 * i as f64;
+        assert_eq!(l.vertex_count(), 2);
+        assert_fuzzy_eq!(l[0].pos(), Vector2::new(0.0, y));
+        assert_fuzzy_eq!(l[1].pos(), Vector2::new(10.0, y));
+    }
+
+    // orientation of input does not matter
+    let mut cw_square = square(0.0, 0.0, 10.0);
+    cw_square.invert_direction_mut();
+    let cw_lines = hatch_fill(&[cw_square], &options);
+    assert_eq!(cw_lines.len(), 5);
+    assert_fuzzy_eq!(total_length(&cw_lines), 50.0);
+}
+
+#[test]
+fn lines_along_edges() {
+    // lines at y = 0, 1, ..., 10, line along the top edge has no interior
+    let lines = hatch_fill(&[square(0.0, 0.0, 10.0)], &HatchOptions::default());
+    assert_eq!(lines.len(), 10);
+    assert_fuzzy_eq!(total_length(&lines), 100.0);
+
+    // diamond with vertexes lying on hatch lines
+    let diamond = pline_closed![
+        (0.0, -5.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (0.0, 5.0, 0.0),
+        (-5.0, 0.0, 0.0),
+    ];
+    let lines = hatch_fill(std::iter::once(&diamond), &HatchOptions::default());
+    assert_eq!(lines.len(), 9);
+    assert_fuzzy_eq!(lines[4][0].pos(), Vector2::new(-5.0, 0.0));
+    assert_fuzzy_eq!(lines[4][1].pos(), Vector2::new(5.0, 0.0));
+    assert_within_region(&lines, &[diamond]);
+}
+
+#[test]
+fn exact_arc_clipping() {
+    let c = circle(3.0, -2.0, 5.0);
+    let options = HatchOptions {
+        spacing: 0.7,
+        ..Default::default()
+    };
+    let lines = hatch_fill(std::iter::once(&c), &options);
+    assert!(!lines.is_empty());
+    for l in lines.iter() {
+        assert_eq!(l.vertex_count(), 2);
+        for v in l.iter_vertexes() {
+            assert_fuzzy_eq!((v.pos() - Vector2::new(3.0, -2.0)).length(), 5.0, 1e-9);
+        }
+        // chord length
+        let dy = l[0].y + 2.0;
+        assert_fuzzy_eq!(l.path_length(), 2.0 * (25.0 - dy * dy).sqrt(), 1e-9);
+    }
+}
+
+#[test]
+fn holes_and_islands() {
+    let outer = square(0.0, 0.0, 20.0);
+    let hole = circle(10.0, 10.0, 6.0);
+    let island = square(0.0, 0.0, 4.0);
+    let island = {
+        let mut p = island;
+        p.translate_mut(8.0, 8.0);
+        p
+    };
+    let region = vec![hole, island, outer];
+    let options = HatchOptions {
+        spacing: 1.0,
+        phase: 0.5,
+        ..Default::default()
+    };
+    let lines = hatch_fill(&region, &options);
+    assert_within_region(&lines, &region);
+
+    // line at y = 10.5 crosses outer, hole, and island
+    let at_y = lines
+        .iter()
+        .filter(|l| (l[0].y - 10.5).abs() < 1e-9)
+        .collect::<Vec<_>>();
+    assert_eq!(at_y.len(), 3);
+    let hole_dx = (36.0f64 - 0.25).sqrt();
+    assert_fuzzy_eq!(at_y[0][1].x, 10.0 - hole_dx);
+    assert_fuzzy_eq!(at_y[1][0].x, 8.0);
+    assert_fuzzy_eq!(at_y[1][1].x, 12.0);
+    assert_fuzzy_eq!(at_y[2][0].x, 10.0 + hole_dx);
+
+    // holes given with any orientation
+    let mut flipped = region.clone();
+    for p in flipped.iter_mut() {
+        p.invert_direction_mut();
+    }
+    let flipped_lines = hatch_fill(&flipped, &options);
+    assert_eq!(flipped_lines.len(), lines.len());
+    assert_fuzzy_eq!(total_length(&flipped_lines), total_length(&lines));
+}
+
+#[test]
+fn angled_lines() {
+    let options = HatchOptions {
+        angle: std::f64::consts::FRAC_PI_4,
+        spacing: 1.0,
+        ..Default::default()
+    };
+    let region = [square(0.0, 0.0, 10.0)];
+    let lines = hatch_fill(&region, &options);
+    assert_within_region(&lines, &region);
+    let dir = Vector2::new(1.0, 1.0).normalize();
+    for l in lines.iter() {
+        let v = l[1].pos() - l[0].pos();
+        assert_fuzzy_eq!(v.normalize(), dir);
+        // lines are spaced from the origin
+        let offset = dir.perp_dot(l[0].pos());
+        assert_fuzzy_eq!(offset, offset.round());
+    }
+    // area covered by lines at spacing 1 is about the area of the square
+    assert!((total_length(&lines) - 100.0).abs() < 10.0);
+}
+
+#[test]
+fn margin() {
+    let options = HatchOptions {
+        spacing: 1.0,
+        phase: 0.5,
+        margin: 2.0,
+        ..Default::default()
+    };
+    let lines = hatch_fill(&[square(0.0, 0.0, 10.0)], &options);
+    assert_eq!(lines.len(), 6);
+    for l in lines.iter() {
+        assert!(l[0].y > 2.0 && l[0].y < 8.0);
+        assert_fuzzy_eq!(l[0].x, 2.0);
+        assert_fuzzy_eq!(l[1].x, 8.0);
+    }
+
+    // margin larger than region collapses it
+    let options = HatchOptions {
+        margin: 6.0,
+        ..options
+    };
+    assert!(hatch_fill(&[square(0.0, 0.0, 10.0)], &options).is_empty());
+}
+
+#[test]
+fn crosshatch() {
+    let options = HatchOptions {
+        spacing: 2.0,
+        phase: 1.0,
+        crosshatch: true,
+        ..Default::default()
+    };
+    let lines = hatch_fill(&[square(0.0, 0.0, 10.0)], &options);
+    assert_eq!(lines.len(), 10);
+    assert_fuzzy_eq!(total_length(&lines), 100.0);
+    // second set is vertical
+    for l in lines[5..].iter() {
+        assert_fuzzy_eq!(l[0].x, l[1].x);
+    }
+}
+
+#[test]
+fn zigzag_square() {
+    let options = HatchOptions {
+        spacing: 2.0,
+        phase: 1.0,
+        zigzag: true,
+        ..Default::default()
+    };
+    let paths = hatch_fill(&[square(0.0, 0.0, 10.0)], &options);
+    assert_eq!(paths.len(), 1);
+    let path = &paths[0];
+    assert_eq!(path.vertex_count(), 10);
+    // alternates direction with connections along the square sides
+    assert_fuzzy_eq!(path[0].pos(), Vector2::new(0.0, 1.0));
+    assert_fuzzy_eq!(path[1].pos(), Vector2::new(10.0, 1.0));
+    assert_fuzzy_eq!(path[2].pos(), Vector2::new(10.0, 3.0));
+    assert_fuzzy_eq!(path[3].pos(), Vector2::new(0.0, 3.0));
+    assert_fuzzy_eq!(path[4].pos(), Vector2::new(0.0, 5.0));
+    assert_fuzzy_eq!(path.path_length(), 50.0 + 8.0);
+}
+
+#[test]
+fn zigzag_follows_arcs() {
+    let c = circle(0.0, 0.0, 5.0);
+    let options = HatchOptions {
+        spacing: 1.0,
+        phase: 0.5,
+        zigzag: true,
+        ..Default::default()
+    };
+    let paths = hatch_fill(std::iter::once(&c), &options);
+    assert_eq!(paths.len(), 1);
+    let path = &paths[0];
+    assert_within_region(&paths, std::slice::from_ref(&c));
+    // connections are exact arcs
+    assert!(path.iter_vertexes().any(|v| !v.bulge_is_zero()));
+    for v in path.iter_vertexes() {
+        if !v.bulge_is_zero() {
+            assert_fuzzy_eq!(v.pos().length(), 5.0, 1e-9);
+        }
+    }
+
+    let lines = hatch_fill(
+        &[c],
+        &HatchOptions {
+            zigzag: false,
+            ..options
+        },
+    );
+    assert_eq!(lines.len(), 10);
+    assert!(path.path_length() > total_length(&lines));
+}
+
+#[test]
+fn zigzag_with_hole() {
+    let outer = square(0.0, 0.0, 20.0);
+    let hole = circle(10.0, 10.0, 5.0);
+    let region = vec![outer, hole];
+    let options = HatchOptions {
+        spacing: 1.0,
+        phase: 0.5,
+        zigzag: true,
+        ..Default::default()
+    };
+    let paths = hatch_fill(&region, &options);
+    assert!(paths.len() > 1);
+    assert_within_region(&paths, &region);
+
+    // every hatch interval is covered by the zig-zag paths
+    let lines = hatch_fill(
+        &region,
+        &HatchOptions {
+            zigzag: false,
+            ..options
+        },
+    );
+    let hatch_length_in_paths: f64 = paths
+        .iter()
+        .flat_map(|p| p.iter_segments())
+        .filter(|(v1, v2)| v1.bulge_is_zero() && (v1.y - v2.y).abs() < 1e-9)
+        .filter(|(v1, _)| ((v1.y - 0.5) - (v1.y - 0.5).round()).abs() < 1e-9)
+        .map(|(v1, v2)| (v2.pos() - v1.pos()).length())
+        .sum();
+    assert_fuzzy_eq!(hatch_length_in_paths, total_length(&lines), 1e-6);
+}
@@ -0,0 +1,181 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{PlineHausdorffOptions, PlineSource, PlineSourceMut, Polyline},
+};
+
+/// Directed Hausdorff distance approximated by densely sampling points along `pline1`.
+fn sampled_directed_hausdorff(pline1: &Polyline, pline2: &Polyline) -> f64 {
+    let total_length = pline1.path_length();
+    let count = 2000;
+    (0..=count)
+        .map(|i| {
+            let target = total_length * (i as f64) / (count as f64);
+            let (_, pt) = pline1.find_point_at_path_length(target).unwrap();
+            pline2.closest_point(pt, 1e-5).unwrap().distance
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn empty_polylines() {
+    let empty: Polyline = Polyline::new();
+    let line = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+    assert!(empty.hausdorff_distance(&line).is_none());
+    assert!(line.hausdorff_distance(&empty).is_none());
+    assert!(line.directed_hausdorff_distance(&empty).is_none());
+    assert!(empty.discrete_frechet_distance(&line, 0.0).is_none());
+}
+
+#[test]
+fn invalid_tolerance() {
+    let line1 = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+    let line2 = pline_open![(0.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    for &tolerance in [0.0, -1.0, f64::NAN, f64::INFINITY].iter() {
+        let options = PlineHausdorffOptions {
+            tolerance,
+            ..Default::default()
+        };
+        assert!(line1.hausdorff_distance_opt(&line2, &options).is_none());
+        assert!(line1
+            .directed_hausdorff_distance_opt(&line2, &options)
+            .is_none());
+    }
+
+    for &spacing in [-1.0, f64::NAN, f64::INFINITY].iter() {
+        assert!(line1.discrete_frechet_distance(&line2, spacing).is_none());
+    }
+}
+
+#[test]
+fn identical_polylines() {
+    let pline = pline_closed![(0.0, 0.0, 0.5), (5.0, 0.0, 0.0), (5.0, 5.0, -0.3)];
+    let result = pline.hausdorff_distance(&pline.clone()).unwrap();
+    assert!(result.distance < 1e-4);
+    let frechet = pline
+        .discrete_frechet_distance(&pline.clone(), 0.1)
+        .unwrap();
+    assert_fuzzy_eq!(frechet, 0.0);
+}
+
+#[test]
+fn directed_is_not_symmetric() {
+    let short_line = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    let long_line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+
+    let result = short_line.directed_hausdorff_distance(&long_line).unwrap();
+    assert!(result.distance < 1e-4);
+
+    let result = long_line.directed_hausdorff_distance(&short_line).unwrap();
+    assert_fuzzy_eq!(result.distance, 8.0);
+    assert_fuzzy_eq!(result.point1, Vector2::new(10.0, 0.0));
+    assert_fuzzy_eq!(result.point2, Vector2::new(2.0, 0.0));
+
+    // symmetric distance keeps point1 on self
+    let result = short_line.hausdorff_distance(&long_line).unwrap();
+    assert_fuzzy_eq!(result.distance, 8.0);
+    assert_fuzzy_eq!(result.point1, Vector2::new(2.0, 0.0));
+    assert_fuzzy_eq!(result.point2, Vector2::new(10.0, 0.0));
+}
+
+#[test]
+fn maximum_in_arc_interior() {
+    // arc bulging up from (0, 0) to (2, 0), top at (1, 1)
+    let arc: Polyline = pline_open![(0.0, 0.0, -1.0), (2.0, 0.0, 0.0)];
+    let line = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    let options = PlineHausdorffOptions {
+        tolerance: 1e-6,
+        ..Default::default()
+    };
+    let result = arc
+        .directed_hausdorff_distance_opt(&line, &options)
+        .unwrap();
+    assert!((result.distance - 1.0).abs() < 1e-6);
+    assert!((result.point1 - Vector2::new(1.0, 1.0)).length() < 1e-2);
+
+    let result = line
+        .directed_hausdorff_distance_opt(&arc, &options)
+        .unwrap();
+    // arc center is the farthest point on the line from the arc
+    assert!((result.distance - 1.0).abs() < 1e-6);
+    assert!((result.point1 - Vector2::new(1.0, 0.0)).length() < 1e-2);
+}
+
+#[test]
+fn concentric_circles() {
+    let inner: Polyline = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    let outer = pline_closed![(-3.0, 0.0, 1.0), (3.0, 0.0, 1.0)];
+    let result = inner.hausdorff_distance(&outer).unwrap();
+    assert_fuzzy_eq!(result.distance, 2.0);
+    assert_fuzzy_eq!((result.point1 - result.point2).length(), 2.0);
+
+    // direction does not matter for Hausdorff distance but does for Fréchet distance
+    let mut outer_inverted = outer.clone();
+    outer_inverted.invert_direction_mut();
+    let frechet = inner.discrete_frechet_distance(&outer, 0.1).unwrap();
+    assert!((frechet - 2.0).abs() < 1e-2);
+    let frechet = inner
+        .discrete_frechet_distance(&outer_inverted, 0.1)
+        .unwrap();
+    assert!(frechet > 2.0);
+    let result = inner.hausdorff_distance(&outer_inverted).unwrap();
+    assert_fuzzy_eq!(result.distance, 2.0);
+}
+
+#[test]
+fn matches_sampled() {
+    let create_wave = |y_offset: f64, amplitude: f64, count: usize| {
+        let mut pline = Polyline::new();
+        for i in 0..count {
+            let x = i as f64 * 20.0 / count as f64;
+            let y = y_offset + (x * 0.9).sin() * amplitude;
+            let bulge = if i % 3 == 0 { 0.4 } else { -0.2 };
+            pline.add(x, y, bulge);
+        }
+        pline
+    };
+
+    let pline1 = create_wave(0.0, 2.0, 40);
+    let pline2 = create_wave(0.5, 1.0, 17);
+    let index2 = pline2.create_approx_aabb_index().unwrap();
+    let options = PlineHausdorffOptions {
+        pline2_aabb_index: Some(&index2),
+        tolerance: 1e-5,
+        ..Default::default()
+    };
+
+    let result = pline1
+        .directed_hausdorff_distance_opt(&pline2, &options)
+        .unwrap();
+    let sampled = sampled_directed_hausdorff(&pline1, &pline2);
+    // sampling can only under estimate
+    assert!(result.distance >= sampled - 1e-5);
+    assert!(result.distance - sampled < 1e-2);
+    assert_fuzzy_eq!(
+        pline2.closest_point(result.point1, 1e-5).unwrap().distance,
+        result.distance
+    );
+
+    let result = pline2.directed_hausdorff_distance(&pline1).unwrap();
+    let sampled = sampled_directed_hausdorff(&pline2, &pline1);
+    assert!(result.distance >= sampled - 1e-4);
+    assert!(result.distance - sampled < 1e-2);
+
+    // Fréchet distance is never less than Hausdorff distance (up to sampling error)
+    let hausdorff = pline1.hausdorff_distance(&pline2).unwrap().distance;
+    let frechet = pline1.discrete_frechet_distance(&pline2, 0.05).unwrap();
+    assert!(frechet >= hausdorff - 0.05);
+}
+
+#[test]
+fn discrete_frechet_vertexes_only() {
+    let pline1 = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    let pline2 = pline_open![(0.0, 1.0, 0.0), (2.0, 1.0, 0.0)];
+    // middle vertex of pline1 must couple with an end point of pline2
+    let frechet = pline1.discrete_frechet_distance(&pline2, 0.0).unwrap();
+    assert_fuzzy_eq!(frechet, 2.0f64.sqrt());
+    // sampling brings it down to the continuous distance
+    let frechet = pline1.discrete_frechet_distance(&pline2, 0.5).unwrap();
+    assert_fuzzy_eq!(frechet, 1.0);
+}
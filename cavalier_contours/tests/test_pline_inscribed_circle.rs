@@ -0,0 +1,95 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        maximum_inscribed_circle, InscribedCircle, InscribedCircleOptions, PlineSource,
+        PlineSourceMut, Polyline,
+    },
+};
+use test_utils::square;
+
+fn find(plines: &[Polyline]) -> Option<InscribedCircle> {
+    maximum_inscribed_circle(plines, &InscribedCircleOptions::default())
+}
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} != {}", a, b);
+}
+
+#[test]
+fn empty_input() {
+    assert!(find(&[]).is_none());
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    assert!(find(&[open]).is_none());
+    let flat: Polyline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(find(&[flat]).is_none());
+}
+
+#[test]
+fn square_and_circle() {
+    let circle = find(&[square(0.0, 0.0, 10.0)]).unwrap();
+    assert_near(circle.radius, 5.0, 1e-4);
+    assert!(circle.center.fuzzy_eq_eps(Vector2::new(5.0, 5.0), 1e-2));
+
+    let circle_pline: Polyline = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    let circle = find(&[circle_pline]).unwrap();
+    assert_near(circle.radius, 5.0, 1e-4);
+    assert!(circle.center.fuzzy_eq_eps(Vector2::zero(), 1e-2));
+}
+
+#[test]
+fn clockwise_input() {
+    let mut pline = square(0.0, 0.0, 10.0);
+    pline.invert_direction_mut();
+    let circle = find(&[pline]).unwrap();
+    assert_near(circle.radius, 5.0, 1e-4);
+}
+
+#[test]
+fn tolerance_option() {
+    let options = InscribedCircleOptions {
+        tolerance: 0.5,
+        ..Default::default()
+    };
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 3.0, 0.0),
+        (3.0, 3.0, 0.0),
+        (3.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let coarse = maximum_inscribed_circle(std::iter::once(&pline), &options).unwrap();
+    let fine = find(std::slice::from_ref(&pline)).unwrap();
+    assert!(coarse.radius <= fine.radius + 1e-9);
+    assert!(fine.radius - coarse.radius < 0.5);
+}
+
+#[test]
+fn square_with_hole() {
+    // hole has the same orientation as the outer loop, nesting determines it is a hole
+    let plines = vec![square(0.0, 0.0, 10.0), square(4.0, 4.0, 2.0)];
+    let circle = find(&plines).unwrap();
+    // largest circle sits on a diagonal between a corner of the hole and the outer corner
+    let expected = 4.0 * 2.0f64.sqrt() / (1.0 + 2.0f64.sqrt());
+    assert_near(circle.radius, expected, 1e-4);
+    assert_near(circle.center.x.min(10.0 - circle.center.x), expected, 1e-2);
+    assert_near(circle.center.y.min(10.0 - circle.center.y), expected, 1e-2);
+}
+
+#[test]
+fn matches_offset_collapse_distance() {
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (8.0, 0.0, 0.5),
+        (8.0, 6.0, 0.0),
+        (5.0, 6.0, 0.0),
+        (3.0, 2.0, 0.0),
+        (0.0, 5.0, 0.0),
+    ];
+    let circle = find(std::slice::from_ref(&pline)).unwrap();
+    assert!(!pline.parallel_offset(circle.radius - 1e-2).is_empty());
+    assert!(pline.parallel_offset(circle.radius + 1e-2).is_empty());
+}
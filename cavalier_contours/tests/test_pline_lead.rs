@@ -0,0 +1,234 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        add_leads, seg_tangent_vector, LeadEntry, LeadOptions, LeadShape, LeadSide, PlineSource,
+        PlineSourceMut, PointLocator, Polyline,
+    },
+};
+use test_utils::square;
+
+#[test]
+fn invalid_input() {
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(add_leads(&open, &LeadOptions::default()).is_none());
+    let single: Polyline = pline_closed![(0.0, 0.0, 0.0)];
+    assert!(add_leads(&single, &LeadOptions::default()).is_none());
+}
+
+#[test]
+fn entry_at_path_length() {
+    let pline = square(0.0, 0.0, 10.0);
+    for &length in [12.0, 52.0, -28.0].iter() {
+        let options = LeadOptions {
+            entry: LeadEntry::AtPathLength(length),
+            ..Default::default()
+        };
+        let result = add_leads(&pline, &options).unwrap();
+        assert_fuzzy_eq!(result.rotated[0].pos(), Vector2::new(10.0, 2.0));
+        assert!(result.rotated.is_closed());
+        assert!(result.lead_in.is_empty());
+        assert!(result.lead_out.is_empty());
+        assert!(!result.toolpath.is_closed());
+        assert_fuzzy_eq!(result.toolpath.path_length(), 40.0);
+        assert_fuzzy_eq!(result.toolpath[0].pos(), Vector2::new(10.0, 2.0));
+        assert_fuzzy_eq!(
+            result.toolpath.last().unwrap().pos(),
+            Vector2::new(10.0, 2.0)
+        );
+    }
+}
+
+#[test]
+fn lead_side() {
+    let ccw = square(0.0, 0.0, 10.0);
+    let mut cw = ccw.clone();
+    cw.invert_direction_mut();
+
+    for pline in [ccw, cw].iter() {
+        let options = LeadOptions {
+            entry: LeadEntry::ClosestTo(Vector2::new(5.0, -3.0)),
+            lead_in: LeadShape::PerpendicularLine { length: 2.0 },
+            lead_out: LeadShape::PerpendicularLine { length: 3.0 },
+            side: LeadSide::Inside,
+            ..Default::default()
+        };
+        let result = add_leads(pline, &options).unwrap();
+        assert_fuzzy_eq!(result.lead_in[0].pos(), Vector2::new(5.0, 2.0));
+        assert_fuzzy_eq!(result.lead_in[1].pos(), Vector2::new(5.0, 0.0));
+        assert_fuzzy_eq!(result.lead_out[0].pos(), Vector2::new(5.0, 0.0));
+        assert_fuzzy_eq!(result.lead_out[1].pos(), Vector2::new(5.0, 3.0));
+
+        let options = LeadOptions {
+            side: LeadSide::Outside,
+            ..options
+        };
+        let result = add_leads(pline, &options).unwrap();
+        assert_fuzzy_eq!(result.lead_in[0].pos(), Vector2::new(5.0, -2.0));
+        assert_fuzzy_eq!(result.lead_out[1].pos(), Vector2::new(5.0, -3.0));
+        assert_fuzzy_eq!(result.toolpath.path_length(), 45.0);
+    }
+}
+
+#[test]
+fn line_leads_are_tangent() {
+    let circle: Polyline = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    for &side in [LeadSide::Inside, LeadSide::Outside].iter() {
+        let options = LeadOptions {
+            entry: LeadEntry::ClosestTo(Vector2::new(0.0, -8.0)),
+            lead_in: LeadShape::Line { length: 2.0 },
+            lead_out: LeadShape::Line { length: 3.0 },
+            side,
+            overlap: 5.0 * std::f64::consts::PI / 2.0,
+            ..Default::default()
+        };
+        // side does not change tangent lines
+        let result = add_leads(&circle, &options).unwrap();
+        assert_fuzzy_eq!(result.lead_in[0].pos(), Vector2::new(-2.0, -5.0));
+        assert_fuzzy_eq!(result.lead_in[1].pos(), Vector2::new(0.0, -5.0));
+        // exit a quarter turn later on the right of the circle heading up
+        assert_fuzzy_eq!(result.lead_out[0].pos(), Vector2::new(5.0, 0.0));
+        assert_fuzzy_eq!(result.lead_out[1].pos(), Vector2::new(5.0, 3.0));
+        // lines tangent to the circle are outside it
+        assert_eq!(result.lead_in_valid, side == LeadSide::Outside);
+        assert_eq!(result.lead_out_valid, side == LeadSide::Outside);
+    }
+
+    // lines along straight segments stay on the boundary so are valid on either side
+    let pline = square(0.0, 0.0, 10.0);
+    for &side in [LeadSide::Inside, LeadSide::Outside].iter() {
+        let options = LeadOptions {
+            entry: LeadEntry::ClosestTo(Vector2::new(5.0, -1.0)),
+            lead_in: LeadShape::Line { length: 2.0 },
+            lead_out: LeadShape::Line { length: 2.0 },
+            side,
+            overlap: 1.0,
+            ..Default::default()
+        };
+        let result = add_leads(&pline, &options).unwrap();
+        assert_fuzzy_eq!(result.lead_in[0].pos(), Vector2::new(3.0, 0.0));
+        assert!(result.lead_in_valid);
+        assert!(result.lead_out_valid);
+    }
+
+    // perpendicular lines use the side
+    let options = LeadOptions {
+        entry: LeadEntry::ClosestTo(Vector2::new(0.0, -8.0)),
+        lead_in: LeadShape::PerpendicularLine { length: 2.0 },
+        side: LeadSide::Outside,
+        ..Default::default()
+    };
+    let result = add_leads(&circle, &options).unwrap();
+    assert_fuzzy_eq!(result.lead_in[0].pos(), Vector2::new(0.0, -7.0));
+}
+
+#[test]
+fn arc_leads_are_tangent() {
+    let circle: Polyline = pline_closed![(-5.0, 0.0, -1.0), (5.0, 0.0, -1.0)];
+    for &side in [LeadSide::Inside, LeadSide::Outside].iter() {
+        let options = LeadOptions {
+            entry: LeadEntry::ClosestTo(Vector2::new(3.0, 8.0)),
+            lead_in: LeadShape::Arc { radius: 1.0 },
+            lead_out: LeadShape::Arc { radius: 1.5 },
+            side,
+            overlap: 2.0,
+            ..Default::default()
+        };
+        let result = add_leads(&circle, &options).unwrap();
+        let entry = result.rotated[0];
+        assert_fuzzy_eq!(entry.pos(), Vector2::new(3.0, 8.0).normalize().scale(5.0));
+
+        let entry_tangent = seg_tangent_vector(entry, result.rotated[1], entry.pos()).normalize();
+        let lead_in_end_tangent = seg_tangent_vector(
+            result.lead_in[0],
+            result.lead_in[1],
+            result.lead_in[1].pos(),
+        )
+        .normalize();
+        assert_fuzzy_eq!(entry_tangent, lead_in_end_tangent);
+
+        // lead start lies on the side requested
+        let start_dist = result.lead_in[0].pos().length();
+        match side {
+            LeadSide::Inside => assert!(start_dist < 5.0),
+            LeadSide::Outside => assert!(start_dist > 5.0),
+        }
+
+        // exit point is 2.0 along the circle from the entry
+        let exit = result.lead_out[0];
+        assert_fuzzy_eq!(exit.pos().length(), 5.0);
+        let chord = (exit.pos() - entry.pos()).length();
+        assert_fuzzy_eq!(chord, 2.0 * 5.0 * (0.2f64).sin());
+        let exit_tangent = seg_tangent_vector(exit, result.lead_out[1], exit.pos()).normalize();
+        let expected_exit_tangent = exit.pos().normalize().perp().scale(-1.0);
+        assert_fuzzy_eq!(exit_tangent, expected_exit_tangent);
+
+        let expected_length =
+            0.5 * std::f64::consts::PI * (1.0 + 1.5) + 2.0 * std::f64::consts::PI * 5.0 + 2.0;
+        assert_fuzzy_eq!(result.toolpath.path_length(), expected_length);
+        assert_fuzzy_eq!(result.toolpath[0].pos(), result.lead_in[0].pos());
+        assert_fuzzy_eq!(
+            result.toolpath.last().unwrap().pos(),
+            result.lead_out[1].pos()
+        );
+    }
+}
+
+#[test]
+fn overlap_clamped_to_full_loop() {
+    let pline = square(0.0, 0.0, 10.0);
+    let options = LeadOptions {
+        entry: LeadEntry::AtPathLength(5.0),
+        overlap: 100.0,
+        ..Default::default()
+    };
+    let result = add_leads(&pline, &options).unwrap();
+    assert_fuzzy_eq!(result.toolpath.path_length(), 80.0);
+    assert_fuzzy_eq!(
+        result.toolpath.last().unwrap().pos(),
+        Vector2::new(5.0, 0.0)
+    );
+}
+
+#[test]
+fn allowed_region() {
+    let part = square(0.0, 0.0, 10.0);
+    let mut part_hole = part.clone();
+    part_hole.invert_direction_mut();
+    let stock = square(-5.0, -5.0, 20.0);
+    let region = PointLocator::new(&[stock, part_hole], 1e-5);
+
+    let options = LeadOptions {
+        entry: LeadEntry::ClosestTo(Vector2::new(5.0, -3.0)),
+        lead_in: LeadShape::Arc { radius: 2.0 },
+        lead_out: LeadShape::PerpendicularLine { length: 3.0 },
+        side: LeadSide::Outside,
+        allowed_region: Some(&region),
+        ..Default::default()
+    };
+    let result = add_leads(&part, &options).unwrap();
+    assert!(result.lead_in_valid);
+    assert!(result.lead_out_valid);
+
+    // lead-in arc leaves the stock
+    let options = LeadOptions {
+        lead_in: LeadShape::Arc { radius: 8.0 },
+        ..options
+    };
+    let result = add_leads(&part, &options).unwrap();
+    assert!(!result.lead_in_valid);
+    assert!(result.lead_out_valid);
+
+    // leads inside the part
+    let options = LeadOptions {
+        lead_in: LeadShape::Arc { radius: 2.0 },
+        side: LeadSide::Inside,
+        ..options
+    };
+    let result = add_leads(&part, &options).unwrap();
+    assert!(!result.lead_in_valid);
+    assert!(!result.lead_out_valid);
+}
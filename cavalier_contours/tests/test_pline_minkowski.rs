@@ -0,0 +1,168 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        minkowski_difference, minkowski_sum, MinkowskiOptions, MinkowskiResult, PlineSource,
+        PlineSourceMut, Polyline,
+    },
+};
+use std::f64::consts::PI;
+use test_utils::{circle, rect, total_area, winding};
+
+fn sum(a: &Polyline, b: &Polyline) -> MinkowskiResult {
+    minkowski_sum(a, b, &MinkowskiOptions::default())
+}
+
+#[test]
+fn empty_input() {
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+    let result = sum(&open, &rect(0.0, 0.0, 1.0, 1.0));
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+
+    let flat: Polyline = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+    let result = sum(&rect(0.0, 0.0, 1.0, 1.0), &flat);
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+}
+
+#[test]
+fn convex_polygons() {
+    let result = sum(&rect(0.0, 0.0, 3.0, 1.0), &rect(-1.0, -1.0, 1.0, 1.0));
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 5.0 * 3.0);
+    let extents = result.pos_plines[0].extents().unwrap();
+    assert_fuzzy_eq!(extents.min_x, -1.0);
+    assert_fuzzy_eq!(extents.min_y, -1.0);
+    assert_fuzzy_eq!(extents.max_x, 4.0);
+    assert_fuzzy_eq!(extents.max_y, 2.0);
+
+    // orientation of the input does not matter
+    let mut cw = rect(-1.0, -1.0, 1.0, 1.0);
+    cw.invert_direction_mut();
+    let result = sum(&rect(0.0, 0.0, 3.0, 1.0), &cw);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 15.0);
+
+    let triangle: Polyline = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)];
+    let result = sum(&triangle, &triangle);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 2.0);
+}
+
+#[test]
+fn arcs() {
+    // circle plus circle is a circle with the radii added
+    let result = sum(&circle(1.0, 2.0, 1.5), &circle(-3.0, 0.5, 2.0));
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), PI * 3.5 * 3.5);
+    assert_eq!(winding(&result, Vector2::new(-2.0, 2.5)), 1);
+    assert_eq!(winding(&result, Vector2::new(-2.0, 6.1)), 0);
+
+    // square plus circle matches offsetting outward
+    let result = sum(&rect(0.0, 0.0, 2.0, 2.0), &circle(0.0, 0.0, 0.5));
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 4.0 + 4.0 + PI * 0.25);
+}
+
+#[test]
+fn concave_arcs_match_offset() {
+    // shape with concave and convex arcs
+    let shape: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.3),
+        (10.0, 6.0, 0.0),
+        (6.0, 6.0, -0.4),
+        (2.0, 6.0, 0.0),
+        (0.0, 6.0, 0.0),
+    ];
+    for &r in [0.25, 1.0].iter() {
+        let result = sum(&shape, &circle(0.0, 0.0, r));
+        let offset = shape.parallel_offset(-r);
+        assert_eq!(result.pos_plines.len(), 1);
+        assert_eq!(offset.len(), 1);
+        assert_fuzzy_eq!(result.pos_plines[0].area(), offset[0].area());
+    }
+}
+
+#[test]
+fn non_convex_polygons() {
+    // L shape plus a square is the union of each leg plus the square
+    let l_shape: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (3.0, 0.0, 0.0),
+        (3.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 3.0, 0.0),
+        (0.0, 3.0, 0.0),
+    ];
+    let result = sum(&l_shape, &rect(0.0, 0.0, 1.0, 1.0));
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 12.0);
+    assert_eq!(winding(&result, Vector2::new(1.5, 1.5)), 1);
+    assert_eq!(winding(&result, Vector2::new(2.5, 2.5)), 0);
+
+    // both non-convex
+    let result = sum(&l_shape, &l_shape);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(winding(&result, Vector2::new(1.5, 1.5)), 1);
+    assert_eq!(winding(&result, Vector2::new(5.5, 5.5)), 0);
+    assert_eq!(winding(&result, Vector2::new(3.5, 1.5)), 1);
+}
+
+#[test]
+fn sum_with_hole() {
+    // square frame opened by a narrow slit, the slit is closed by the sum leaving a hole
+    let frame: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.9, 0.0, 0.0),
+        (4.9, 2.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 8.0, 0.0),
+        (8.0, 8.0, 0.0),
+        (8.0, 2.0, 0.0),
+        (5.1, 2.0, 0.0),
+        (5.1, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let result = sum(&frame, &rect(-0.5, -0.5, 0.5, 0.5));
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 121.0);
+    assert_fuzzy_eq!(result.neg_plines[0].area(), -25.0);
+    assert_fuzzy_eq!(total_area(&result), 96.0);
+    assert_eq!(winding(&result, Vector2::new(5.0, 5.0)), 0);
+    assert_eq!(winding(&result, Vector2::new(5.0, 1.0)), 1);
+}
+
+#[test]
+fn difference() {
+    let obstacle = rect(0.0, 0.0, 4.0, 2.0);
+    let part: Polyline = pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (0.0, 1.0, 0.0)];
+    let result = minkowski_difference(&obstacle, &part, &MinkowskiOptions::default());
+    assert_eq!(result.pos_plines.len(), 1);
+    // bounding box of the sum with one corner cut by the hypotenuse
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 6.0 * 3.0 - 1.0);
+
+    // translations where the part overlaps the obstacle are inside
+    assert_eq!(winding(&result, Vector2::new(-1.5, 0.2)), 1);
+    assert_eq!(winding(&result, Vector2::new(-1.5, 1.8)), 1);
+    assert_eq!(winding(&result, Vector2::new(3.5, -0.5)), 1);
+    assert_eq!(winding(&result, Vector2::new(-1.8, -0.8)), 0);
+
+    // difference of circles is a circle centered at the difference of centers
+    let result = minkowski_difference(
+        &circle(5.0, 5.0, 1.0),
+        &circle(2.0, 1.0, 2.0),
+        &MinkowskiOptions::default(),
+    );
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), PI * 9.0);
+    assert_eq!(winding(&result, Vector2::new(3.0, 4.0)), 1);
+    assert_eq!(winding(&result, Vector2::new(3.0, 7.1)), 0);
+}
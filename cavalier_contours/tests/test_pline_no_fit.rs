@@ -0,0 +1,174 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed,
+    polyline::{
+        inner_fit_polygon, no_fit_polygon, MinkowskiOptions, MinkowskiResult, PlineSource,
+        PlineSourceMut, Polyline,
+    },
+};
+use std::f64::consts::PI;
+use test_utils::{circle, rect, total_area, winding};
+
+fn nfp(stationary: &[Polyline], orbiting: &[Polyline]) -> MinkowskiResult {
+    no_fit_polygon(stationary, orbiting, &MinkowskiOptions::default())
+}
+
+fn ifp(container: &[Polyline], part: &[Polyline]) -> MinkowskiResult {
+    inner_fit_polygon(container, part, &MinkowskiOptions::default())
+}
+
+#[test]
+fn empty_input() {
+    let empty: Vec<Polyline> = Vec::new();
+    let square = vec![rect(0.0, 0.0, 1.0, 1.0)];
+    let result = nfp(&empty, &square);
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+    let result = ifp(&square, &empty);
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+
+    // part too large to fit
+    let result = ifp(&square, &[rect(0.0, 0.0, 2.0, 0.5)]);
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+}
+
+#[test]
+fn nfp_rectangles() {
+    // orbiting part origin is its center
+    let result = nfp(&[rect(0.0, 0.0, 4.0, 2.0)], &[rect(-1.0, -1.0, 1.0, 1.0)]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 6.0 * 4.0);
+    let extents = result.pos_plines[0].extents().unwrap();
+    assert_fuzzy_eq!(extents.min_x, -1.0);
+    assert_fuzzy_eq!(extents.min_y, -1.0);
+    assert_fuzzy_eq!(extents.max_x, 5.0);
+    assert_fuzzy_eq!(extents.max_y, 3.0);
+
+    // orientation of the input does not matter
+    let mut cw = rect(-1.0, -1.0, 1.0, 1.0);
+    cw.invert_direction_mut();
+    let result = nfp(&[rect(0.0, 0.0, 4.0, 2.0)], &[cw]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 24.0);
+}
+
+#[test]
+fn nfp_circles() {
+    let result = nfp(&[circle(5.0, 5.0, 1.0)], &[circle(2.0, 1.0, 2.0)]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), PI * 9.0);
+    // touching placement is on the boundary
+    let touching = Vector2::new(3.0 + 3.0, 4.0);
+    assert_fuzzy_eq!(
+        result.pos_plines[0]
+            .closest_point(touching, 1e-5)
+            .unwrap()
+            .distance,
+        0.0
+    );
+    assert_eq!(winding(&result, Vector2::new(3.0, 4.0)), 1);
+    assert_eq!(winding(&result, Vector2::new(6.1, 4.0)), 0);
+}
+
+#[test]
+fn nfp_stationary_with_hole() {
+    // 2x2 part fits inside the 6x6 hole of the stationary part
+    let stationary = vec![rect(0.0, 0.0, 10.0, 10.0), rect(2.0, 2.0, 8.0, 8.0)];
+    let result = nfp(&stationary, &[rect(0.0, 0.0, 2.0, 2.0)]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 12.0 * 12.0);
+    assert_fuzzy_eq!(result.neg_plines[0].area(), -4.0 * 4.0);
+    assert_fuzzy_eq!(total_area(&result), 144.0 - 16.0);
+    assert_eq!(winding(&result, Vector2::new(4.0, 4.0)), 0);
+    assert_eq!(winding(&result, Vector2::new(1.0, 4.0)), 1);
+    assert_eq!(winding(&result, Vector2::new(-2.5, 4.0)), 0);
+
+    // part too large for the hole
+    let result = nfp(&stationary, &[rect(0.0, 0.0, 7.0, 2.0)]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_eq!(winding(&result, Vector2::new(2.0, 4.0)), 1);
+}
+
+#[test]
+fn nfp_orbiting_with_hole() {
+    // stationary part fits inside the hole of the orbiting part
+    let orbiting = vec![rect(0.0, 0.0, 10.0, 10.0), rect(2.0, 2.0, 8.0, 8.0)];
+    let result = nfp(&[rect(0.0, 0.0, 2.0, 2.0)], &orbiting);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+    assert_fuzzy_eq!(total_area(&result), 144.0 - 16.0);
+    assert_eq!(winding(&result, Vector2::new(-4.0, -4.0)), 0);
+    assert_eq!(winding(&result, Vector2::new(-1.0, -4.0)), 1);
+}
+
+#[test]
+fn nfp_arcs_and_concave() {
+    // part with a concave arc notch, the circle fits into the notch
+    let stationary: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, 0.0),
+        (7.0, 4.0, -1.0),
+        (3.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0),
+    ];
+    let orbiting = circle(0.0, 0.0, 1.0);
+    let result = nfp(std::slice::from_ref(&stationary), &[orbiting]);
+    // equivalent to offsetting outward by the circle radius
+    let offset = stationary.parallel_offset(-1.0);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(offset.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), offset[0].area());
+    // circle fits into the notch touching its bottom at (5, 3)
+    assert_eq!(winding(&result, Vector2::new(5.0, 2.9)), 1);
+    assert_eq!(winding(&result, Vector2::new(5.0, 3.1)), 0);
+}
+
+#[test]
+fn ifp_rectangles() {
+    let sheet = rect(0.0, 0.0, 10.0, 5.0);
+    // part origin offset from the part
+    let part = rect(1.0, 1.0, 3.0, 2.0);
+    let result = ifp(std::slice::from_ref(&sheet), &[part]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 8.0 * 4.0);
+    let extents = result.pos_plines[0].extents().unwrap();
+    assert_fuzzy_eq!(extents.min_x, -1.0);
+    assert_fuzzy_eq!(extents.min_y, -1.0);
+    assert_fuzzy_eq!(extents.max_x, 7.0);
+    assert_fuzzy_eq!(extents.max_y, 3.0);
+
+    // circle part gives the sheet shrunk by the radius with sharp corners
+    let result = ifp(std::slice::from_ref(&sheet), &[circle(0.0, 0.0, 1.0)]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 8.0 * 3.0);
+    assert_eq!(winding(&result, Vector2::new(1.1, 1.1)), 1);
+    assert_eq!(winding(&result, Vector2::new(0.9, 2.0)), 0);
+}
+
+#[test]
+fn ifp_container_with_hole() {
+    // sheet with a defect, placements overlapping the defect are removed
+    let mut defect = rect(4.0, 2.0, 6.0, 3.0);
+    defect.invert_direction_mut();
+    let sheet = vec![rect(0.0, 0.0, 10.0, 5.0), defect];
+    let result = ifp(&sheet, &[rect(0.0, 0.0, 2.0, 1.0)]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+    assert_fuzzy_eq!(total_area(&result), 32.0 - 4.0 * 2.0);
+    assert_eq!(winding(&result, Vector2::new(3.0, 2.0)), 0);
+    assert_eq!(winding(&result, Vector2::new(1.0, 2.0)), 1);
+
+    // defect splits the placements into two regions
+    let sheet = vec![rect(0.0, 0.0, 10.0, 2.0), rect(4.0, 0.5, 6.0, 1.5)];
+    let result = ifp(&sheet, &[rect(0.0, 0.0, 1.0, 1.5)]);
+    assert_eq!(result.pos_plines.len(), 2);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(total_area(&result), 2.0 * 3.0 * 0.5);
+}
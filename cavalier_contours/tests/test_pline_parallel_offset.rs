@@ -1,3 +1,5 @@
+#![allow(clippy::excessive_precision)]
+
 mod test_utils;
 
 use cavalier_contours::polyline::{PlineOffsetOptions, PlineSource, Polyline};
@@ -0,0 +1,224 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{PlineSource, PlineSourceMut, PointLocation, PointLocator, Polyline},
+};
+
+/// Wavy closed polyline with line and arc segments.
+fn create_wavy_loop() -> Polyline {
+    let mut pline = Polyline::new_closed();
+    let count = 60;
+    for i in 0..count {
+        let angle = (i as f64) / (count as f64) * std::f64::consts::TAU;
+        let radius = 10.0 + (angle * 5.0).sin() * 2.0;
+        let bulge = match i % 3 {
+            0 => 0.0,
+            1 => 0.4,
+            _ => -0.3,
+        };
+        pline.add(radius * angle.cos(), radius * angle.sin(), bulge);
+    }
+    pline
+}
+
+#[test]
+fn empty_locator() {
+    let plines: Vec<Polyline> = Vec::new();
+    let locator = PointLocator::new(&plines, 1e-5);
+    assert!(locator.aabb_index().is_none());
+    assert_eq!(locator.winding_number(Vector2::new(0.0, 0.0)), 0);
+    assert_eq!(
+        locator.locate(Vector2::new(0.0, 0.0)),
+        PointLocation::Outside
+    );
+
+    // open polylines are ignored
+    let open = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+    let locator = PointLocator::new(&[open], 1e-5);
+    assert!(!locator.contains(Vector2::new(0.5, 0.0)));
+}
+
+#[test]
+fn matches_winding_number() {
+    let pline = create_wavy_loop();
+    let mut inverted = pline.clone();
+    inverted.invert_direction_mut();
+
+    for source in [pline, inverted].iter() {
+        let locator = PointLocator::new(std::iter::once(source), 1e-5);
+        let points = (0..50)
+            .flat_map(|i| {
+                (0..50).map(move |j| Vector2::new(-13.0 + i as f64 * 0.53, -13.0 + j as f64 * 0.53))
+            })
+            .collect::<Vec<_>>();
+
+        let windings = locator.winding_numbers(points.iter().copied());
+        let locations = locator.locate_all(points.iter().copied());
+        for ((&pt, &w), &loc) in points.iter().zip(windings.iter()).zip(locations.iter()) {
+            let expected = source.winding_number(pt);
+            assert_eq!(w, expected, "point: {:?}", pt);
+            assert_eq!(locator.winding_number(pt), expected);
+            let on_boundary = source.closest_point(pt, 1e-5).unwrap().distance <= 1e-5;
+            let expected_loc = if on_boundary {
+                PointLocation::Boundary
+            } else if expected != 0 {
+                PointLocation::Inside
+            } else {
+                PointLocation::Outside
+            };
+            assert_eq!(loc, expected_loc, "point: {:?}", pt);
+        }
+    }
+}
+
+#[test]
+fn boundary_tolerance() {
+    let square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let locator = PointLocator::new(std::iter::once(&square), 0.1);
+    assert_eq!(locator.boundary_eps(), 0.1);
+
+    let points = [
+        Vector2::new(5.0, 0.05),
+        Vector2::new(5.0, -0.05),
+        Vector2::new(5.0, 0.2),
+        Vector2::new(5.0, -0.2),
+        Vector2::new(10.0, 10.0),
+        Vector2::new(10.05, 10.05),
+    ];
+    assert_eq!(
+        locator.locate_all(points.iter().copied()),
+        vec![
+            PointLocation::Boundary,
+            PointLocation::Boundary,
+            PointLocation::Inside,
+            PointLocation::Outside,
+            PointLocation::Boundary,
+            PointLocation::Boundary,
+        ]
+    );
+    assert_eq!(
+        locator.contains_all(points.iter().copied()),
+        vec![true, true, true, false, true, true]
+    );
+}
+
+#[test]
+fn multiple_loops_with_holes() {
+    let outer1 = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    // clockwise hole
+    let hole = pline_closed![
+        (2.0, 2.0, 0.0),
+        (2.0, 8.0, 0.0),
+        (8.0, 8.0, 0.0),
+        (8.0, 2.0, 0.0)
+    ];
+    // island inside the hole
+    let island = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    let outer2 = pline_closed![(20.0, 0.0, 1.0), (30.0, 0.0, 1.0)];
+
+    let locator = PointLocator::new(&[outer1, hole, island, outer2], 1e-5);
+    assert!(locator.contains(Vector2::new(1.0, 1.0)));
+    assert!(!locator.contains(Vector2::new(3.0, 3.0)));
+    assert!(locator.contains(Vector2::new(5.0, 5.0)));
+    assert!(locator.contains(Vector2::new(25.0, 0.0)));
+    assert!(!locator.contains(Vector2::new(15.0, 0.0)));
+    assert_eq!(locator.winding_number(Vector2::new(5.0, 5.0)), 1);
+    assert_eq!(
+        locator.locate(Vector2::new(2.0, 5.0)),
+        PointLocation::Boundary
+    );
+}
+
+#[test]
+fn contains_pline() {
+    let outer = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    // clockwise hole
+    let hole = pline_closed![(4.0, 5.0, -1.0), (6.0, 5.0, -1.0)];
+    let locator = PointLocator::new(&[outer, hole], 1e-5);
+
+    let inside: Polyline = pline_open![(1.0, 1.0, 0.0), (9.0, 1.0, 0.0), (9.0, 9.0, 0.0)];
+    assert!(locator.contains_pline(&inside));
+    assert!(!locator.excludes_pline(&inside));
+
+    // along the boundary
+    let on_boundary: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (8.0, 2.0, 0.0)];
+    assert!(locator.contains_pline(&on_boundary));
+    let around_hole: Polyline = pline_open![(4.0, 5.0, 1.0), (6.0, 5.0, 0.0), (5.0, 5.0, 0.0)];
+    assert!(locator.excludes_pline(&around_hole));
+
+    // end points inside but crosses the hole
+    let crosses_hole: Polyline = pline_open![(1.0, 5.0, 0.0), (9.0, 5.0, 0.0)];
+    assert!(!locator.contains_pline(&crosses_hole));
+    assert!(!locator.excludes_pline(&crosses_hole));
+    let inside_hole: Polyline = pline_open![(4.5, 5.0, 0.0), (5.5, 5.0, 0.0)];
+    assert!(locator.excludes_pline(&inside_hole));
+
+    // arc bulging out of the region with end points inside
+    let arc_out: Polyline = pline_open![(2.0, 9.0, -1.0), (8.0, 9.0, 0.0)];
+    assert!(!locator.contains_pline(&arc_out));
+    let arc_in: Polyline = pline_open![(2.0, 9.0, 0.2), (8.0, 9.0, 0.0)];
+    assert!(locator.contains_pline(&arc_in));
+
+    // vertex outside
+    let outside: Polyline = pline_open![(1.0, 1.0, 0.0), (11.0, 1.0, 0.0)];
+    assert!(!locator.contains_pline(&outside));
+    assert!(!locator.excludes_pline(&outside));
+    let beside: Polyline = pline_open![(11.0, 1.0, 0.0), (11.0, 9.0, 0.0)];
+    assert!(locator.excludes_pline(&beside));
+
+    let empty: Polyline = Polyline::new();
+    assert!(locator.contains_pline(&empty));
+    let empty_locator = PointLocator::new(&Vec::<Polyline>::new(), 1e-5);
+    assert!(empty_locator.contains_pline(&empty));
+    assert!(!empty_locator.contains_pline(&inside));
+    assert!(empty_locator.excludes_pline(&inside));
+}
+
+#[test]
+fn distance_to_boundary() {
+    let outer: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    // clockwise hole (circle radius 1 at (5, 5))
+    let hole: Polyline = pline_closed![(4.0, 5.0, -1.0), (6.0, 5.0, -1.0)];
+    let locator = PointLocator::new(&[outer, hole], 1e-5);
+
+    let cases = [
+        (Vector2::new(1.0, 2.0), 1.0f64),
+        (Vector2::new(5.0, 7.0), 1.0),
+        (Vector2::new(5.0, 5.5), -0.5),
+        (Vector2::new(12.0, 5.0), -2.0),
+        (Vector2::new(13.0, 14.0), -5.0),
+    ];
+    for (point, expected) in cases {
+        let signed = locator.signed_distance(point).unwrap();
+        assert_fuzzy_eq!(signed, expected);
+        let dist = locator.distance_to_boundary(point).unwrap();
+        assert_fuzzy_eq!(dist, expected.abs());
+    }
+
+    let empty_locator = PointLocator::new(&Vec::<Polyline>::new(), 1e-5);
+    assert!(empty_locator
+        .distance_to_boundary(Vector2::zero())
+        .is_none());
+    assert!(empty_locator.signed_distance(Vector2::zero()).is_none());
+}
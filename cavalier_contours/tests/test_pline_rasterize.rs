@@ -0,0 +1,203 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        rasterize_bitmap, rasterize_coverage, FillRule, PlineSource, PlineSourceMut, Polyline,
+        RasterGrid, RasterizeOptions,
+    },
+};
+use test_utils::square;
+
+fn even_odd() -> RasterizeOptions {
+    RasterizeOptions {
+        fill_rule: FillRule::EvenOdd,
+        ..Default::default()
+    }
+}
+
+/// Assert bitmap matches the winding number of the pixel centers.
+fn assert_matches_winding(plines: &[Polyline], grid: &RasterGrid, options: &RasterizeOptions) {
+    let bitmap = rasterize_bitmap(plines, grid, options);
+    assert_eq!(bitmap.len(), grid.width * grid.height);
+    for row in 0..grid.height {
+        for col in 0..grid.width {
+            let center = grid.pixel_center(col, row);
+            let winding: i32 = plines.iter().map(|p| p.winding_number(center)).sum();
+            let inside = match options.fill_rule {
+                FillRule::NonZero => winding != 0,
+                FillRule::EvenOdd => winding % 2 != 0,
+            };
+            assert_eq!(
+                bitmap[row * grid.width + col],
+                if inside { 255 } else { 0 },
+                "pixel ({}, {}) at {:?}",
+                col,
+                row,
+                center
+            );
+        }
+    }
+}
+
+#[test]
+fn empty_input() {
+    let grid = RasterGrid::new(Vector2::zero(), 1.0, 8, 4);
+    let empty: Vec<Polyline> = Vec::new();
+    assert!(rasterize_bitmap(&empty, &grid, &Default::default())
+        .iter()
+        .all(|&b| b == 0));
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (8.0, 0.0, 0.0), (8.0, 4.0, 0.0)];
+    assert!(rasterize_coverage(&[open], &grid, &Default::default())
+        .iter()
+        .all(|&c| c == 0.0));
+
+    let no_pixels = RasterGrid::new(Vector2::zero(), 1.0, 0, 0);
+    assert!(rasterize_bitmap(&[square(0.0, 0.0, 2.0)], &no_pixels, &Default::default()).is_empty());
+}
+
+#[test]
+fn invalid_input() {
+    let plines = [square(0.0, 0.0, 2.0)];
+    let invalid_grids = [
+        RasterGrid::new(Vector2::new(f64::NAN, 0.0), 1.0, 4, 4),
+        RasterGrid::new(Vector2::new(0.0, f64::INFINITY), 1.0, 4, 4),
+        RasterGrid::new(Vector2::zero(), f64::NAN, 4, 4),
+        RasterGrid::new(Vector2::zero(), f64::INFINITY, 4, 4),
+        RasterGrid::new(Vector2::zero(), -1.0, 4, 4),
+    ];
+    for grid in invalid_grids.iter() {
+        assert!(!grid.is_valid());
+        assert!(rasterize_bitmap(&plines, grid, &Default::default())
+            .iter()
+            .all(|&b| b == 0));
+        assert!(rasterize_coverage(&plines, grid, &Default::default())
+            .iter()
+            .all(|&c| c == 0.0));
+    }
+
+    // segments with non-finite values are skipped
+    let grid = RasterGrid::new(Vector2::zero(), 0.5, 8, 8);
+    let nan_vertex: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (f64::NAN, 1.0, 0.0),
+        (0.0, 2.0, 0.0)
+    ];
+    let nan_bulge: Polyline = pline_closed![(0.0, 0.0, f64::NAN), (2.0, 2.0, 0.0)];
+    let infinite: Polyline =
+        pline_closed![(0.0, 0.0, 0.0), (f64::INFINITY, 1.0, 0.0), (0.0, 2.0, 0.0)];
+    for pline in [nan_vertex, nan_bulge, infinite].iter() {
+        assert_eq!(
+            rasterize_bitmap(std::iter::once(pline), &grid, &Default::default()).len(),
+            64
+        );
+        assert_eq!(
+            rasterize_coverage(std::iter::once(pline), &grid, &Default::default()).len(),
+            64
+        );
+    }
+}
+
+#[test]
+fn square_bitmap() {
+    let grid = RasterGrid::new(Vector2::new(-1.0, -1.0), 0.5, 12, 10);
+    let bitmap = rasterize_bitmap(&[square(0.0, 0.0, 2.0)], &grid, &Default::default());
+    // 2x2 square covers 4x4 pixels starting at column/row 2
+    assert_eq!(bitmap.iter().filter(|&&b| b == 255).count(), 16);
+    for row in 2..6 {
+        for col in 2..6 {
+            assert_eq!(bitmap[row * 12 + col], 255);
+        }
+    }
+}
+
+#[test]
+fn arcs_match_winding_number() {
+    let plines = vec![
+        pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)],
+        pline_closed![
+            (8.0, -3.0, 0.5),
+            (14.0, -3.0, -0.7),
+            (14.0, 4.0, 0.3),
+            (8.0, 4.0, 0.0)
+        ],
+    ];
+    let grid = RasterGrid::new(Vector2::new(-7.0, -7.3), 0.23, 100, 70);
+    assert_matches_winding(&plines, &grid, &Default::default());
+
+    let mut reversed = plines.clone();
+    reversed[1].invert_direction_mut();
+    assert_matches_winding(&reversed, &grid, &Default::default());
+}
+
+#[test]
+fn fill_rules() {
+    // same orientation for the hole so only even-odd excludes it
+    let plines = vec![square(0.0, 0.0, 10.0), square(3.0, 3.0, 4.0)];
+    let grid = RasterGrid::new(Vector2::new(-1.0, -1.0), 0.5, 24, 24);
+    let center = 10 * 24 + 10;
+
+    let non_zero = rasterize_bitmap(&plines, &grid, &Default::default());
+    assert_eq!(non_zero[center], 255);
+    assert_eq!(non_zero.iter().filter(|&&b| b == 255).count(), 400);
+
+    let even_odd_bitmap = rasterize_bitmap(&plines, &grid, &even_odd());
+    assert_eq!(even_odd_bitmap[center], 0);
+    assert_eq!(
+        even_odd_bitmap.iter().filter(|&&b| b == 255).count(),
+        400 - 64
+    );
+
+    // with the hole reversed both rules give the same result
+    let mut reversed = plines.clone();
+    reversed[1].invert_direction_mut();
+    assert_eq!(
+        rasterize_bitmap(&reversed, &grid, &Default::default()),
+        even_odd_bitmap
+    );
+
+    // self overlapping shape
+    let figure_eight: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 4.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let grid = RasterGrid::new(Vector2::new(-0.1, -0.1), 0.13, 40, 40);
+    assert_matches_winding(std::slice::from_ref(&figure_eight), &grid, &even_odd());
+    assert_matches_winding(&[figure_eight, square(1.0, 1.0, 2.0)], &grid, &even_odd());
+}
+
+#[test]
+fn coverage() {
+    let grid = RasterGrid::new(Vector2::zero(), 1.0, 4, 4);
+
+    // pixel aligned square is fully covered or empty
+    let coverage = rasterize_coverage(&[square(1.0, 1.0, 2.0)], &grid, &Default::default());
+    for row in 0..4 {
+        for col in 0..4 {
+            let expected = if (1..3).contains(&row) && (1..3).contains(&col) {
+                1.0
+            } else {
+                0.0
+            };
+            assert!((coverage[row * 4 + col] - expected).abs() < 1e-9);
+        }
+    }
+
+    // total coverage approximates the area
+    let circle: Polyline = pline_closed![(0.3, 2.0, 1.0), (3.7, 2.0, 1.0)];
+    let options = RasterizeOptions {
+        coverage_samples: 64,
+        ..Default::default()
+    };
+    let coverage = rasterize_coverage(std::slice::from_ref(&circle), &grid, &options);
+    let total: f64 = coverage.iter().sum();
+    assert!((total - circle.area()).abs() < 1e-2);
+    assert!(coverage.iter().all(|&c| (0.0..=1.0 + 1e-9).contains(&c)));
+    // center pixels are fully covered and corner pixels partially
+    assert!((coverage[4 + 1] - 1.0).abs() < 1e-9);
+    assert!(coverage[0] > 0.0 && coverage[0] < 0.5);
+}
@@ -0,0 +1,191 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{PlineRayCrossing, PlineRayOptions, PlineSource, PlineSourceMut, Polyline},
+};
+
+fn square() -> Polyline {
+    pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ]
+}
+
+#[test]
+fn empty_and_degenerate() {
+    let empty: Polyline = Polyline::new_closed();
+    assert!(empty
+        .ray_intersects(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0))
+        .is_empty());
+    assert!(square()
+        .ray_intersects(Vector2::new(-1.0, 5.0), Vector2::new(0.0, 0.0))
+        .is_empty());
+    assert!(square()
+        .line_intersects(Vector2::new(-1.0, 5.0), Vector2::new(-1.0, 5.0))
+        .is_empty());
+}
+
+#[test]
+fn ray_through_square() {
+    let square = square();
+    let hits = square.ray_intersects(Vector2::new(-5.0, 5.0), Vector2::new(2.0, 0.0));
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].seg_start_index, 3);
+    assert_fuzzy_eq!(hits[0].point, Vector2::new(0.0, 5.0));
+    assert_fuzzy_eq!(hits[0].distance, 5.0);
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+    assert_eq!(hits[1].seg_start_index, 1);
+    assert_fuzzy_eq!(hits[1].point, Vector2::new(10.0, 5.0));
+    assert_fuzzy_eq!(hits[1].distance, 15.0);
+    assert_eq!(hits[1].crossing, PlineRayCrossing::Exiting);
+
+    // clockwise gives the same crossings
+    let mut cw_square = square.clone();
+    cw_square.invert_direction_mut();
+    let hits = cw_square.ray_intersects(Vector2::new(-5.0, 5.0), Vector2::new(2.0, 0.0));
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+    assert_eq!(hits[1].crossing, PlineRayCrossing::Exiting);
+
+    // starting inside
+    let hits = square.ray_intersects(Vector2::new(5.0, 5.0), Vector2::new(0.0, -1.0));
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].seg_start_index, 0);
+    assert_fuzzy_eq!(hits[0].distance, 5.0);
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Exiting);
+
+    // pointing away
+    let hits = square.ray_intersects(Vector2::new(-5.0, 5.0), Vector2::new(-1.0, 0.0));
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn vertex_hits() {
+    let square = square();
+    // diagonal through opposite corners
+    let hits = square.ray_intersects(Vector2::new(-1.0, -1.0), Vector2::new(1.0, 1.0));
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].seg_start_index, 0);
+    assert_fuzzy_eq!(hits[0].point, Vector2::new(0.0, 0.0));
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+    assert_eq!(hits[1].seg_start_index, 2);
+    assert_fuzzy_eq!(hits[1].point, Vector2::new(10.0, 10.0));
+    assert_eq!(hits[1].crossing, PlineRayCrossing::Exiting);
+
+    // touching a single corner
+    let hits = square.ray_intersects(Vector2::new(-5.0, 5.0), Vector2::new(1.0, 1.0));
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].seg_start_index, 3);
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Touching);
+
+    // running along an edge
+    let hits = square.ray_intersects(Vector2::new(-5.0, 0.0), Vector2::new(1.0, 0.0));
+    assert_eq!(hits.len(), 2);
+    assert_fuzzy_eq!(hits[0].point, Vector2::new(0.0, 0.0));
+    assert_fuzzy_eq!(hits[1].point, Vector2::new(10.0, 0.0));
+    assert!(hits
+        .iter()
+        .all(|h| h.crossing == PlineRayCrossing::Touching));
+}
+
+#[test]
+fn arcs() {
+    // circle with radius 1 centered at (5, 0)
+    let circle = pline_closed![(4.0, 0.0, 1.0), (6.0, 0.0, 1.0)];
+    let hits = circle.ray_intersects(Vector2::new(5.0, -5.0), Vector2::new(0.0, 1.0));
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].seg_start_index, 0);
+    assert_fuzzy_eq!(hits[0].point, Vector2::new(5.0, -1.0));
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+    assert_eq!(hits[1].seg_start_index, 1);
+    assert_fuzzy_eq!(hits[1].point, Vector2::new(5.0, 1.0));
+    assert_eq!(hits[1].crossing, PlineRayCrossing::Exiting);
+
+    // tangent
+    let hits = circle.ray_intersects(Vector2::new(0.0, 1.0), Vector2::new(1.0, 0.0));
+    assert_eq!(hits.len(), 1);
+    assert_fuzzy_eq!(hits[0].point, Vector2::new(5.0, 1.0));
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Touching);
+}
+
+#[test]
+fn open_polyline_sides() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    let hits = pline.line_intersects(Vector2::new(5.0, 5.0), Vector2::new(5.0, 6.0));
+    assert_eq!(hits.len(), 1);
+    assert_fuzzy_eq!(hits[0].distance, -5.0);
+    // moving up through the first segment goes from its right side to its left side
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+
+    // end point of open polyline
+    let hits = pline.ray_intersects(Vector2::new(5.0, 10.0), Vector2::new(1.0, 0.0));
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].seg_start_index, 1);
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Touching);
+}
+
+#[test]
+fn line_intersects_both_directions() {
+    let square = square();
+    let index = square.create_approx_aabb_index().unwrap();
+    let options = PlineRayOptions {
+        aabb_index: Some(&index),
+        ..Default::default()
+    };
+    let hits = square.line_intersects_opt(Vector2::new(5.0, 5.0), Vector2::new(6.0, 5.0), &options);
+    assert_eq!(hits.len(), 2);
+    assert_fuzzy_eq!(hits[0].distance, -5.0);
+    assert_fuzzy_eq!(hits[0].point, Vector2::new(0.0, 5.0));
+    assert_eq!(hits[0].crossing, PlineRayCrossing::Entering);
+    assert_fuzzy_eq!(hits[1].distance, 5.0);
+    assert_eq!(hits[1].crossing, PlineRayCrossing::Exiting);
+}
+
+#[test]
+fn crossing_parity_matches_winding_number() {
+    let mut pline = Polyline::new_closed();
+    let count = 40;
+    for i in 0..count {
+        let angle = (i as f64) / (count as f64) * std::f64::consts::TAU;
+        let radius = 10.0 + (angle * 5.0).sin() * 3.0;
+        let bulge = if i % 2 == 0 { 0.3 } else { -0.2 };
+        pline.add(radius * angle.cos(), radius * angle.sin(), bulge);
+    }
+
+    let directions = [
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.3, 1.0),
+        Vector2::new(-1.0, -0.7),
+    ];
+
+    for i in 0..25 {
+        for j in 0..25 {
+            let pt = Vector2::new(-14.0 + i as f64 * 1.13, -14.0 + j as f64 * 1.13);
+            if pline.closest_point(pt, 1e-5).unwrap().distance < 1e-3 {
+                continue;
+            }
+            let inside = pline.winding_number(pt) != 0;
+            for &dir in directions.iter() {
+                let hits = pline.ray_intersects(pt, dir);
+                let crossings = hits
+                    .iter()
+                    .filter(|h| h.crossing != PlineRayCrossing::Touching)
+                    .collect::<Vec<_>>();
+                assert_eq!(crossings.len() % 2 == 1, inside, "point: {:?}", pt);
+                // crossings alternate ending with exiting
+                for (k, h) in crossings.iter().rev().enumerate() {
+                    let expected = if k % 2 == 0 {
+                        PlineRayCrossing::Exiting
+                    } else {
+                        PlineRayCrossing::Entering
+                    };
+                    assert_eq!(h.crossing, expected);
+                }
+                assert!(hits.windows(2).all(|w| w[0].distance <= w[1].distance));
+            }
+        }
+    }
+}
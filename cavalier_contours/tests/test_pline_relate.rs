@@ -0,0 +1,140 @@
+use cavalier_contours::{
+    pline_closed, pline_open,
+    polyline::{PlineRelateOptions, PlineRelation, PlineSource, PlineSourceMut, Polyline},
+};
+
+fn rectangle(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Polyline {
+    pline_closed![
+        (min_x, min_y, 0.0),
+        (max_x, min_y, 0.0),
+        (max_x, max_y, 0.0),
+        (min_x, max_y, 0.0),
+    ]
+}
+
+/// Assert relation holds in both argument orders and for all direction combinations.
+fn assert_relation(pline1: &Polyline, pline2: &Polyline, expected: PlineRelation) {
+    let reversed_expected = match expected {
+        PlineRelation::Within => PlineRelation::Contains,
+        PlineRelation::Contains => PlineRelation::Within,
+        r => r,
+    };
+
+    let mut inverted1 = pline1.clone();
+    inverted1.invert_direction_mut();
+    let mut inverted2 = pline2.clone();
+    inverted2.invert_direction_mut();
+
+    for p1 in [pline1, &inverted1].iter() {
+        for p2 in [pline2, &inverted2].iter() {
+            assert_eq!(p1.relate(*p2), expected);
+            assert_eq!(p2.relate(*p1), reversed_expected);
+        }
+    }
+}
+
+#[test]
+fn invalid_input() {
+    let open = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+    let rect = rectangle(0.0, 0.0, 1.0, 1.0);
+    assert_eq!(open.relate(&rect), PlineRelation::InvalidInput);
+    assert_eq!(rect.relate(&open), PlineRelation::InvalidInput);
+    assert!(!PlineRelation::InvalidInput.intersects());
+}
+
+#[test]
+fn no_intersects() {
+    let outer = rectangle(0.0, 0.0, 10.0, 10.0);
+    let inner = rectangle(2.0, 2.0, 4.0, 4.0);
+    let outside = rectangle(20.0, 0.0, 30.0, 10.0);
+    assert_relation(&outer, &inner, PlineRelation::Contains);
+    assert_relation(&inner, &outer, PlineRelation::Within);
+    assert_relation(&outer, &outside, PlineRelation::Disjoint);
+    assert!(!PlineRelation::Disjoint.intersects());
+}
+
+#[test]
+fn equal() {
+    let rect = rectangle(0.0, 0.0, 10.0, 10.0);
+    assert_relation(&rect, &rect.clone(), PlineRelation::Equal);
+
+    // different start vertex
+    let rotated = rect.rotate_start(2, rect.at(2).pos(), 1e-5).unwrap();
+    assert_relation(&rect, &rotated, PlineRelation::Equal);
+
+    let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    assert_relation(&circle, &circle.clone(), PlineRelation::Equal);
+}
+
+#[test]
+fn touches() {
+    let rect = rectangle(0.0, 0.0, 10.0, 10.0);
+    // sharing a whole edge
+    assert_relation(
+        &rect,
+        &rectangle(10.0, 0.0, 20.0, 10.0),
+        PlineRelation::Touches,
+    );
+    // sharing part of an edge
+    assert_relation(
+        &rect,
+        &rectangle(10.0, 5.0, 20.0, 15.0),
+        PlineRelation::Touches,
+    );
+    // sharing a single corner point
+    assert_relation(
+        &rect,
+        &rectangle(10.0, 10.0, 20.0, 20.0),
+        PlineRelation::Touches,
+    );
+    // circle tangent to an edge
+    let circle = pline_closed![(10.0, 5.0, 1.0), (12.0, 5.0, 1.0)];
+    assert_relation(&rect, &circle, PlineRelation::Touches);
+}
+
+#[test]
+fn within_and_contains_touching() {
+    let rect = rectangle(0.0, 0.0, 10.0, 10.0);
+    // inner rectangle sharing part of the bottom edge
+    assert_relation(
+        &rect,
+        &rectangle(2.0, 0.0, 4.0, 4.0),
+        PlineRelation::Contains,
+    );
+    // inner rectangle sharing a corner
+    assert_relation(&rectangle(0.0, 0.0, 4.0, 4.0), &rect, PlineRelation::Within);
+    // circle inside touching at one point
+    let circle = pline_closed![(0.0, 5.0, 1.0), (2.0, 5.0, 1.0)];
+    assert_relation(&rect, &circle, PlineRelation::Contains);
+}
+
+#[test]
+fn overlaps() {
+    let rect = rectangle(0.0, 0.0, 10.0, 10.0);
+    assert_relation(
+        &rect,
+        &rectangle(5.0, 5.0, 15.0, 15.0),
+        PlineRelation::Overlaps,
+    );
+    // overlapping along an edge and crossing
+    assert_relation(
+        &rect,
+        &rectangle(5.0, 0.0, 15.0, 5.0),
+        PlineRelation::Overlaps,
+    );
+    let circle = pline_closed![(9.0, 5.0, 1.0), (11.0, 5.0, 1.0)];
+    assert_relation(&rect, &circle, PlineRelation::Overlaps);
+    assert!(PlineRelation::Overlaps.intersects());
+}
+
+#[test]
+fn with_provided_index() {
+    let rect = rectangle(0.0, 0.0, 10.0, 10.0);
+    let index = rect.create_approx_aabb_index().unwrap();
+    let options = PlineRelateOptions {
+        pline1_aabb_index: Some(&index),
+        ..Default::default()
+    };
+    let other = rectangle(5.0, 5.0, 15.0, 15.0);
+    assert_eq!(rect.relate_opt(&other, &options), PlineRelation::Overlaps);
+}
@@ -0,0 +1,137 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_open,
+    polyline::{swept_area, MinkowskiOptions, MinkowskiResult, PlineSource, Polyline},
+};
+use std::f64::consts::PI;
+use test_utils::{circle, rect, total_area, winding};
+
+fn sweep(profile: &[Polyline], path: &Polyline) -> MinkowskiResult {
+    swept_area(profile, path, &MinkowskiOptions::default())
+}
+
+/// Assert swept circle covers exactly the points within `radius` of the path.
+fn assert_matches_distance(result: &MinkowskiResult, path: &Polyline, radius: f64) {
+    let extents = path.extents().unwrap();
+    let steps = 80;
+    for i in 0..=steps {
+        for j in 0..=steps {
+            let t_x = i as f64 / steps as f64;
+            let t_y = j as f64 / steps as f64;
+            let p = Vector2::new(
+                extents.min_x - 1.5 * radius + t_x * (extents.max_x - extents.min_x + 3.0 * radius),
+                extents.min_y - 1.5 * radius + t_y * (extents.max_y - extents.min_y + 3.0 * radius),
+            );
+            let dist = path.closest_point(p, 1e-5).unwrap().distance;
+            if (dist - radius).abs() < 1e-3 {
+                continue;
+            }
+            let expected = if dist < radius { 1 } else { 0 };
+            assert_eq!(winding(result, p), expected, "point {:?}", p);
+        }
+    }
+}
+
+#[test]
+fn empty_input() {
+    let path: Polyline = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+    let empty: Vec<Polyline> = Vec::new();
+    let result = sweep(&empty, &path);
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+
+    let result = sweep(&[rect(0.0, 0.0, 1.0, 1.0)], &Polyline::new());
+    assert!(result.pos_plines.is_empty() && result.neg_plines.is_empty());
+
+    // zero length path gives the translated profile
+    let point: Polyline = pline_open![(5.0, 3.0, 0.0)];
+    let result = sweep(&[rect(0.0, 0.0, 1.0, 1.0)], &point);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 1.0);
+    assert_eq!(winding(&result, Vector2::new(5.5, 3.5)), 1);
+}
+
+#[test]
+fn square_along_lines() {
+    let profile = rect(-1.0, -1.0, 1.0, 1.0);
+    let path: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    let result = sweep(std::slice::from_ref(&profile), &path);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 24.0 + 24.0 - 4.0);
+    assert_eq!(winding(&result, Vector2::new(5.0, 5.0)), 0);
+    assert_eq!(winding(&result, Vector2::new(10.5, 10.5)), 1);
+
+    // diagonal path gives a hexagon
+    let path: Polyline = pline_open![(0.0, 0.0, 0.0), (4.0, 4.0, 0.0)];
+    let result = sweep(std::slice::from_ref(&profile), &path);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 4.0 + 16.0);
+    assert_eq!(result.pos_plines[0].vertex_count(), 6);
+}
+
+#[test]
+fn circle_matches_distance() {
+    let paths: Vec<Polyline> = vec![
+        pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)],
+        pline_open![
+            (0.0, 0.0, 0.0),
+            (8.0, 0.0, 0.5),
+            (8.0, 6.0, 0.0),
+            (2.0, 7.0, -0.8),
+            (0.0, 3.0, 0.0)
+        ],
+        // self intersecting with a sharp turn back
+        pline_open![
+            (0.0, 0.0, 0.0),
+            (6.0, 6.0, 0.0),
+            (6.0, 0.0, 0.0),
+            (0.0, 5.0, 0.3),
+            (-1.0, 1.0, 0.0)
+        ],
+    ];
+    for path in paths.iter() {
+        for &r in [0.5, 1.5].iter() {
+            let result = sweep(&[circle(0.0, 0.0, r)], path);
+            assert_matches_distance(&result, path, r);
+        }
+    }
+
+    // straight path gives a stadium
+    let result = sweep(&[circle(0.0, 0.0, 1.0)], &paths[0]);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 20.0 + PI);
+}
+
+#[test]
+fn closed_path() {
+    let path = rect(0.0, 0.0, 10.0, 10.0);
+    let result = sweep(&[circle(0.0, 0.0, 1.0)], &path);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].area(), 140.0 + PI);
+    assert_fuzzy_eq!(result.neg_plines[0].area(), -64.0);
+    assert_matches_distance(&result, &path, 1.0);
+}
+
+#[test]
+fn profile_with_hole() {
+    // frame profile moved less than its wall thickness plus hole size keeps a hole
+    let profile = vec![rect(0.0, 0.0, 10.0, 10.0), rect(2.0, 2.0, 8.0, 8.0)];
+    let path: Polyline = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    let result = sweep(&profile, &path);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+    assert_fuzzy_eq!(total_area(&result), 120.0 - 24.0);
+    assert_eq!(winding(&result, Vector2::new(6.0, 5.0)), 0);
+    assert_eq!(winding(&result, Vector2::new(3.0, 5.0)), 1);
+
+    // moved far enough the hole is covered
+    let path: Polyline = pline_open![(0.0, 0.0, 0.0), (7.0, 0.0, 0.0)];
+    let result = sweep(&profile, &path);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert!(result.neg_plines.is_empty());
+    assert_fuzzy_eq!(total_area(&result), 170.0);
+}
@@ -0,0 +1,231 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{insert_tabs, PlineSource, Polyline, TabOptions, TabPlacement, TabResult},
+};
+
+fn square() -> Polyline {
+    pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ]
+}
+
+/// Obround with two 10 length lines and two half circle arcs (radius 5).
+fn obround() -> Polyline {
+    pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 1.0),
+    ]
+}
+
+fn slices_length(pline: &Polyline, result: &TabResult, is_tab: bool) -> f64 {
+    result
+        .slices
+        .iter()
+        .filter(|s| s.is_tab == is_tab)
+        .map(|s| s.view_data.view(pline).path_length())
+        .sum()
+}
+
+#[test]
+fn invalid_input() {
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    assert!(insert_tabs(&open, &TabOptions::default()).is_none());
+    let collapsed: Polyline = pline_closed![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    assert!(insert_tabs(&collapsed, &TabOptions::default()).is_none());
+
+    let pline = square();
+    for &value in [f64::NAN, f64::INFINITY].iter() {
+        let options = TabOptions {
+            width: value,
+            ..Default::default()
+        };
+        assert!(insert_tabs(&pline, &options).is_none());
+        let options = TabOptions {
+            corner_clearance: value,
+            ..Default::default()
+        };
+        assert!(insert_tabs(&pline, &options).is_none());
+        let options = TabOptions {
+            corner_angle: value,
+            ..Default::default()
+        };
+        assert!(insert_tabs(&pline, &options).is_none());
+    }
+
+    // non-finite positions are skipped
+    let positions = [f64::NAN, 5.0, f64::INFINITY, f64::NEG_INFINITY];
+    let options = TabOptions {
+        placement: TabPlacement::AtPathLengths(&positions),
+        width: 2.0,
+        corner_clearance: 1.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.tab_centers, vec![5.0]);
+}
+
+#[test]
+fn gaps_and_raised_tabs() {
+    let pline = square();
+    let options = TabOptions {
+        placement: TabPlacement::Count(4),
+        width: 2.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.tab_centers, vec![5.0, 15.0, 25.0, 35.0]);
+    assert_eq!(result.slices.len(), 4);
+    assert!(result.slices.iter().all(|s| !s.is_tab));
+    assert_fuzzy_eq!(slices_length(&pline, &result, false), 32.0);
+    let first = result.slices[0].view_data.view(&pline);
+    assert_fuzzy_eq!(first.at(0).pos(), Vector2::new(6.0, 0.0));
+    assert_fuzzy_eq!(first.last().unwrap().pos(), Vector2::new(10.0, 4.0));
+
+    let options = TabOptions {
+        raised_tabs: true,
+        ..options
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.slices.len(), 8);
+    assert_fuzzy_eq!(slices_length(&pline, &result, false), 32.0);
+    assert_fuzzy_eq!(slices_length(&pline, &result, true), 8.0);
+
+    // slices are contiguous and alternate between tabs and cuts
+    for i in 0..result.slices.len() {
+        let s1 = &result.slices[i];
+        let s2 = &result.slices[(i + 1) % result.slices.len()];
+        assert_ne!(s1.is_tab, s2.is_tab);
+        let end = s1.view_data.view(&pline).last().unwrap().pos();
+        let start = s2.view_data.view(&pline).at(0).pos();
+        assert_fuzzy_eq!(end, start);
+    }
+}
+
+#[test]
+fn single_tab_wraps_around() {
+    let pline = square();
+    let positions = [5.0];
+    let options = TabOptions {
+        placement: TabPlacement::AtPathLengths(&positions),
+        width: 2.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.slices.len(), 1);
+    let view = result.slices[0].view_data.view(&pline);
+    assert_fuzzy_eq!(view.path_length(), 38.0);
+    assert_eq!(view.vertex_count(), 6);
+    assert_fuzzy_eq!(view.at(0).pos(), Vector2::new(6.0, 0.0));
+    assert_fuzzy_eq!(view.last().unwrap().pos(), Vector2::new(4.0, 0.0));
+
+    // tab over the start of the polyline
+    let positions = [40.0];
+    let options = TabOptions {
+        placement: TabPlacement::AtPathLengths(&positions),
+        ..options
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.tab_centers, vec![0.0]);
+    let view = result.slices[0].view_data.view(&pline);
+    assert_fuzzy_eq!(view.path_length(), 38.0);
+    assert_fuzzy_eq!(view.at(0).pos(), Vector2::new(1.0, 0.0));
+    assert_fuzzy_eq!(view.last().unwrap().pos(), Vector2::new(0.0, 1.0));
+}
+
+#[test]
+fn overlapping_and_oversized_tabs() {
+    let pline = square();
+    let positions = [5.0, 5.5];
+    let options = TabOptions {
+        placement: TabPlacement::AtPathLengths(&positions),
+        width: 2.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.tab_centers.len(), 2);
+    assert_fuzzy_eq!(result.tab_centers[0], 5.0);
+    assert_fuzzy_eq!(result.tab_centers[1], 7.0, 1e-4);
+    assert_fuzzy_eq!(slices_length(&pline, &result, false), 36.0, 1e-4);
+
+    let options = TabOptions {
+        placement: TabPlacement::Count(2),
+        width: 50.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert!(result.tab_centers.is_empty());
+    assert_eq!(result.slices.len(), 1);
+    assert_fuzzy_eq!(slices_length(&pline, &result, false), 40.0);
+}
+
+#[test]
+fn avoid_arcs() {
+    let pline = obround();
+    let half_circle = std::f64::consts::PI * 5.0;
+    let total = 20.0 + 2.0 * half_circle;
+    // tab positions requested on the arcs
+    let positions = [10.0 + half_circle / 2.0, 20.0 + 1.5 * half_circle];
+    let options = TabOptions {
+        placement: TabPlacement::AtPathLengths(&positions),
+        width: 2.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_fuzzy_eq!(result.tab_centers[0], positions[0]);
+    assert_fuzzy_eq!(slices_length(&pline, &result, false), total - 4.0);
+
+    let options = TabOptions {
+        avoid_arcs: true,
+        ..options
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.tab_centers.len(), 2);
+    let line_ranges = [(0.0, 10.0), (10.0 + half_circle, 20.0 + half_circle)];
+    for &c in result.tab_centers.iter() {
+        assert!(line_ranges
+            .iter()
+            .any(|&(s, e)| c - 1.0 >= s - 1e-5 && c + 1.0 <= e + 1e-5));
+    }
+    assert_fuzzy_eq!(slices_length(&pline, &result, false), total - 4.0);
+}
+
+#[test]
+fn corner_clearance() {
+    let pline = square();
+    let options = TabOptions {
+        placement: TabPlacement::Count(4),
+        width: 2.0,
+        corner_clearance: 3.0,
+        ..Default::default()
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert_eq!(result.tab_centers, vec![5.0, 15.0, 25.0, 35.0]);
+
+    // not enough room on the sides
+    let options = TabOptions {
+        corner_clearance: 4.5,
+        ..options
+    };
+    let result = insert_tabs(&pline, &options).unwrap();
+    assert!(result.tab_centers.is_empty());
+
+    // smooth joins are not corners
+    let options = TabOptions {
+        placement: TabPlacement::Count(8),
+        width: 1.0,
+        corner_clearance: 1.0,
+        ..Default::default()
+    };
+    let circle: Polyline = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let result = insert_tabs(&circle, &options).unwrap();
+    assert_eq!(result.tab_centers.len(), 8);
+    let total = std::f64::consts::PI * 10.0;
+    assert_fuzzy_eq!(slices_length(&circle, &result, false), total - 8.0);
+}
@@ -0,0 +1,172 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq, pline_closed, pline_open,
+    polyline::{
+        compensate_tool_radius, CutSide, PlineOrientation, PlineSource, PlineSourceMut, Polyline,
+        ToolCompensationOptions,
+    },
+};
+use std::f64::consts::PI;
+use test_utils::square;
+
+fn options(tool_radius: f64) -> ToolCompensationOptions {
+    ToolCompensationOptions {
+        tool_radius,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn empty_and_ignored_input() {
+    let plines: Vec<Polyline> = Vec::new();
+    let result = compensate_tool_radius(&plines, &options(1.0));
+    assert!(result.loops.is_empty());
+    assert!(result.vanished.is_empty());
+
+    let plines: Vec<Polyline> = vec![
+        pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)],
+        Polyline::new_closed(),
+    ];
+    let result = compensate_tool_radius(&plines, &options(1.0));
+    assert!(result.loops.is_empty());
+    assert!(result.vanished.is_empty());
+}
+
+#[test]
+fn zero_tool_radius() {
+    let plines = vec![square(0.0, 0.0, 10.0), square(2.0, 2.0, 2.0)];
+    let result = compensate_tool_radius(&plines, &options(0.0));
+    assert_eq!(result.loops.len(), 2);
+    assert_eq!(result.loops[0].side, CutSide::Outside);
+    assert_eq!(result.loops[1].side, CutSide::Inside);
+    assert_fuzzy_eq!(result.loops[1].pline.area(), 4.0);
+}
+
+#[test]
+fn side_independent_of_orientation() {
+    let expected_outer_area = 144.0 + PI - 4.0;
+    let expected_hole_area = 4.0;
+    for &(invert_part, invert_hole) in
+        [(false, false), (true, false), (false, true), (true, true)].iter()
+    {
+        let mut part = square(0.0, 0.0, 10.0);
+        let mut hole = square(2.0, 2.0, 4.0);
+        if invert_part {
+            part.invert_direction_mut();
+        }
+        if invert_hole {
+            hole.invert_direction_mut();
+        }
+        let plines = vec![part, hole];
+        let result = compensate_tool_radius(&plines, &options(1.0));
+        assert_eq!(result.loops.len(), 2);
+        assert!(result.vanished.is_empty());
+
+        let outer = &result.loops[0];
+        assert_eq!(outer.source_index, 0);
+        assert_eq!(outer.side, CutSide::Outside);
+        assert_fuzzy_eq!(outer.pline.area().abs(), expected_outer_area);
+        assert_eq!(outer.pline.orientation(), plines[0].orientation());
+
+        let inner = &result.loops[1];
+        assert_eq!(inner.source_index, 1);
+        assert_eq!(inner.side, CutSide::Inside);
+        assert_fuzzy_eq!(inner.pline.area().abs(), expected_hole_area);
+        assert_eq!(inner.pline.orientation(), plines[1].orientation());
+    }
+}
+
+#[test]
+fn part_nested_in_hole() {
+    let plines = vec![
+        square(0.0, 0.0, 20.0),
+        square(2.0, 2.0, 16.0),
+        square(6.0, 6.0, 8.0),
+    ];
+    let result = compensate_tool_radius(&plines, &options(1.0));
+    assert_eq!(result.loops.len(), 3);
+    assert_eq!(result.loops[2].source_index, 2);
+    assert_eq!(result.loops[2].side, CutSide::Outside);
+    assert_fuzzy_eq!(result.loops[2].pline.area(), 100.0 + PI - 4.0);
+    assert_eq!(
+        result.loops[2].pline.orientation(),
+        PlineOrientation::CounterClockwise
+    );
+}
+
+#[test]
+fn hole_too_small_vanishes() {
+    let plines = vec![square(0.0, 0.0, 10.0), square(2.0, 2.0, 1.5)];
+    let result = compensate_tool_radius(&plines, &options(1.0));
+    assert_eq!(result.loops.len(), 1);
+    assert_eq!(result.loops[0].source_index, 0);
+    assert_eq!(result.vanished, vec![1]);
+
+    let result = compensate_tool_radius(&plines, &options(0.5));
+    assert_eq!(result.loops.len(), 2);
+    assert_fuzzy_eq!(result.loops[1].pline.area(), 0.25);
+    assert!(result.vanished.is_empty());
+}
+
+#[test]
+fn hole_pinches_into_multiple_loops() {
+    // two 4x4 pockets joined by a 1 wide channel
+    let hole: Polyline = pline_closed![
+        (1.0, 1.0, 0.0),
+        (5.0, 1.0, 0.0),
+        (5.0, 2.5, 0.0),
+        (7.0, 2.5, 0.0),
+        (7.0, 1.0, 0.0),
+        (11.0, 1.0, 0.0),
+        (11.0, 5.0, 0.0),
+        (7.0, 5.0, 0.0),
+        (7.0, 3.5, 0.0),
+        (5.0, 3.5, 0.0),
+        (5.0, 5.0, 0.0),
+        (1.0, 5.0, 0.0),
+    ];
+    let plines = vec![square(0.0, 0.0, 12.0), hole];
+    let result = compensate_tool_radius(&plines, &options(1.0));
+    assert_eq!(result.loops.len(), 3);
+    let hole_loops = result
+        .loops
+        .iter()
+        .filter(|l| l.source_index == 1)
+        .collect::<Vec<_>>();
+    assert_eq!(hole_loops.len(), 2);
+    for l in hole_loops {
+        assert_eq!(l.side, CutSide::Inside);
+        // 2x2 square plus a small bulge towards the channel mouth
+        let area = l.pline.area().abs();
+        assert!(area > 4.0 && area < 4.1);
+    }
+}
+
+#[test]
+fn partial_pinch_off_not_reported() {
+    // 4x4 pocket with a 1 wide slot the tool does not fit into
+    let hole: Polyline = pline_closed![
+        (1.0, 1.0, 0.0),
+        (5.0, 1.0, 0.0),
+        (5.0, 2.5, 0.0),
+        (9.0, 2.5, 0.0),
+        (9.0, 3.5, 0.0),
+        (5.0, 3.5, 0.0),
+        (5.0, 5.0, 0.0),
+        (1.0, 5.0, 0.0),
+    ];
+    let plines = vec![square(0.0, 0.0, 12.0), hole];
+    let result = compensate_tool_radius(&plines, &options(1.0));
+    assert_eq!(result.loops.len(), 2);
+    assert!(result.vanished.is_empty());
+    // slot is left uncut, offsetting the loop back does not recover the slot area
+    let hole_loop = &result.loops[1].pline;
+    assert_eq!(result.loops[1].side, CutSide::Inside);
+    let cut_area: f64 = hole_loop
+        .parallel_offset(if hole_loop.area() > 0.0 { -1.0 } else { 1.0 })
+        .iter()
+        .map(|p| p.area().abs())
+        .sum();
+    assert!(cut_area < plines[1].area().abs() - 3.0);
+}
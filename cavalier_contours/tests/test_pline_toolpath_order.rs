@@ -0,0 +1,174 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_open,
+    polyline::{order_toolpaths, PlineSource, Polyline, ToolpathOrderOptions},
+};
+use test_utils::square;
+
+#[test]
+fn empty_input() {
+    let plines: Vec<Polyline> = Vec::new();
+    let result = order_toolpaths(&plines, &ToolpathOrderOptions::default());
+    assert!(result.order.is_empty());
+    assert!(result.toolpaths.is_empty());
+    assert!(result.travels.is_empty());
+    assert_fuzzy_eq!(result.total_travel_length, 0.0);
+
+    let plines = vec![Polyline::new_closed(), square(0.0, 0.0, 1.0)];
+    let result = order_toolpaths(&plines, &ToolpathOrderOptions::default());
+    assert_eq!(result.order, vec![1]);
+}
+
+#[test]
+fn nearest_neighbor_order() {
+    let plines = vec![
+        square(30.0, 0.0, 1.0),
+        square(10.0, 0.0, 1.0),
+        square(20.0, 0.0, 1.0),
+        square(0.0, 0.0, 1.0),
+    ];
+    let result = order_toolpaths(&plines, &ToolpathOrderOptions::default());
+    assert_eq!(result.order, vec![3, 1, 2, 0]);
+    assert_eq!(result.toolpaths.len(), 4);
+    assert_eq!(result.travels.len(), 4);
+    // start at origin (first square vertex), loops exit where they enter
+    assert_fuzzy_eq!(result.travels[0].length(), 0.0);
+    assert_fuzzy_eq!(result.travels[1].from, Vector2::new(0.0, 0.0));
+    assert_fuzzy_eq!(result.travels[1].to, Vector2::new(10.0, 0.0));
+    assert_fuzzy_eq!(result.total_travel_length, 30.0);
+
+    // each toolpath starts at the end of its travel and ends where it starts
+    for (toolpath, travel) in result.toolpaths.iter().zip(result.travels.iter()) {
+        assert_fuzzy_eq!(toolpath[0].pos(), travel.to);
+        assert_fuzzy_eq!(toolpath.path_length(), 4.0);
+    }
+}
+
+#[test]
+fn start_points_not_optimized() {
+    let plines = vec![square(0.0, 0.0, 1.0), square(10.0, 0.0, 1.0)];
+    let options = ToolpathOrderOptions {
+        start_point: Vector2::new(5.0, 5.0),
+        optimize_start_points: false,
+        ..Default::default()
+    };
+    let result = order_toolpaths(&plines, &options);
+    for (toolpath, &i) in result.toolpaths.iter().zip(result.order.iter()) {
+        assert_eq!(toolpath.vertex_count(), 4);
+        assert_fuzzy_eq!(toolpath[0].pos(), plines[i][0].pos());
+    }
+}
+
+#[test]
+fn inner_loops_before_outer() {
+    // offset loops of a pocket plus an island in a separate region
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(1.0, 1.0, 8.0),
+        square(2.0, 2.0, 6.0),
+        square(20.0, 0.0, 2.0),
+    ];
+    let options = ToolpathOrderOptions {
+        start_point: Vector2::new(-1.0, -1.0),
+        ..Default::default()
+    };
+    let result = order_toolpaths(&plines, &options);
+    let position = |i: usize| result.order.iter().position(|&x| x == i).unwrap();
+    assert!(position(2) < position(1));
+    assert!(position(1) < position(0));
+    assert_eq!(result.order.len(), 4);
+
+    // without precedence the outer loop nearest the start point is cut first
+    let options = ToolpathOrderOptions {
+        start_point: Vector2::new(-1.0, -1.0),
+        inner_before_outer: false,
+        ..Default::default()
+    };
+    let result = order_toolpaths(&plines, &options);
+    assert_eq!(result.order[0], 0);
+}
+
+#[test]
+fn two_opt_improves_greedy() {
+    // greedy from the start point goes to the nearest point first leaving a long travel back
+    let plines = vec![
+        square(1.0, 0.0, 0.5),
+        square(-2.0, 0.0, 0.5),
+        square(-4.0, 0.0, 0.5),
+        square(8.0, 0.0, 0.5),
+    ];
+    let options = ToolpathOrderOptions {
+        optimize_start_points: false,
+        two_opt_max_passes: 0,
+        ..Default::default()
+    };
+    let greedy = order_toolpaths(&plines, &options);
+
+    let options = ToolpathOrderOptions {
+        optimize_start_points: false,
+        ..Default::default()
+    };
+    let improved = order_toolpaths(&plines, &options);
+    assert_fuzzy_eq!(greedy.total_travel_length, 18.0);
+    assert_fuzzy_eq!(improved.total_travel_length, 16.0);
+    assert_eq!(improved.order[3], 3);
+}
+
+#[test]
+fn open_polylines_cut_start_to_end() {
+    let plines: Vec<Polyline> = vec![
+        pline_open![(5.0, 0.0, 0.0), (10.0, 0.0, 0.0)],
+        pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)],
+    ];
+    let result = order_toolpaths(&plines, &ToolpathOrderOptions::default());
+    assert_eq!(result.order, vec![1, 0]);
+    assert_fuzzy_eq!(result.travels[1].from, Vector2::new(4.0, 0.0));
+    assert_fuzzy_eq!(result.travels[1].to, Vector2::new(5.0, 0.0));
+    assert_fuzzy_eq!(result.total_travel_length, 1.0);
+}
+
+#[test]
+fn two_opt_keeps_precedence_for_many_loops() {
+    // grid of pockets each with nested offset loops plus open polylines scattered between them
+    let mut plines = Vec::new();
+    let mut parent = Vec::new();
+    for i in 0..6 {
+        for j in 0..6 {
+            let (x, y) = (((i * 7) % 6) as f64 * 20.0, ((j * 5) % 6) as f64 * 20.0);
+            plines.push(square(x, y, 10.0));
+            parent.push(None);
+            for k in 1..4 {
+                plines.push(square(x + k as f64, y + k as f64, 10.0 - 2.0 * k as f64));
+                parent.push(Some(plines.len() - 2));
+            }
+            plines.push(pline_open![(x + 12.0, y, 0.0), (x + 12.0, y + 8.0, 0.0)]);
+            parent.push(None);
+        }
+    }
+
+    let options = ToolpathOrderOptions {
+        two_opt_max_passes: 0,
+        ..Default::default()
+    };
+    let greedy = order_toolpaths(&plines, &options);
+    let result = order_toolpaths(&plines, &ToolpathOrderOptions::default());
+    assert_eq!(result.order.len(), plines.len());
+    assert!(result.total_travel_length <= greedy.total_travel_length + 1e-5);
+
+    let mut position = vec![0; plines.len()];
+    for (pos, &i) in result.order.iter().enumerate() {
+        position[i] = pos;
+    }
+    for (i, p) in parent.iter().enumerate() {
+        if let Some(p) = *p {
+            // inner loop cut before the loop containing it
+            assert!(position[p] > position[i]);
+        }
+    }
+
+    let travel_sum: f64 = result.travels.iter().map(|t| t.length()).sum();
+    assert_fuzzy_eq!(travel_sum, result.total_travel_length);
+}
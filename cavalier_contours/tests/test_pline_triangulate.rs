@@ -0,0 +1,173 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_open,
+    polyline::{
+        triangulate, PlineSource, PlineSourceMut, Polyline, TriangleMesh, TriangulateOptions,
+    },
+};
+use test_utils::square;
+
+fn mesh(plines: &[Polyline]) -> TriangleMesh {
+    triangulate(plines, &TriangulateOptions::default())
+}
+
+fn centroid(mesh: &TriangleMesh, t: &[usize; 3]) -> Vector2 {
+    (mesh.vertexes[t[0]] + mesh.vertexes[t[1]] + mesh.vertexes[t[2]]).scale(1.0 / 3.0)
+}
+
+fn assert_valid(mesh: &TriangleMesh) {
+    for t in mesh.triangles.iter() {
+        let a = mesh.vertexes[t[0]];
+        let area = (mesh.vertexes[t[1]] - a).perp_dot(mesh.vertexes[t[2]] - a);
+        assert!(area > 0.0, "triangle {:?} is not counter clockwise", t);
+    }
+}
+
+#[test]
+fn empty_input() {
+    assert!(mesh(&[]).triangles.is_empty());
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    let result = mesh(&[open]);
+    assert!(result.triangles.is_empty());
+    assert!(result.vertexes.is_empty());
+}
+
+#[test]
+fn square_and_orientation() {
+    let result = mesh(&[square(0.0, 0.0, 10.0)]);
+    assert_eq!(result.vertexes.len(), 4);
+    assert_eq!(result.triangles.len(), 2);
+    assert_eq!(result.index_buffer().len(), 6);
+    assert_fuzzy_eq!(result.area(), 100.0);
+    assert_valid(&result);
+
+    let mut pline = square(0.0, 0.0, 10.0);
+    pline.invert_direction_mut();
+    let result = mesh(&[pline]);
+    assert_fuzzy_eq!(result.area(), 100.0);
+    assert_valid(&result);
+}
+
+#[test]
+fn concave_shape() {
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (8.0, 10.0, 0.0),
+        (8.0, 2.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let result = mesh(std::slice::from_ref(&pline));
+    assert_eq!(result.triangles.len(), 6);
+    assert_fuzzy_eq!(result.area(), pline.area());
+    assert_valid(&result);
+    // no triangle covers the notch
+    for t in result.triangles.iter() {
+        let c = centroid(&result, t);
+        assert!(!(c.x > 2.0 && c.x < 8.0 && c.y > 2.0));
+    }
+}
+
+#[test]
+fn holes_excluded() {
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(1.0, 1.0, 2.0),
+        square(6.0, 6.0, 3.0),
+        square(6.0, 1.0, 2.0),
+    ];
+    let result = mesh(&plines);
+    assert_fuzzy_eq!(result.area(), 100.0 - 4.0 - 9.0 - 4.0);
+    assert_valid(&result);
+    for t in result.triangles.iter() {
+        let c = centroid(&result, t);
+        for hole in plines.iter().skip(1) {
+            assert_eq!(hole.winding_number(c), 0);
+        }
+    }
+    // triangles for 4 loops of 4 vertexes with 3 holes bridged
+    assert_eq!(result.triangles.len(), 16 + 2 * 3 - 2);
+}
+
+#[test]
+fn island_in_hole() {
+    let plines = vec![
+        square(0.0, 0.0, 10.0),
+        square(2.0, 2.0, 6.0),
+        square(4.0, 4.0, 2.0),
+    ];
+    let result = mesh(&plines);
+    assert_fuzzy_eq!(result.area(), 100.0 - 36.0 + 4.0);
+    assert_valid(&result);
+}
+
+#[test]
+fn arcs_tessellated() {
+    let circle: Polyline = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    let coarse = triangulate(
+        std::slice::from_ref(&circle),
+        &TriangulateOptions {
+            arc_error_distance: 0.1,
+            ..Default::default()
+        },
+    );
+    let fine = triangulate(
+        std::slice::from_ref(&circle),
+        &TriangulateOptions {
+            arc_error_distance: 0.001,
+            ..Default::default()
+        },
+    );
+    assert!(fine.vertexes.len() > coarse.vertexes.len());
+    assert_valid(&coarse);
+    assert_valid(&fine);
+
+    let exact = circle.area();
+    assert!(coarse.area() < exact && fine.area() < exact);
+    assert!(exact - fine.area() < exact - coarse.area());
+    // every vertex is on the circle and chords are within the error distance
+    for v in fine.vertexes.iter() {
+        assert!((v.length() - 5.0).abs() < 1e-9);
+    }
+    assert!(exact - fine.area() < 0.001 * 2.0 * std::f64::consts::PI * 5.0);
+}
+
+#[test]
+fn max_edge_length() {
+    let plines = vec![square(0.0, 0.0, 10.0), square(4.0, 4.0, 2.0)];
+    let options = TriangulateOptions {
+        max_edge_length: Some(1.5),
+        ..Default::default()
+    };
+    let result = triangulate(&plines, &options);
+    assert_fuzzy_eq!(result.area(), 96.0);
+    assert_valid(&result);
+    for t in result.triangles.iter() {
+        for k in 0..3 {
+            let length = (result.vertexes[t[(k + 1) % 3]] - result.vertexes[t[k]]).length();
+            assert!(length <= 1.5 + 1e-9);
+        }
+    }
+
+    // mesh is conforming: every interior edge is shared by exactly two triangles
+    let mut edges = std::collections::HashMap::new();
+    for t in result.triangles.iter() {
+        for k in 0..3 {
+            let (a, b) = (t[k], t[(k + 1) % 3]);
+            *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+    let boundary_length: f64 = edges
+        .iter()
+        .filter(|(_, &count)| count == 1)
+        .map(|((a, b), _)| (result.vertexes[*b] - result.vertexes[*a]).length())
+        .sum();
+    assert!(edges.values().all(|&count| count <= 2));
+    assert_fuzzy_eq!(boundary_length, 40.0 + 8.0);
+}
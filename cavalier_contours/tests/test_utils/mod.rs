@@ -4,7 +4,14 @@ mod debug;
 mod pline_modifiers;
 #[allow(unused)]
 mod pline_test_properties;
+#[allow(unused)]
+mod shapes;
 
+#[allow(unused_imports)]
 pub use debug::*;
+#[allow(unused_imports)]
 pub use pline_modifiers::*;
+#[allow(unused_imports)]
 pub use pline_test_properties::*;
+#[allow(unused_imports)]
+pub use shapes::*;
@@ -0,0 +1,40 @@
+use cavalier_contours::{
+    core::math::Vector2,
+    pline_closed,
+    polyline::{MinkowskiResult, PlineSource, Polyline},
+};
+
+/// Counter clockwise axis aligned rectangle from `(x1, y1)` to `(x2, y2)`.
+pub fn rect(x1: f64, y1: f64, x2: f64, y2: f64) -> Polyline {
+    pline_closed![(x1, y1, 0.0), (x2, y1, 0.0), (x2, y2, 0.0), (x1, y2, 0.0)]
+}
+
+/// Counter clockwise square with minimum corner at `(x, y)`.
+pub fn square(x: f64, y: f64, size: f64) -> Polyline {
+    rect(x, y, x + size, y + size)
+}
+
+/// Counter clockwise circle formed from two arcs.
+pub fn circle(x: f64, y: f64, r: f64) -> Polyline {
+    pline_closed![(x - r, y, 1.0), (x + r, y, 1.0)]
+}
+
+/// Total signed area of all the polylines in a [MinkowskiResult].
+pub fn total_area(result: &MinkowskiResult) -> f64 {
+    result
+        .pos_plines
+        .iter()
+        .chain(result.neg_plines.iter())
+        .map(|p| p.area())
+        .sum()
+}
+
+/// Winding number of `point` summed over all the polylines in a [MinkowskiResult].
+pub fn winding(result: &MinkowskiResult, point: Vector2) -> i32 {
+    result
+        .pos_plines
+        .iter()
+        .chain(result.neg_plines.iter())
+        .map(|p| p.winding_number(point))
+        .sum()
+}
@@ -90,7 +90,7 @@ impl cavc_pline_parallel_offset_o {
     /// # Safety
     ///
     /// `aabb_index` field must be null or a valid pointer to a [cavc_aabbindex].
-    pub unsafe fn to_internal(&self) -> PlineOffsetOptions<f64> {
+    pub unsafe fn to_internal(&self) -> PlineOffsetOptions<'_, f64> {
         PlineOffsetOptions {
             aabb_index: self.aabb_index.as_ref().map(|w| &w.0),
             pos_equal_eps: self.pos_equal_eps,
@@ -152,7 +152,7 @@ impl cavc_pline_boolean_o {
     /// # Safety
     ///
     /// `pline1_aabb_index` field must be null or a valid pointer to a [cavc_aabbindex].
-    pub unsafe fn to_internal(&self) -> PlineBooleanOptions<f64> {
+    pub unsafe fn to_internal(&self) -> PlineBooleanOptions<'_, f64> {
         PlineBooleanOptions {
             pline1_aabb_index: self.pline1_aabb_index.as_ref().map(|w| &w.0),
             pos_equal_eps: self.pos_equal_eps,
@@ -561,7 +561,7 @@ pub unsafe extern "C" fn cavc_pline_get_vertex(
             return 2;
         }
 
-        let v = (*pline).0[position as usize];
+        let v = (&(*pline).0)[position as usize];
         vertex.write(cavc_vertex::from_internal(v));
         0
     })
@@ -596,7 +596,7 @@ pub unsafe extern "C" fn cavc_pline_set_vertex(
             return 2;
         }
 
-        (*pline).0[position as usize] = PlineVertex::new(vertex.x, vertex.y, vertex.bulge);
+        (&mut (*pline).0)[position as usize] = PlineVertex::new(vertex.x, vertex.y, vertex.bulge);
         0
     })
 }
@@ -1158,8 +1158,9 @@ pub unsafe extern "C" fn cavc_plinelist_get_pline(
         }
 
         let pos = position as usize;
+        let list = &(*plinelist).0;
 
-        match (*plinelist).0.get(pos) {
+        match list.get(pos) {
             Some(pl) => {
                 pline.write(*pl);
                 0